@@ -22,15 +22,23 @@ import { WalletButton } from "../../../components/WalletButton";
 import {
   useProgram,
   getBondingCurvePDA,
+  getBuyerRecordPDA,
+  getCurveStatsPDA,
   getCurveVaultPDA,
   getFeeVaultPDA,
+  getMetadataPDA,
   getPlatformConfigPDA,
+  getPositionPDA,
+  getPriceHistoryPDA,
+  legacyCurveSetup,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "../../../hooks/useProgram";
 import { isVerified, getVerifiedInfo } from "../../../lib/verified";
 import { getArtistVestingPDA } from "../../../hooks/useProgram";
 import { BondingCurveChart } from "../../../components/BondingCurveChart";
 import { HolderGate, GatingConfig } from "../../../components/HolderGate";
 import { computeBadges, topBadge, buildShareTweet, type BadgeInfo } from "../../../lib/badges";
+import { currentFeeBps, quoteBuy, quoteSell, type CurveReserves, type FeeConfig } from "../../../lib/curveMath";
 import { WalletModal } from "../../../components/WalletModal";
 import { WalletName } from "@solana/wallet-adapter-base";
 import { BoostModal } from "../../../components/BoostModal";
@@ -83,6 +91,7 @@ interface CurveData {
   realTokenReserves: BN;
   totalSupply: BN;
   artistShareBps: number;
+  treasuryBps: number; // Share of each buy routed to the artist treasury
   isActive: boolean;
   createdAt: BN;
   bump: number;
@@ -97,22 +106,22 @@ function calcPrice(curve: CurveData): number {
   return vTokens > 0 ? vSol / vTokens : 0;
 }
 
-function calcTokensOut(curve: CurveData, solLamports: number): number {
-  const feeBps = 100; // 1%
-  const fee = Math.floor((solLamports * feeBps) / 10_000);
-  const solAfterFee = solLamports - fee;
-  const vSol = curve.virtualSolReserves.toNumber();
-  const vTokens = curve.virtualTokenReserves.toNumber();
-  return Math.floor((solAfterFee * vTokens) / (vSol + solAfterFee));
+function reservesOf(curve: CurveData): CurveReserves {
+  return {
+    virtualSol: curve.virtualSolReserves,
+    virtualToken: curve.virtualTokenReserves,
+    realSol: curve.realSolReserves,
+    realToken: curve.realTokenReserves,
+  };
 }
 
-function calcSolOut(curve: CurveData, tokenAmount: number): number {
-  const vSol = curve.virtualSolReserves.toNumber();
-  const vTokens = curve.virtualTokenReserves.toNumber();
-  const rawSol = Math.floor((tokenAmount * vSol) / (vTokens + tokenAmount));
-  const feeBps = 100;
-  const fee = Math.floor((rawSol * feeBps) / 10_000);
-  return rawSol - fee;
+// Exact integer quotes — same math as the program, so previews match to the lamport
+function calcTokensOut(curve: CurveData, solLamports: number, feeBps: number): number {
+  return quoteBuy(reservesOf(curve), new BN(solLamports), feeBps, curve.treasuryBps).tokensOut.toNumber();
+}
+
+function calcSolOut(curve: CurveData, tokenAmount: number, feeBps: number): number {
+  return quoteSell(reservesOf(curve), new BN(tokenAmount), feeBps).solOut.toNumber();
 }
 
 function fmtSol(lamports: number): string {
@@ -310,6 +319,9 @@ export default function ArtistPage() {
 
   const editFileRef = useRef<HTMLInputElement>(null);
   const [curve, setCurve] = useState<CurveData | null>(null);
+  const [feeConfig, setFeeConfig] = useState<FeeConfig | null>(null);
+  // Fee in force now, including a queued change that is due
+  const feeBps = feeConfig ? currentFeeBps(feeConfig, Math.floor(Date.now() / 1000)) : 0;
   const [pageLoading, setPageLoading] = useState(true);
   const [pageError, setPageError] = useState<string | null>(null);
 
@@ -370,6 +382,10 @@ export default function ArtistPage() {
       // eslint-disable-next-line @typescript-eslint/no-explicit-any
       const acc = await (program.account as any).bondingCurve.fetch(bondingCurvePDA);
       setCurve(acc as CurveData);
+      const [platformConfigPDA] = getPlatformConfigPDA();
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      const config = await (program.account as any).platformConfig.fetch(platformConfigPDA);
+      setFeeConfig(config as FeeConfig);
     } catch (err) {
       const msg = err instanceof Error ? err.message : String(err);
       setPageError("Failed to load artist: " + msg);
@@ -581,7 +597,13 @@ export default function ArtistPage() {
 
       const updateIx = await program.methods
         .updateArtistToken(newUri)
-        .accounts({ bondingCurve: bondingCurvePDA, artist: publicKey })
+        .accounts({
+          bondingCurve: bondingCurvePDA,
+          artist: publicKey,
+          metadata: getMetadataPDA(mintPubkey)[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          mint: mintPubkey,
+        })
         .instruction();
 
       const tx = new Transaction();
@@ -644,6 +666,8 @@ export default function ArtistPage() {
         );
       }
 
+      tx.add(...(await legacyCurveSetup(program, connection, publicKey, mintPubkey)));
+
       const solLamports = new BN(Math.floor(solAmt * LAMPORTS_PER_SOL));
 
       const buyIx = await program.methods
@@ -654,9 +678,12 @@ export default function ArtistPage() {
           mint: mintPubkey,
           user: publicKey,
           userTokenAccount: ata,
+          curveStats: getCurveStatsPDA(mintPubkey)[0],
+          buyerRecord: getBuyerRecordPDA(mintPubkey, publicKey)[0],
+          priceHistory: getPriceHistoryPDA(mintPubkey)[0],
           curveVault,
           feeVault,
-          artistVesting: getArtistVestingPDA(mintPubkey)[0],
+          position: getPositionPDA(mintPubkey, publicKey)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -678,7 +705,7 @@ export default function ArtistPage() {
 
       // Show success immediately — don't block on confirmation
       setTxSuccess(sig);
-      setLastPurchaseTokens(calcTokensOut(curve, Math.floor(solAmt * LAMPORTS_PER_SOL)));
+      setLastPurchaseTokens(calcTokensOut(curve, Math.floor(solAmt * LAMPORTS_PER_SOL), feeBps));
       setLastPurchaseSol(solAmt);
       setLastPurchaseDate(new Date().toLocaleDateString("en-US", { month: "short", day: "numeric", year: "numeric" }));
       setSolInput("");
@@ -728,6 +755,7 @@ export default function ArtistPage() {
       const rawTokens = new BN(Math.floor(tokenAmt * 1_000_000)); // 6 decimals
 
       const tx = new Transaction();
+      tx.add(...(await legacyCurveSetup(program, connection, publicKey, mintPubkey)));
 
       const sellIx = await program.methods
         .sell(rawTokens, new BN(0))
@@ -737,9 +765,13 @@ export default function ArtistPage() {
           mint: mintPubkey,
           user: publicKey,
           userTokenAccount: ata,
+          curveStats: getCurveStatsPDA(mintPubkey)[0],
+          buyerRecord: getBuyerRecordPDA(mintPubkey, publicKey)[0],
+          priceHistory: getPriceHistoryPDA(mintPubkey)[0],
           curveVault,
           feeVault,
           artistVesting: getArtistVestingPDA(mintPubkey)[0],
+          position: getPositionPDA(mintPubkey, publicKey)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -825,11 +857,11 @@ export default function ArtistPage() {
   // Preview calcs
   const solLamInput = parseFloat(solInput) > 0
     ? Math.floor(parseFloat(solInput) * LAMPORTS_PER_SOL) : 0;
-  const tokensPreview = solLamInput > 0 ? calcTokensOut(curve, solLamInput) : 0;
+  const tokensPreview = solLamInput > 0 ? calcTokensOut(curve, solLamInput, feeBps) : 0;
 
   const tokenRawInput = parseFloat(tokenInput) > 0
     ? Math.floor(parseFloat(tokenInput) * 1_000_000) : 0;
-  const solPreview = tokenRawInput > 0 ? calcSolOut(curve, tokenRawInput) : 0;
+  const solPreview = tokenRawInput > 0 ? calcSolOut(curve, tokenRawInput, feeBps) : 0;
 
   return (
     <>
@@ -1254,7 +1286,7 @@ export default function ArtistPage() {
                     <span className="text-white font-medium">
                       {fmtTokens(tokensPreview)} {curve.symbol}
                     </span>
-                    <span className="text-gray-600 ml-1">(1% fee applied)</span>
+                    <span className="text-gray-600 ml-1">({feeBps / 100}% fee applied)</span>
                   </div>
                 )}
                 <button
@@ -1301,7 +1333,7 @@ export default function ArtistPage() {
                     <span className="text-white font-medium">
                       {fmtSol(solPreview)} SOL
                     </span>
-                    <span className="text-gray-600 ml-1">(1% fee applied)</span>
+                    <span className="text-gray-600 ml-1">({feeBps / 100}% fee applied)</span>
                   </div>
                 )}
                 <button
@@ -8,7 +8,7 @@ import { useWallet, useConnection } from "../../components/WalletProvider";
 import { WalletButton, WalletButtonCompact } from "../../components/WalletButton";
 import { Keypair, SystemProgram, SYSVAR_RENT_PUBKEY, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
-import { useProgram, getBondingCurvePDA, getPlatformConfigPDA, getArtistVestingPDA } from "../../hooks/useProgram";
+import { useProgram, getBondingCurvePDA, getPlatformConfigPDA, getArtistVestingPDA, getCurveStatsPDA, getPriceHistoryPDA, getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from "../../hooks/useProgram";
 
 /** Resize image to max 800×800 at 85% quality before uploading */
 async function compressImage(file: File): Promise<Blob> {
@@ -206,6 +206,9 @@ export default function LaunchPage() {
       // Derive PDAs
       const [bondingCurve] = getBondingCurvePDA(mintKeypair.publicKey);
       const [artistVesting] = getArtistVestingPDA(mintKeypair.publicKey);
+      const [curveStats] = getCurveStatsPDA(mintKeypair.publicKey);
+      const [priceHistory] = getPriceHistoryPDA(mintKeypair.publicKey);
+      const [metadata] = getMetadataPDA(mintKeypair.publicKey);
       const [platformConfig] = getPlatformConfigPDA();
 
       // Derive artist ATA for receiving their 10% share
@@ -229,6 +232,10 @@ export default function LaunchPage() {
           artist: publicKey,
           artistTokenAccount,
           artistVesting,
+          curveStats,
+          priceHistory,
+          metadata,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -4,6 +4,7 @@ import { createPortal } from "react-dom";
 import { useState, useRef } from "react";
 import { Connection, PublicKey, Transaction } from "@solana/web3.js";
 import { useWallet, useConnection } from "./WalletProvider";
+import { getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from "../hooks/useProgram";
 
 const COOLDOWN_DAYS = 30;
 const COOLDOWN_MS = COOLDOWN_DAYS * 24 * 60 * 60 * 1000;
@@ -140,7 +141,13 @@ export function EditProfileModal({
       // 4. Call updateArtistToken on-chain
       const updateIx = await program.methods
         .updateArtistToken(newUri)
-        .accounts({ bondingCurve: bondingCurvePDA, artist: publicKey })
+        .accounts({
+          bondingCurve: bondingCurvePDA,
+          artist: publicKey,
+          metadata: getMetadataPDA(new PublicKey(mint))[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          mint: new PublicKey(mint),
+        })
         .instruction();
 
       const tx = new Transaction();
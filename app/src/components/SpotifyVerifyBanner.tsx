@@ -3,6 +3,7 @@
 import { useState } from "react";
 import { PublicKey, Transaction } from "@solana/web3.js";
 import { useWallet, useConnection } from "./WalletProvider";
+import { getMetadataPDA, TOKEN_METADATA_PROGRAM_ID } from "../hooks/useProgram";
 
 interface SpotifyVerifyBannerProps {
   mint: string;
@@ -68,7 +69,13 @@ export function SpotifyVerifyBanner({
 
       const updateIx = await program.methods
         .updateArtistToken(newUri)
-        .accounts({ bondingCurve: bondingCurvePDA, artist: publicKey })
+        .accounts({
+          bondingCurve: bondingCurvePDA,
+          artist: publicKey,
+          metadata: getMetadataPDA(new PublicKey(mint))[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          mint: new PublicKey(mint),
+        })
         .instruction();
 
       const tx = new Transaction();
@@ -3,7 +3,7 @@
 import { useMemo } from "react";
 import { useConnection, useWallet } from "../components/WalletProvider";
 import { AnchorProvider, Program, setProvider } from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { Connection, PublicKey, TransactionInstruction } from "@solana/web3.js";
 import type { Fanstake } from "../lib/fanstake";
 import idl from "../lib/fanstake.json";
 
@@ -11,6 +11,10 @@ export const PROGRAM_ID = new PublicKey(
   "JCAt7JFiHxMBQ9TcEZYbWkp2GZpF3ZbdYdwD5ZBP6Nkf"
 );
 
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
 export function useProgram() {
   const { connection } = useConnection();
   const wallet = useWallet();
@@ -71,3 +75,70 @@ export function getArtistVestingPDA(mint: PublicKey): [PublicKey, number] {
     PROGRAM_ID
   );
 }
+
+export function getCurveStatsPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("curve_stats"), mint.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getPriceHistoryPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("price_history"), mint.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getBuyerRecordPDA(mint: PublicKey, user: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("buyer_record"), mint.toBuffer(), user.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function getPositionPDA(mint: PublicKey, user: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("position"), mint.toBuffer(), user.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+// Metaplex metadata account for an SPL Token artist mint
+export function getMetadataPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+}
+
+// Curves launched before the per-curve accounts existed get them created
+// in the same transaction as their first trade
+export async function legacyCurveSetup(
+  program: Program<Fanstake>,
+  connection: Connection,
+  payer: PublicKey,
+  mint: PublicKey
+): Promise<TransactionInstruction[]> {
+  const [bondingCurve] = getBondingCurvePDA(mint);
+  const [curveStats] = getCurveStatsPDA(mint);
+  const ixs: TransactionInstruction[] = [];
+  if (!(await connection.getAccountInfo(curveStats))) {
+    ixs.push(
+      await program.methods
+        .initCurveStats()
+        .accounts({ curveStats, bondingCurve, payer })
+        .instruction()
+    );
+  }
+  const [priceHistory] = getPriceHistoryPDA(mint);
+  if (!(await connection.getAccountInfo(priceHistory))) {
+    ixs.push(
+      await program.methods
+        .initPriceHistory()
+        .accounts({ priceHistory, bondingCurve, payer })
+        .instruction()
+    );
+  }
+  return ixs;
+}
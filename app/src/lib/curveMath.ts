@@ -0,0 +1,120 @@
+/**
+ * Bonding curve math — a line-for-line port of `crates/fanstake-math`, which
+ * the on-chain program uses in `buy`/`sell`. Integer (BN) arithmetic with the
+ * same floor rounding, so previews match on-chain results to the lamport.
+ * Keep the two in sync.
+ */
+import { BN } from "@coral-xyz/anchor";
+
+export const BPS_DENOMINATOR = new BN(10_000);
+/** Token base units per whole token (6 decimals). */
+export const TOKEN_UNIT = new BN(1_000_000);
+/** Prices are lamports per whole token as 1e9 fixed-point. */
+export const PRICE_SCALE = new BN(1_000_000_000);
+
+export interface CurveReserves {
+  virtualSol: BN;
+  virtualToken: BN;
+  realSol: BN;
+  realToken: BN;
+}
+
+export interface BuyQuote {
+  solIn: BN;
+  fee: BN;
+  treasury: BN;
+  solToCurve: BN;
+  tokensOut: BN;
+  priceAfter: BN;
+}
+
+export interface SellQuote {
+  tokensIn: BN;
+  solOutGross: BN;
+  fee: BN;
+  solOut: BN;
+  priceAfter: BN;
+}
+
+/** Fee fields of `PlatformConfig`. */
+export interface FeeConfig {
+  feeBps: number;
+  pendingFeeBps: number;
+  pendingFeeEffectiveAt: BN;
+}
+
+/** Platform fee in force at `nowSecs` — a queued fee applies once due (`current_fee_bps`). */
+export function currentFeeBps(config: FeeConfig, nowSecs: number): number {
+  const effectiveAt = config.pendingFeeEffectiveAt.toNumber();
+  return effectiveAt !== 0 && nowSecs >= effectiveAt ? config.pendingFeeBps : config.feeBps;
+}
+
+export function feeFor(amount: BN, feeBps: number): BN {
+  return amount.muln(feeBps).div(BPS_DENOMINATOR);
+}
+
+export function tokensForSol(r: CurveReserves, solIn: BN): BN {
+  return solIn.mul(r.virtualToken).div(r.virtualSol.add(solIn));
+}
+
+export function solForTokens(r: CurveReserves, tokensIn: BN): BN {
+  return tokensIn.mul(r.virtualSol).div(r.virtualToken.add(tokensIn));
+}
+
+export function spotPrice(virtualSol: BN, virtualToken: BN): BN {
+  return virtualSol.mul(TOKEN_UNIT).mul(PRICE_SCALE).div(virtualToken);
+}
+
+/**
+ * Buy with `solAmount` lamports (fee included). `treasuryBps` of the post-fee
+ * SOL goes to the artist treasury. Caller checks `tokensOut <= realToken`.
+ */
+export function quoteBuy(r: CurveReserves, solAmount: BN, feeBps: number, treasuryBps = 0): BuyQuote {
+  const fee = feeFor(solAmount, feeBps);
+  const afterFee = solAmount.sub(fee);
+  const treasury = feeFor(afterFee, treasuryBps);
+  const solToCurve = afterFee.sub(treasury);
+  const tokensOut = tokensForSol(r, solToCurve);
+  const priceAfter = spotPrice(r.virtualSol.add(solToCurve), r.virtualToken.sub(tokensOut));
+  return { solIn: solAmount, fee, treasury, solToCurve, tokensOut, priceAfter };
+}
+
+/** Sell `tokenAmount` base units. Caller checks `solOutGross <= realSol`. */
+export function quoteSell(r: CurveReserves, tokenAmount: BN, feeBps: number): SellQuote {
+  const solOutGross = solForTokens(r, tokenAmount);
+  const fee = feeFor(solOutGross, feeBps);
+  const solOut = solOutGross.sub(fee);
+  const priceAfter = spotPrice(r.virtualSol.sub(solOutGross), r.virtualToken.add(tokenAmount));
+  return { tokensIn: tokenAmount, solOutGross, fee, solOut, priceAfter };
+}
+
+/** Fully diluted market cap in lamports. */
+export function marketCap(virtualSol: BN, virtualToken: BN, totalSupply: BN): BN {
+  return virtualSol.mul(totalSupply).div(virtualToken);
+}
+
+function impactBps(before: BN, after: BN): BN {
+  return before.sub(after).abs().mul(BPS_DENOMINATOR).div(before);
+}
+
+export function buyPriceImpactBps(r: CurveReserves, solAmount: BN, feeBps: number): BN {
+  return impactBps(spotPrice(r.virtualSol, r.virtualToken), quoteBuy(r, solAmount, feeBps).priceAfter);
+}
+
+export function sellPriceImpactBps(r: CurveReserves, tokenAmount: BN, feeBps: number): BN {
+  return impactBps(spotPrice(r.virtualSol, r.virtualToken), quoteSell(r, tokenAmount, feeBps).priceAfter);
+}
+
+/** Smallest gross SOL (fee included) whose buy would take every remaining real token. */
+export function solToGraduation(r: CurveReserves, feeBps: number): BN {
+  if (r.realToken.isZero()) return new BN(0);
+  const denom = r.virtualToken.sub(r.realToken);
+  const net = r.realToken.mul(r.virtualSol).add(denom.subn(1)).div(denom);
+
+  const keepBps = BPS_DENOMINATOR.subn(feeBps);
+  const covers = (gross: BN) => gross.sub(feeFor(gross, feeBps)).gte(net);
+  let gross = net.mul(BPS_DENOMINATOR).add(keepBps.subn(1)).div(keepBps);
+  while (gross.gtn(0) && covers(gross.subn(1))) gross = gross.subn(1);
+  while (!covers(gross)) gross = gross.addn(1);
+  return gross;
+}
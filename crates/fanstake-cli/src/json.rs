@@ -0,0 +1,428 @@
+//! JSON views of FanStake accounts. Pubkeys are base58, prices stay in raw
+//! `PRICE_SCALE` units so output round-trips without precision loss.
+
+use anchor_lang::Discriminator;
+use fanstake_sdk::{
+    accounts, AdminGroup, AdminProposal, Airdrop, ArtistTreasury, Badge, Boost, BondingCurve, Buyback, BuyerRecord, CurveStats, DcaPlan,
+    Index, LenderPosition, LendingPool, LimitOrder, Loan, PlatformConfig, Position, PriceHistory,
+    TreasuryVote, VestingSchedule,
+};
+use serde_json::{json, Value};
+
+pub fn platform_config(c: &PlatformConfig) -> Value {
+    json!({
+        "type": "PlatformConfig",
+        "authority": c.authority.to_string(),
+        "fee_bps": c.fee_bps,
+        "fee_vault": c.fee_vault.to_string(),
+        "total_artists": c.total_artists,
+        "boost_tiers": c.boost_tiers.iter().map(|t| json!({
+            "price_lamports": t.price_lamports,
+            "duration_secs": t.duration_secs,
+        })).collect::<Vec<_>>(),
+        "total_volume_sol": c.total_volume_sol,
+        "total_fees": c.total_fees,
+        "total_trades": c.total_trades,
+        "swap_fee_discount_bps": c.swap_fee_discount_bps,
+        "admin_group": c.admin_group.to_string(),
+        "paused": c.paused,
+        "fee_change_delay": c.fee_change_delay,
+        "pending_fee_bps": c.pending_fee_bps,
+        "pending_fee_effective_at": c.pending_fee_effective_at,
+        "version": c.version,
+        "pending_fee_change_delay": c.pending_fee_change_delay,
+        "pending_delay_effective_at": c.pending_delay_effective_at,
+    })
+}
+
+pub fn bonding_curve(c: &BondingCurve) -> Value {
+    json!({
+        "type": "BondingCurve",
+        "artist": c.artist.to_string(),
+        "mint": c.mint.to_string(),
+        "name": c.name,
+        "symbol": c.symbol,
+        "uri": c.uri,
+        "virtual_sol_reserves": c.virtual_sol_reserves,
+        "virtual_token_reserves": c.virtual_token_reserves,
+        "real_sol_reserves": c.real_sol_reserves,
+        "real_token_reserves": c.real_token_reserves,
+        "total_supply": c.total_supply,
+        "artist_share_bps": c.artist_share_bps,
+        "is_active": c.is_active,
+        "created_at": c.created_at,
+        "token_program": c.token_program.to_string(),
+        "treasury_bps": c.treasury_bps,
+        "treasury_balance": c.treasury_balance,
+        "version": c.version,
+        "eligible_tokens": c.eligible_tokens,
+        "spot_price": c.spot_price(),
+    })
+}
+
+pub fn vesting_schedule(v: &VestingSchedule) -> Value {
+    json!({
+        "type": "VestingSchedule",
+        "mint": v.mint.to_string(),
+        "artist": v.artist.to_string(),
+        "vesting_end": v.vesting_end,
+        "version": v.version,
+    })
+}
+
+pub fn curve_stats(s: &CurveStats) -> Value {
+    json!({
+        "type": "CurveStats",
+        "mint": s.mint.to_string(),
+        "buy_volume_sol": s.buy_volume_sol,
+        "sell_volume_sol": s.sell_volume_sol,
+        "buy_volume_tokens": s.buy_volume_tokens,
+        "sell_volume_tokens": s.sell_volume_tokens,
+        "buy_count": s.buy_count,
+        "sell_count": s.sell_count,
+        "fees_paid": s.fees_paid,
+        "ath_price": s.ath_price,
+        "last_trade_at": s.last_trade_at,
+        "unique_buyers": s.unique_buyers,
+        "last_price": s.last_price,
+        "price_updated_at": s.price_updated_at,
+        "observation_count": s.observation_count,
+        "version": s.version,
+    })
+}
+
+pub fn buyer_record(r: &BuyerRecord) -> Value {
+    json!({
+        "type": "BuyerRecord",
+        "mint": r.mint.to_string(),
+        "wallet": r.wallet.to_string(),
+        "sequence": r.sequence,
+        "first_buy_at": r.first_buy_at,
+        "has_sold": r.has_sold,
+        "sol_spent": r.sol_spent,
+        "curve_tokens": r.curve_tokens,
+        "version": r.version,
+    })
+}
+
+pub fn position(p: &Position) -> Value {
+    json!({
+        "type": "Position",
+        "mint": p.mint.to_string(),
+        "wallet": p.wallet.to_string(),
+        "tokens_bought": p.tokens_bought,
+        "tokens_sold": p.tokens_sold,
+        "sol_in": p.sol_in,
+        "sol_out": p.sol_out,
+        "tokens_held": p.tokens_held,
+        "cost_basis": p.cost_basis,
+        "average_price": p.average_price(),
+        "realized_pnl": p.realized_pnl,
+        "first_buy_at": p.first_buy_at,
+        "last_trade_at": p.last_trade_at,
+        "opened_at": p.opened_at,
+        "version": p.version,
+    })
+}
+
+pub fn badge(b: &Badge) -> Value {
+    json!({
+        "type": "Badge",
+        "mint": b.mint.to_string(),
+        "wallet": b.wallet.to_string(),
+        "kind": format!("{:?}", b.kind),
+        "sequence": b.sequence,
+        "first_buy_at": b.first_buy_at,
+        "sol_spent": b.sol_spent,
+        "issued_at": b.issued_at,
+        "version": b.version,
+    })
+}
+
+pub fn boost(b: &Boost) -> Value {
+    json!({
+        "type": "Boost",
+        "mint": b.mint.to_string(),
+        "tier": b.tier,
+        "last_payer": b.last_payer.to_string(),
+        "started_at": b.started_at,
+        "expires_at": b.expires_at,
+        "total_paid": b.total_paid,
+        "version": b.version,
+    })
+}
+
+pub fn index(i: &Index) -> Value {
+    json!({
+        "type": "Index",
+        "curator": i.curator.to_string(),
+        "index_id": i.index_id,
+        "index_mint": i.index_mint.to_string(),
+        "name": i.name,
+        "symbol": i.symbol,
+        "components": i.components.iter().map(|c| json!({
+            "mint": c.mint.to_string(),
+            "weight_bps": c.weight_bps,
+        })).collect::<Vec<_>>(),
+        "created_at": i.created_at,
+        "version": i.version,
+    })
+}
+
+pub fn limit_order(o: &LimitOrder) -> Value {
+    json!({
+        "type": "LimitOrder",
+        "owner": o.owner.to_string(),
+        "mint": o.mint.to_string(),
+        "order_id": o.order_id,
+        "side": format!("{:?}", o.side),
+        "amount": o.amount,
+        "trigger_price": o.trigger_price,
+        "min_out": o.min_out,
+        "keeper_tip": o.keeper_tip,
+        "created_at": o.created_at,
+        "version": o.version,
+    })
+}
+
+pub fn dca_plan(p: &DcaPlan) -> Value {
+    json!({
+        "type": "DcaPlan",
+        "owner": p.owner.to_string(),
+        "mint": p.mint.to_string(),
+        "plan_id": p.plan_id,
+        "amount_per_interval": p.amount_per_interval,
+        "interval_secs": p.interval_secs,
+        "num_intervals": p.num_intervals,
+        "intervals_executed": p.intervals_executed,
+        "max_price": p.max_price,
+        "keeper_tip": p.keeper_tip,
+        "next_execution_at": p.next_execution_at,
+        "total_sol_spent": p.total_sol_spent,
+        "total_tokens_bought": p.total_tokens_bought,
+        "created_at": p.created_at,
+        "version": p.version,
+    })
+}
+
+pub fn lending_pool(p: &LendingPool) -> Value {
+    json!({
+        "type": "LendingPool",
+        "mint": p.mint.to_string(),
+        "ltv_bps": p.params.ltv_bps,
+        "liquidation_threshold_bps": p.params.liquidation_threshold_bps,
+        "base_rate_bps": p.params.base_rate_bps,
+        "slope_bps": p.params.slope_bps,
+        "liquidation_bonus_bps": p.params.liquidation_bonus_bps,
+        "liquidity": p.liquidity,
+        "total_shares": p.total_shares,
+        "total_debt": p.total_debt,
+        "total_collateral": p.total_collateral,
+        "borrow_index": p.borrow_index.to_string(),
+        "last_accrual": p.last_accrual,
+        "version": p.version,
+    })
+}
+
+pub fn lender_position(p: &LenderPosition) -> Value {
+    json!({
+        "type": "LenderPosition",
+        "owner": p.owner.to_string(),
+        "mint": p.mint.to_string(),
+        "shares": p.shares,
+        "version": p.version,
+    })
+}
+
+pub fn loan(l: &Loan) -> Value {
+    json!({
+        "type": "Loan",
+        "borrower": l.borrower.to_string(),
+        "mint": l.mint.to_string(),
+        "collateral": l.collateral,
+        "debt": l.debt,
+        "index_snapshot": l.index_snapshot.to_string(),
+        "version": l.version,
+    })
+}
+
+pub fn artist_treasury(t: &ArtistTreasury) -> Value {
+    json!({
+        "type": "ArtistTreasury",
+        "mint": t.mint.to_string(),
+        "artist": t.artist.to_string(),
+        "milestones": t.milestones.iter().map(|m| json!({
+            "description": m.description,
+            "amount": m.amount,
+            "deadline": m.deadline,
+            "round": m.round,
+            "vote_ends_at": m.vote_ends_at,
+            "votes_for": m.votes_for,
+            "votes_against": m.votes_against,
+            "released_at": m.released_at,
+        })).collect::<Vec<_>>(),
+        "next_milestone": t.next_milestone,
+        "total_released": t.total_released,
+        "failed_at": t.failed_at,
+        "refund_pool": t.refund_pool,
+        "refund_supply": t.refund_supply,
+        "total_refunded": t.total_refunded,
+        "created_at": t.created_at,
+        "version": t.version,
+    })
+}
+
+pub fn treasury_vote(v: &TreasuryVote) -> Value {
+    json!({
+        "type": "TreasuryVote",
+        "voter": v.voter.to_string(),
+        "mint": v.mint.to_string(),
+        "milestone": v.milestone,
+        "round": v.round,
+        "approve": v.approve,
+        "amount": v.amount,
+        "version": v.version,
+    })
+}
+
+pub fn buyback(b: &Buyback) -> Value {
+    json!({
+        "type": "Buyback",
+        "mint": b.mint.to_string(),
+        "artist": b.artist.to_string(),
+        "max_price": b.config.max_price,
+        "period_secs": b.config.period_secs,
+        "max_sol_per_period": b.config.max_sol_per_period,
+        "amount_per_run": b.config.amount_per_run,
+        "run_interval_secs": b.config.run_interval_secs,
+        "keeper_tip": b.config.keeper_tip,
+        "period_start": b.period_start,
+        "spent_in_period": b.spent_in_period,
+        "next_run_at": b.next_run_at,
+        "total_sol_spent": b.total_sol_spent,
+        "total_tokens_burned": b.total_tokens_burned,
+        "version": b.version,
+    })
+}
+
+pub fn admin_group(g: &AdminGroup) -> Value {
+    json!({
+        "type": "AdminGroup",
+        "members": g.members.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+        "threshold": g.threshold,
+        "generation": g.generation,
+        "proposal_count": g.proposal_count,
+        "version": g.version,
+    })
+}
+
+pub fn admin_proposal(p: &AdminProposal) -> Value {
+    json!({
+        "type": "AdminProposal",
+        "proposal_id": p.proposal_id,
+        "proposer": p.proposer.to_string(),
+        "action": format!("{:?}", p.action),
+        "generation": p.generation,
+        "approvals": p.approvals.count_ones(),
+        "approval_bitmap": p.approvals,
+        "created_at": p.created_at,
+        "expires_at": p.expires_at,
+        "executed_at": p.executed_at,
+        "version": p.version,
+    })
+}
+
+pub fn airdrop(a: &Airdrop) -> Value {
+    json!({
+        "type": "Airdrop",
+        "creator": a.creator.to_string(),
+        "mint": a.mint.to_string(),
+        "airdrop_id": a.airdrop_id,
+        "merkle_root": hex(&a.merkle_root),
+        "total_amount": a.total_amount,
+        "claimed_amount": a.claimed_amount,
+        "num_recipients": a.num_recipients,
+        "num_claimed": a.num_claimed,
+        "expires_at": a.expires_at,
+        "version": a.version,
+    })
+}
+
+/// Candles are listed oldest first.
+pub fn price_history(h: &PriceHistory) -> Value {
+    let len = h.buckets.len();
+    let candles: Vec<Value> = (0..h.count as usize)
+        .rev()
+        .map(|age| &h.buckets[(h.head as usize + len - age) % len])
+        .map(|b| json!({
+            "start": b.start,
+            "open": b.open,
+            "high": b.high,
+            "low": b.low,
+            "close": b.close,
+            "volume_sol": b.volume_sol,
+        }))
+        .collect();
+    json!({
+        "type": "PriceHistory",
+        "mint": h.mint.to_string(),
+        "interval_secs": h.interval_secs,
+        "version": h.version,
+        "candles": candles,
+    })
+}
+
+/// Decodes any FanStake account by its discriminator.
+pub fn any_account(data: &[u8]) -> anyhow::Result<Value> {
+    let disc = data.get(..8).ok_or_else(|| anyhow::anyhow!("account data too short"))?;
+    let value = if disc == PlatformConfig::DISCRIMINATOR {
+        platform_config(&accounts::platform_config(data)?)
+    } else if disc == BondingCurve::DISCRIMINATOR {
+        bonding_curve(&accounts::bonding_curve(data)?)
+    } else if disc == VestingSchedule::DISCRIMINATOR {
+        vesting_schedule(&accounts::vesting_schedule(data)?)
+    } else if disc == CurveStats::DISCRIMINATOR {
+        curve_stats(&accounts::curve_stats(data)?)
+    } else if disc == BuyerRecord::DISCRIMINATOR {
+        buyer_record(&accounts::buyer_record(data)?)
+    } else if disc == Position::DISCRIMINATOR {
+        position(&accounts::position(data)?)
+    } else if disc == Badge::DISCRIMINATOR {
+        badge(&accounts::badge(data)?)
+    } else if disc == Boost::DISCRIMINATOR {
+        boost(&accounts::boost(data)?)
+    } else if disc == Index::DISCRIMINATOR {
+        index(&accounts::index(data)?)
+    } else if disc == LimitOrder::DISCRIMINATOR {
+        limit_order(&accounts::limit_order(data)?)
+    } else if disc == DcaPlan::DISCRIMINATOR {
+        dca_plan(&accounts::dca_plan(data)?)
+    } else if disc == LendingPool::DISCRIMINATOR {
+        lending_pool(&accounts::lending_pool(data)?)
+    } else if disc == LenderPosition::DISCRIMINATOR {
+        lender_position(&accounts::lender_position(data)?)
+    } else if disc == Loan::DISCRIMINATOR {
+        loan(&accounts::loan(data)?)
+    } else if disc == ArtistTreasury::DISCRIMINATOR {
+        artist_treasury(&accounts::artist_treasury(data)?)
+    } else if disc == TreasuryVote::DISCRIMINATOR {
+        treasury_vote(&accounts::treasury_vote(data)?)
+    } else if disc == AdminGroup::DISCRIMINATOR {
+        admin_group(&accounts::admin_group(data)?)
+    } else if disc == AdminProposal::DISCRIMINATOR {
+        admin_proposal(&accounts::admin_proposal(data)?)
+    } else if disc == Buyback::DISCRIMINATOR {
+        buyback(&accounts::buyback(data)?)
+    } else if disc == Airdrop::DISCRIMINATOR {
+        airdrop(&accounts::airdrop(data)?)
+    } else if disc == PriceHistory::DISCRIMINATOR {
+        price_history(&accounts::price_history(data)?)
+    } else {
+        anyhow::bail!("not a FanStake account");
+    };
+    Ok(value)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,1183 @@
+//! `fanstake` — admin and operator CLI for the FanStake program.
+//!
+//! Every state-changing command is simulated first; failures print the
+//! program logs and decoded error instead of sending. `--dry-run` stops
+//! after the simulation.
+
+mod json;
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::path::PathBuf;
+
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fanstake_sdk::rpc::{Filter, RpcClient};
+use fanstake_sdk::{
+    accounts, error, instructions, math, pda, AdminAction, AdminProposal, BondingCurve, BuyQuoteResult, Buyback, BuybackConfig, DcaPlan,
+    LendingParams,
+    LimitOrder, MilestoneInput, OrderSide, SellQuoteResult, TwapResult, DEFAULT_PRICE_HISTORY_INTERVAL, PROGRAM_ID,
+};
+use serde_json::{json, Value};
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "fanstake", version, about = "FanStake admin and operator CLI")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(short, long, global = true, env = "FANSTAKE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Signer keypair file [default: ~/.config/solana/id.json]
+    #[arg(short, long, global = true, env = "FANSTAKE_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Simulate transactions but don't send them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the platform config and fee vault
+    Init {
+        /// Platform fee in basis points (max 1000)
+        #[arg(long, default_value_t = 100)]
+        fee_bps: u16,
+    },
+    /// Print the platform config as JSON
+    Config,
+    /// Print a curve and its stats as JSON
+    Curve { mint: Pubkey },
+    /// Decode any FanStake account as JSON
+    Account { address: Pubkey },
+    /// List all curves as JSON
+    Curves {
+        /// Only curves created by this artist
+        #[arg(long)]
+        artist: Option<Pubkey>,
+        /// Skip deactivated curves
+        #[arg(long)]
+        active: bool,
+    },
+    /// Quote a trade against on-chain state
+    Quote {
+        #[command(subcommand)]
+        side: Side,
+    },
+    /// Buy tokens with SOL
+    Buy {
+        mint: Pubkey,
+        /// Lamports to spend (including fee)
+        lamports: u64,
+        /// Allowed slippage below the quote
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u16,
+    },
+    /// Buy tokens for another wallet
+    Gift {
+        mint: Pubkey,
+        recipient: Pubkey,
+        /// Lamports to spend (including fee)
+        lamports: u64,
+        /// Gift note; only its sha256 goes on-chain
+        #[arg(long)]
+        memo: Option<String>,
+        /// Allowed slippage below the quote
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u16,
+    },
+    /// Sell tokens for SOL
+    Sell {
+        mint: Pubkey,
+        /// Token base units to sell
+        amount: u64,
+        /// Allowed slippage below the quote
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u16,
+    },
+    /// Sell one artist token and buy another in a single instruction
+    Swap {
+        from_mint: Pubkey,
+        to_mint: Pubkey,
+        /// `from` token base units to sell
+        amount: u64,
+        /// Allowed slippage below the quote
+        #[arg(long, default_value_t = 100)]
+        slippage_bps: u16,
+    },
+    /// Buy every component of an index (large indexes exceed legacy transaction size)
+    IndexBuy {
+        index: Pubkey,
+        /// Lamports to spend (including fees)
+        lamports: u64,
+        /// Minimum index tokens to receive
+        #[arg(long, default_value_t = 0)]
+        min_out: u64,
+    },
+    /// Redeem index tokens for SOL
+    IndexRedeem {
+        index: Pubkey,
+        /// Index token base units to burn
+        amount: u64,
+        /// Minimum lamports to receive
+        #[arg(long, default_value_t = 0)]
+        min_out: u64,
+    },
+    /// Place a limit order (escrows the SOL or tokens plus the keeper tip)
+    PlaceOrder {
+        mint: Pubkey,
+        #[arg(value_enum)]
+        side: OrderSideArg,
+        /// Lamports to spend (buy) or token base units to sell (sell)
+        amount: u64,
+        /// Fires at or below (buy) / at or above (sell) this spot price, PRICE_SCALE units
+        trigger_price: u64,
+        /// Minimum tokens (buy) or lamports (sell) the fill must return
+        #[arg(long, default_value_t = 0)]
+        min_out: u64,
+        /// Lamports paid to the keeper that fills the order
+        #[arg(long, default_value_t = 100_000)]
+        tip: u64,
+        /// Defaults to the current unix time
+        #[arg(long)]
+        order_id: Option<u64>,
+    },
+    /// Cancel one of the signer's orders and reclaim the escrow
+    CancelOrder { order: Pubkey },
+    /// List open limit orders as JSON
+    Orders {
+        #[arg(long)]
+        owner: Option<Pubkey>,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Fill every triggered limit order and run every due DCA plan and buyback, earning the tips
+    Keeper {
+        #[arg(long, default_value_t = 5_000)]
+        poll_ms: u64,
+        /// Run one pass and exit
+        #[arg(long)]
+        once: bool,
+    },
+    /// Open a recurring buy plan funded up front
+    DcaCreate {
+        mint: Pubkey,
+        /// Lamports spent per interval (fee included)
+        lamports: u64,
+        /// Number of buys
+        intervals: u32,
+        /// Seconds between buys
+        #[arg(long, default_value_t = 7 * 24 * 60 * 60)]
+        interval_secs: i64,
+        /// Skip buys while the spot price is above this, PRICE_SCALE units
+        #[arg(long, default_value_t = u64::MAX)]
+        max_price: u64,
+        /// Lamports paid to the keeper for each buy
+        #[arg(long, default_value_t = 100_000)]
+        tip: u64,
+        /// Lamports to deposit [default: enough for every interval and tip]
+        #[arg(long)]
+        deposit: Option<u64>,
+        /// Defaults to the current unix time
+        #[arg(long)]
+        plan_id: Option<u64>,
+    },
+    /// Withdraw unspent SOL from one of the signer's DCA plans
+    DcaWithdraw { plan: Pubkey, lamports: u64 },
+    /// Close one of the signer's DCA plans, reclaiming all unspent SOL
+    DcaClose { plan: Pubkey },
+    /// List DCA plans as JSON
+    DcaPlans {
+        #[arg(long)]
+        owner: Option<Pubkey>,
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Start tracking cost basis for the signer on one curve
+    OpenPosition { mint: Pubkey },
+    /// Print a wallet's position (realized and unrealized PnL) as JSON
+    Position {
+        mint: Pubkey,
+        /// Defaults to the signer
+        #[arg(long)]
+        wallet: Option<Pubkey>,
+    },
+    /// Time-weighted average price over the last `window` seconds
+    Twap {
+        mint: Pubkey,
+        #[arg(long, default_value_t = 3600)]
+        window: i64,
+    },
+    /// Lend SOL or borrow against artist tokens
+    Lending {
+        #[command(subcommand)]
+        action: Lending,
+    },
+    /// Artist buyback-and-burn
+    Buyback {
+        #[command(subcommand)]
+        action: BuybackCmd,
+    },
+    /// Milestone-gated artist treasuries
+    Treasury {
+        #[command(subcommand)]
+        action: Treasury,
+    },
+    /// M-of-N admin group proposals
+    Group {
+        #[command(subcommand)]
+        action: Group,
+    },
+    /// Platform and maintenance actions
+    Admin {
+        #[command(subcommand)]
+        action: Admin,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OrderSideArg {
+    Buy,
+    Sell,
+}
+
+#[derive(Subcommand)]
+enum Side {
+    /// Tokens received for `lamports` of SOL
+    Buy { mint: Pubkey, lamports: u64 },
+    /// SOL received for `amount` token base units
+    Sell { mint: Pubkey, amount: u64 },
+}
+
+#[derive(Subcommand)]
+enum Lending {
+    /// Print a curve's lending pool as JSON
+    Pool { mint: Pubkey },
+    /// Print a wallet's loan as JSON [default wallet: signer]
+    Loan {
+        mint: Pubkey,
+        #[arg(long)]
+        wallet: Option<Pubkey>,
+    },
+    /// Supply SOL to a pool for pool shares
+    Supply { mint: Pubkey, lamports: u64 },
+    /// Redeem pool shares for SOL
+    Withdraw { mint: Pubkey, shares: u64 },
+    /// Lock artist tokens as collateral
+    AddCollateral { mint: Pubkey, amount: u64 },
+    /// Unlock collateral the debt doesn't need
+    RemoveCollateral { mint: Pubkey, amount: u64 },
+    /// Borrow SOL against the signer's collateral
+    Borrow { mint: Pubkey, lamports: u64 },
+    /// Repay debt [default: all of it]
+    Repay { mint: Pubkey, lamports: Option<u64> },
+    /// Liquidate an unhealthy loan, earning the bonus
+    Liquidate { mint: Pubkey, borrower: Pubkey },
+}
+
+#[derive(Subcommand)]
+enum BuybackCmd {
+    /// Print a curve's buyback as JSON
+    Show { mint: Pubkey },
+    /// Set up buyback-and-burn on the signer's curve
+    Init {
+        mint: Pubkey,
+        #[command(flatten)]
+        config: BuybackConfigArgs,
+    },
+    /// Replace the buyback limits and schedule
+    SetConfig {
+        mint: Pubkey,
+        #[command(flatten)]
+        config: BuybackConfigArgs,
+    },
+    /// Add SOL to a buyback treasury
+    Fund { mint: Pubkey, lamports: u64 },
+    /// Take unspent SOL back out of the signer's buyback treasury
+    Withdraw { mint: Pubkey, lamports: u64 },
+    /// Buy back and burn now (artist)
+    Run {
+        mint: Pubkey,
+        lamports: u64,
+        #[arg(long, default_value_t = 0)]
+        min_tokens_out: u64,
+        /// Pay from the buyback treasury instead of the signer's wallet
+        #[arg(long)]
+        from_treasury: bool,
+    },
+}
+
+/// Buyback limits and schedule
+#[derive(Args)]
+struct BuybackConfigArgs {
+    /// No buybacks that end above this spot price, PRICE_SCALE units
+    #[arg(long, default_value_t = u64::MAX)]
+    max_price: u64,
+    /// Rate-limit period
+    #[arg(long, default_value_t = 24 * 60 * 60)]
+    period_secs: i64,
+    /// Lamports spent on buybacks per period
+    #[arg(long)]
+    max_sol_per_period: u64,
+    /// Lamports per scheduled run [default: no schedule]
+    #[arg(long, default_value_t = 0)]
+    amount_per_run: u64,
+    #[arg(long, default_value_t = 24 * 60 * 60)]
+    run_interval_secs: i64,
+    /// Lamports paid to the keeper per scheduled run
+    #[arg(long, default_value_t = 100_000)]
+    tip: u64,
+}
+
+impl From<BuybackConfigArgs> for BuybackConfig {
+    fn from(a: BuybackConfigArgs) -> Self {
+        BuybackConfig {
+            max_price: a.max_price,
+            period_secs: a.period_secs,
+            max_sol_per_period: a.max_sol_per_period,
+            amount_per_run: a.amount_per_run,
+            run_interval_secs: a.run_interval_secs,
+            keeper_tip: a.tip,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Treasury {
+    /// Print a curve's treasury as JSON
+    Show { mint: Pubkey },
+    /// Route a share of every buy to milestones (artist, before the first trade)
+    Create {
+        mint: Pubkey,
+        /// Share of each buy after the fee
+        #[arg(long)]
+        bps: u16,
+        /// `description:lamports:deadline_unix`, in order; repeat per milestone
+        #[arg(long = "milestone", required = true, value_parser = parse_milestone)]
+        milestones: Vec<MilestoneInput>,
+    },
+    /// Open a holder vote to release the next milestone (artist)
+    RequestRelease { mint: Pubkey, milestone: u8 },
+    /// Lock tokens for or against an open release
+    Vote {
+        mint: Pubkey,
+        milestone: u8,
+        amount: u64,
+        /// Vote against instead of for
+        #[arg(long)]
+        reject: bool,
+    },
+    /// Reclaim tokens locked in an ended vote
+    WithdrawVote { mint: Pubkey },
+    /// Settle an ended vote, paying the artist if it passed
+    Finalize { mint: Pubkey, milestone: u8 },
+    /// Fail a treasury whose next milestone missed its deadline
+    Fail { mint: Pubkey },
+    /// Sell tokens back with their share of a failed treasury
+    Refund {
+        mint: Pubkey,
+        amount: u64,
+        #[arg(long, default_value_t = 0)]
+        min_sol_out: u64,
+    },
+}
+
+/// Parses `description:lamports:deadline`; the description may contain colons.
+fn parse_milestone(s: &str) -> Result<MilestoneInput> {
+    let mut parts = s.rsplitn(3, ':');
+    let (Some(deadline), Some(amount), Some(description)) = (parts.next(), parts.next(), parts.next()) else {
+        bail!("expected description:lamports:deadline");
+    };
+    Ok(MilestoneInput {
+        description: description.to_string(),
+        amount: amount.parse().context("milestone lamports")?,
+        deadline: deadline.parse().context("milestone deadline")?,
+    })
+}
+
+/// Lending pool parameters, all in basis points
+#[derive(Args)]
+struct LendingParamsArgs {
+    #[arg(long, default_value_t = 3_000)]
+    ltv_bps: u16,
+    #[arg(long, default_value_t = 5_000)]
+    liquidation_threshold_bps: u16,
+    /// Annual borrow rate at 0% utilization
+    #[arg(long, default_value_t = 200)]
+    base_rate_bps: u16,
+    /// Added to the annual rate at 100% utilization
+    #[arg(long, default_value_t = 2_000)]
+    slope_bps: u16,
+    #[arg(long, default_value_t = 500)]
+    liquidation_bonus_bps: u16,
+}
+
+impl From<LendingParamsArgs> for LendingParams {
+    fn from(a: LendingParamsArgs) -> Self {
+        LendingParams {
+            ltv_bps: a.ltv_bps,
+            liquidation_threshold_bps: a.liquidation_threshold_bps,
+            base_rate_bps: a.base_rate_bps,
+            slope_bps: a.slope_bps,
+            liquidation_bonus_bps: a.liquidation_bonus_bps,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Group {
+    /// Print the admin group and every proposal as JSON
+    Show,
+    /// Hand config changes to an M-of-N group (current authority)
+    Init {
+        /// Repeat per member
+        #[arg(long = "member", required = true)]
+        members: Vec<Pubkey>,
+        #[arg(long)]
+        threshold: u8,
+    },
+    /// Propose a config change, approving it as the signer
+    Propose {
+        #[command(subcommand)]
+        action: ProposalCmd,
+    },
+    /// Approve an open proposal
+    Approve { proposal_id: u64 },
+    /// Apply a proposal that has enough approvals
+    Execute { proposal_id: u64 },
+}
+
+#[derive(Subcommand)]
+enum ProposalCmd {
+    /// Queue a platform fee change, in basis points
+    SetFee {
+        fee_bps: u16,
+        /// Unix time it applies [default: as soon as the delay allows]
+        #[arg(long, default_value_t = 0)]
+        effective_at: i64,
+    },
+    /// Notice required before a fee change applies
+    SetFeeChangeDelay { delay_secs: i64 },
+    /// Halt all trading
+    Pause,
+    /// Resume trading
+    Unpause,
+    /// Send lamports from the fee vault
+    WithdrawFees { lamports: u64, recipient: Pubkey },
+    SetSwapFeeDiscount { discount_bps: u16 },
+    SetBoostTier {
+        tier: u8,
+        #[arg(long)]
+        price_lamports: u64,
+        #[arg(long)]
+        duration_secs: i64,
+    },
+    SetLendingParams {
+        mint: Pubkey,
+        #[command(flatten)]
+        params: LendingParamsArgs,
+    },
+    /// Replace the members and threshold; open proposals lapse
+    SetMembers {
+        #[arg(long = "member", required = true)]
+        members: Vec<Pubkey>,
+        #[arg(long)]
+        threshold: u8,
+    },
+}
+
+impl From<ProposalCmd> for AdminAction {
+    fn from(cmd: ProposalCmd) -> Self {
+        match cmd {
+            ProposalCmd::SetFee { fee_bps, effective_at } => AdminAction::SetFee { fee_bps, effective_at },
+            ProposalCmd::SetFeeChangeDelay { delay_secs } => AdminAction::SetFeeChangeDelay { delay_secs },
+            ProposalCmd::Pause => AdminAction::SetPaused { paused: true },
+            ProposalCmd::Unpause => AdminAction::SetPaused { paused: false },
+            ProposalCmd::WithdrawFees { lamports, recipient } => AdminAction::WithdrawFees { amount: lamports, recipient },
+            ProposalCmd::SetSwapFeeDiscount { discount_bps } => AdminAction::SetSwapFeeDiscount { discount_bps },
+            ProposalCmd::SetBoostTier { tier, price_lamports, duration_secs } => {
+                AdminAction::SetBoostTier { tier, price_lamports, duration_secs }
+            }
+            ProposalCmd::SetLendingParams { mint, params } => AdminAction::SetLendingParams { mint, params: params.into() },
+            ProposalCmd::SetMembers { members, threshold } => AdminAction::SetMembers { members, threshold },
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Admin {
+    /// Set a boost tier's price and duration (price 0 disables it)
+    SetBoostTier {
+        tier: u8,
+        #[arg(long)]
+        price_lamports: u64,
+        #[arg(long)]
+        duration_secs: i64,
+    },
+    /// Share of the fee waived on each leg of a swap (5000 = one fee's worth)
+    SetSwapFeeDiscount { discount_bps: u16 },
+    /// Notice required before a fee change applies
+    SetFeeChangeDelay { delay_secs: i64 },
+    /// Open a lending pool on a curve
+    InitLendingPool {
+        mint: Pubkey,
+        #[command(flatten)]
+        params: LendingParamsArgs,
+    },
+    /// Replace a lending pool's parameters
+    SetLendingParams {
+        mint: Pubkey,
+        #[command(flatten)]
+        params: LendingParamsArgs,
+    },
+    /// Apply a queued fee change that is due
+    ActivateFeeChange,
+    /// Move the platform config to the current account layout
+    MigratePlatformConfig,
+    /// Move a bonding curve to the current account layout
+    MigrateCurve { mint: Pubkey },
+    /// Move any other program account to the current account layout
+    MigrateAccount { address: Pubkey },
+    /// Create missing stats/oracle account for a legacy curve
+    InitCurveStats { mint: Pubkey },
+    /// Create missing Metaplex metadata for a legacy curve
+    InitTokenMetadata { mint: Pubkey },
+    /// Create missing price history for a legacy curve
+    InitPriceHistory {
+        mint: Pubkey,
+        #[arg(long, default_value_t = 3600)]
+        interval_secs: i64,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let client = RpcClient::new(&cli.url);
+    let ctx = Ctx { client, keypair: cli.keypair, dry_run: cli.dry_run };
+
+    match cli.command {
+        Command::Init { fee_bps } => {
+            let signer = ctx.signer()?;
+            ctx.execute(&signer, &[instructions::initialize(&signer.pubkey(), fee_bps)])
+        }
+        Command::Config => {
+            let config = accounts::platform_config(&ctx.fetch(&pda::platform_config().0)?)?;
+            print(json::platform_config(&config))
+        }
+        Command::Curve { mint } => {
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let stats = match ctx.client.get_account_data(&pda::curve_stats(&mint).0)? {
+                Some(data) => json::curve_stats(&accounts::curve_stats(&data)?),
+                None => Value::Null,
+            };
+            let fee_bps = ctx.fee_bps()?;
+            let mut out = json::bonding_curve(&curve);
+            out["market_cap"] =
+                json!(math::market_cap(curve.virtual_sol_reserves, curve.virtual_token_reserves, curve.total_supply));
+            out["sol_to_graduation"] = json!(math::sol_to_graduation(&curve.reserves(), fee_bps));
+            out["stats"] = stats;
+            print(out)
+        }
+        Command::Account { address } => print(json::any_account(&ctx.fetch(&address)?)?),
+        Command::Curves { artist, active } => {
+            let mut filters = vec![Filter::Memcmp { offset: 0, bytes: BondingCurve::DISCRIMINATOR.to_vec() }];
+            if let Some(artist) = artist {
+                filters.push(Filter::Memcmp { offset: 8, bytes: artist.to_bytes().to_vec() });
+            }
+            let mut curves = Vec::new();
+            for (address, data) in ctx.client.get_program_accounts(&PROGRAM_ID, &filters)? {
+                let curve = accounts::bonding_curve(&data)?;
+                if active && !curve.is_active {
+                    continue;
+                }
+                let mut value = json::bonding_curve(&curve);
+                value["address"] = json!(address.to_string());
+                curves.push(value);
+            }
+            curves.sort_by_key(|c| c["created_at"].as_i64());
+            print(Value::Array(curves))
+        }
+        Command::Quote { side: Side::Buy { mint, lamports } } => {
+            let quote: BuyQuoteResult = ctx.view(&instructions::quote_buy(&mint, lamports))?;
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            print(json!({
+                "tokens_out": quote.tokens_out,
+                "fee": quote.fee,
+                "price_before": curve.spot_price(),
+                "price_after": quote.price_after,
+                "price_impact_bps": math::buy_price_impact_bps(&curve.reserves(), lamports, ctx.fee_bps()?),
+            }))
+        }
+        Command::Quote { side: Side::Sell { mint, amount } } => {
+            let quote: SellQuoteResult = ctx.view(&instructions::quote_sell(&mint, amount))?;
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            print(json!({
+                "sol_out": quote.sol_out,
+                "fee": quote.fee,
+                "price_before": curve.spot_price(),
+                "price_after": quote.price_after,
+                "price_impact_bps": math::sell_price_impact_bps(&curve.reserves(), amount, ctx.fee_bps()?),
+            }))
+        }
+        Command::Buy { mint, lamports, slippage_bps } => {
+            let signer = ctx.signer()?;
+            let quote: BuyQuoteResult = ctx.view(&instructions::quote_buy(&mint, lamports))?;
+            let min_out = apply_slippage(quote.tokens_out, slippage_bps);
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let user = signer.pubkey();
+            let mut ixs = ctx.legacy_curve_setup(&user, &mint)?;
+            ixs.push(instructions::create_token_account_idempotent(&user, &user, &mint, &curve.token_program));
+            ixs.push(instructions::buy(&user, &mint, &curve.token_program, lamports, min_out));
+            ctx.execute(&signer, &ixs)
+        }
+        Command::Gift { mint, recipient, lamports, memo, slippage_bps } => {
+            let signer = ctx.signer()?;
+            let quote: BuyQuoteResult = ctx.view(&instructions::quote_buy(&mint, lamports))?;
+            let min_out = apply_slippage(quote.tokens_out, slippage_bps);
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let memo_hash = memo.map(|m| hash(m.as_bytes()).to_bytes());
+            let mut ixs = ctx.legacy_curve_setup(&signer.pubkey(), &mint)?;
+            ixs.push(instructions::gift_buy(
+                &signer.pubkey(),
+                &recipient,
+                &mint,
+                &curve.token_program,
+                lamports,
+                min_out,
+                memo_hash,
+            ));
+            ctx.execute(&signer, &ixs)
+        }
+        Command::Sell { mint, amount, slippage_bps } => {
+            let signer = ctx.signer()?;
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let quote: SellQuoteResult = ctx.view(&instructions::quote_sell(&mint, amount))?;
+            let min_out = apply_slippage(quote.sol_out, slippage_bps);
+            let user = signer.pubkey();
+            let mut ixs = ctx.legacy_curve_setup(&user, &mint)?;
+            ixs.push(instructions::sell(&user, &mint, &curve.token_program, amount, min_out));
+            ctx.execute(&signer, &ixs)
+        }
+        Command::Swap { from_mint, to_mint, amount, slippage_bps } => {
+            let signer = ctx.signer()?;
+            let user = signer.pubkey();
+            let from = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&from_mint).0)?)?;
+            let to = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&to_mint).0)?)?;
+            let config = accounts::platform_config(&ctx.fetch(&pda::platform_config().0)?)?;
+            let leg_fee_bps = math::swap_fee_bps(ctx.fee_bps()?, u64::from(config.swap_fee_discount_bps))
+                .context("invalid swap fee")?;
+            let (_, buy) = math::quote_swap(&from.reserves(), &to.reserves(), amount, leg_fee_bps)
+                .context("swap quote overflowed")?;
+            let min_out = apply_slippage(buy.tokens_out, slippage_bps);
+            let leg = |mint: Pubkey, curve: &BondingCurve| instructions::SwapLeg { mint, token_program: curve.token_program };
+            let ix = instructions::swap_artist_tokens(
+                &user,
+                &leg(from_mint, &from),
+                &leg(to_mint, &to),
+                amount,
+                min_out,
+            );
+            ctx.execute(&signer, &[ix])
+        }
+        Command::IndexBuy { index, lamports, min_out } => {
+            let signer = ctx.signer()?;
+            let components = ctx.index_components(&index)?;
+            ctx.execute(
+                &signer,
+                &[instructions::buy_index(&signer.pubkey(), &index, &components, lamports, min_out)],
+            )
+        }
+        Command::IndexRedeem { index, amount, min_out } => {
+            let signer = ctx.signer()?;
+            let components = ctx.index_components(&index)?;
+            ctx.execute(
+                &signer,
+                &[instructions::redeem_index(&signer.pubkey(), &index, &components, amount, min_out)],
+            )
+        }
+        Command::PlaceOrder { mint, side, amount, trigger_price, min_out, tip, order_id } => {
+            let signer = ctx.signer()?;
+            let owner = signer.pubkey();
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let order_id = match order_id {
+                Some(id) => id,
+                None => unix_now()?,
+            };
+            let side = match side {
+                OrderSideArg::Buy => OrderSide::Buy,
+                OrderSideArg::Sell => OrderSide::Sell,
+            };
+            let ix = instructions::place_limit_order(
+                &owner,
+                &mint,
+                &curve.token_program,
+                order_id,
+                side,
+                amount,
+                trigger_price,
+                min_out,
+                tip,
+            );
+            eprintln!("order: {}", pda::limit_order(&mint, &owner, order_id).0);
+            ctx.execute(&signer, &[ix])
+        }
+        Command::CancelOrder { order } => {
+            let signer = ctx.signer()?;
+            let order = accounts::limit_order(&ctx.fetch(&order)?)?;
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&order.mint).0)?)?;
+            ctx.execute(&signer, &[instructions::cancel_order(&order, &curve.token_program)])
+        }
+        Command::Orders { owner, mint } => {
+            let orders = ctx
+                .owned_accounts::<LimitOrder>(owner, mint)?
+                .iter()
+                .map(|(address, order)| {
+                    let mut value = json::limit_order(order);
+                    value["address"] = json!(address.to_string());
+                    value
+                })
+                .collect();
+            print(Value::Array(orders))
+        }
+        Command::Keeper { poll_ms, once } => {
+            let signer = ctx.signer()?;
+            loop {
+                let mut curves = HashMap::new();
+                for (address, order) in ctx.owned_accounts::<LimitOrder>(None, None)? {
+                    let curve = ctx.cached_curve(&mut curves, &order.mint)?;
+                    let (spot, token_program) = (curve.spot_price(), curve.token_program);
+                    let triggered = match order.side {
+                        OrderSide::Buy => spot <= order.trigger_price,
+                        OrderSide::Sell => spot >= order.trigger_price,
+                    };
+                    if !triggered {
+                        continue;
+                    }
+                    eprintln!("filling {address} at spot {spot}");
+                    let ix = instructions::execute_order(&signer.pubkey(), &order, &token_program);
+                    if let Err(e) = ctx.execute(&signer, &[ix]) {
+                        eprintln!("  skipped: {e:#}");
+                    }
+                    // The fill moved the price; re-read before the next order on this curve
+                    curves.remove(&order.mint);
+                }
+                let now = unix_now()? as i64;
+                for (address, plan) in ctx.owned_accounts::<DcaPlan>(None, None)? {
+                    if plan.intervals_executed >= plan.num_intervals || now < plan.next_execution_at {
+                        continue;
+                    }
+                    let curve = ctx.cached_curve(&mut curves, &plan.mint)?;
+                    let (spot, token_program) = (curve.spot_price(), curve.token_program);
+                    if spot > plan.max_price {
+                        continue;
+                    }
+                    eprintln!("running DCA {address} ({}/{})", plan.intervals_executed + 1, plan.num_intervals);
+                    let ix = instructions::execute_dca(&signer.pubkey(), &plan, &token_program);
+                    if let Err(e) = ctx.execute(&signer, &[ix]) {
+                        eprintln!("  skipped: {e:#}");
+                    }
+                    curves.remove(&plan.mint);
+                }
+                for (address, buyback) in ctx.owned_accounts::<Buyback>(None, None)? {
+                    let config = buyback.config;
+                    if config.amount_per_run == 0 || now < buyback.next_run_at {
+                        continue;
+                    }
+                    let period_over = now >= buyback.period_start + config.period_secs;
+                    let spent = if period_over { 0 } else { buyback.spent_in_period };
+                    if spent.saturating_add(config.amount_per_run) > config.max_sol_per_period {
+                        continue;
+                    }
+                    let curve = ctx.cached_curve(&mut curves, &buyback.mint)?;
+                    let (spot, token_program) = (curve.spot_price(), curve.token_program);
+                    if spot > config.max_price {
+                        continue;
+                    }
+                    eprintln!("running buyback {address}");
+                    let ix = instructions::execute_buyback(&signer.pubkey(), &buyback.mint, &token_program);
+                    if let Err(e) = ctx.execute(&signer, &[ix]) {
+                        eprintln!("  skipped: {e:#}");
+                    }
+                    curves.remove(&buyback.mint);
+                }
+                if once {
+                    return Ok(());
+                }
+                std::thread::sleep(std::time::Duration::from_millis(poll_ms));
+            }
+        }
+        Command::DcaCreate { mint, lamports, intervals, interval_secs, max_price, tip, deposit, plan_id } => {
+            let signer = ctx.signer()?;
+            let owner = signer.pubkey();
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let plan_id = match plan_id {
+                Some(id) => id,
+                None => unix_now()?,
+            };
+            let deposit = match deposit {
+                Some(deposit) => deposit,
+                None => lamports
+                    .checked_add(tip)
+                    .and_then(|per| per.checked_mul(u64::from(intervals)))
+                    .ok_or_else(|| anyhow!("deposit overflows u64"))?,
+            };
+            let ix = instructions::create_dca_plan(
+                &owner,
+                &mint,
+                &curve.token_program,
+                plan_id,
+                lamports,
+                interval_secs,
+                intervals,
+                max_price,
+                tip,
+                deposit,
+            );
+            eprintln!("plan: {}", pda::dca_plan(&mint, &owner, plan_id).0);
+            ctx.execute(&signer, &[ix])
+        }
+        Command::DcaWithdraw { plan, lamports } => {
+            let signer = ctx.signer()?;
+            let plan = accounts::dca_plan(&ctx.fetch(&plan)?)?;
+            ctx.execute(&signer, &[instructions::withdraw_dca(&plan, lamports)])
+        }
+        Command::DcaClose { plan } => {
+            let signer = ctx.signer()?;
+            let plan = accounts::dca_plan(&ctx.fetch(&plan)?)?;
+            ctx.execute(&signer, &[instructions::close_dca_plan(&plan)])
+        }
+        Command::DcaPlans { owner, mint } => {
+            let plans = ctx
+                .owned_accounts::<DcaPlan>(owner, mint)?
+                .iter()
+                .map(|(address, plan)| {
+                    let mut value = json::dca_plan(plan);
+                    value["address"] = json!(address.to_string());
+                    value
+                })
+                .collect();
+            print(Value::Array(plans))
+        }
+        Command::OpenPosition { mint } => {
+            let signer = ctx.signer()?;
+            ctx.execute(&signer, &[instructions::open_position(&signer.pubkey(), &mint)])
+        }
+        Command::Position { mint, wallet } => {
+            let wallet = match wallet {
+                Some(wallet) => wallet,
+                None => ctx.signer()?.pubkey(),
+            };
+            let position = accounts::position(&ctx.fetch(&pda::position(&mint, &wallet).0)?)?;
+            let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+            let value = math::market_cap(curve.virtual_sol_reserves, curve.virtual_token_reserves, position.tokens_held)
+                .unwrap_or_default();
+            let mut out = json::position(&position);
+            out["current_value"] = json!(value);
+            out["unrealized_pnl"] = json!(value as i128 - position.cost_basis as i128);
+            print(out)
+        }
+        Command::Twap { mint, window } => {
+            let twap: TwapResult = ctx.view(&instructions::get_twap(&mint, window))?;
+            print(json!({ "price": twap.price, "window": twap.window, "spot_price": twap.spot_price }))
+        }
+        Command::Lending { action: Lending::Pool { mint } } => {
+            print(json::lending_pool(&accounts::lending_pool(&ctx.fetch(&pda::lending_pool(&mint).0)?)?))
+        }
+        Command::Lending { action: Lending::Loan { mint, wallet } } => {
+            let wallet = match wallet {
+                Some(wallet) => wallet,
+                None => ctx.signer()?.pubkey(),
+            };
+            print(json::loan(&accounts::loan(&ctx.fetch(&pda::loan(&mint, &wallet).0)?)?))
+        }
+        Command::Lending { action } => {
+            let signer = ctx.signer()?;
+            let wallet = signer.pubkey();
+            let ix = match action {
+                Lending::Pool { .. } | Lending::Loan { .. } => unreachable!("handled above"),
+                Lending::Supply { mint, lamports } => instructions::deposit_liquidity(&wallet, &mint, lamports),
+                Lending::Withdraw { mint, shares } => instructions::withdraw_liquidity(&wallet, &mint, shares),
+                Lending::AddCollateral { mint, amount } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::deposit_collateral(&wallet, &mint, &curve.token_program, amount)
+                }
+                Lending::RemoveCollateral { mint, amount } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::withdraw_collateral(&wallet, &mint, &curve.token_program, amount)
+                }
+                Lending::Borrow { mint, lamports } => instructions::borrow(&wallet, &mint, lamports),
+                Lending::Repay { mint, lamports } => {
+                    instructions::repay(&wallet, &mint, lamports.unwrap_or(u64::MAX))
+                }
+                Lending::Liquidate { mint, borrower } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::liquidate(&wallet, &borrower, &mint, &curve.token_program)
+                }
+            };
+            ctx.execute(&signer, &[ix])
+        }
+        Command::Buyback { action: BuybackCmd::Show { mint } } => {
+            print(json::buyback(&accounts::buyback(&ctx.fetch(&pda::buyback(&mint).0)?)?))
+        }
+        Command::Buyback { action } => {
+            let signer = ctx.signer()?;
+            let wallet = signer.pubkey();
+            let ix = match action {
+                BuybackCmd::Show { .. } => unreachable!("handled above"),
+                BuybackCmd::Init { mint, config } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::init_buyback(&wallet, &mint, &curve.token_program, config.into())
+                }
+                BuybackCmd::SetConfig { mint, config } => instructions::set_buyback_config(&wallet, &mint, config.into()),
+                BuybackCmd::Fund { mint, lamports } => instructions::fund_buyback(&wallet, &mint, lamports),
+                BuybackCmd::Withdraw { mint, lamports } => instructions::withdraw_buyback(&wallet, &mint, lamports),
+                BuybackCmd::Run { mint, lamports, min_tokens_out, from_treasury } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::buyback(&wallet, &mint, &curve.token_program, lamports, min_tokens_out, from_treasury)
+                }
+            };
+            ctx.execute(&signer, &[ix])
+        }
+        Command::Treasury { action: Treasury::Show { mint } } => {
+            print(json::artist_treasury(&accounts::artist_treasury(&ctx.fetch(&pda::artist_treasury(&mint).0)?)?))
+        }
+        Command::Treasury { action } => {
+            let signer = ctx.signer()?;
+            let wallet = signer.pubkey();
+            let ix = match action {
+                Treasury::Show { .. } => unreachable!("handled above"),
+                Treasury::Create { mint, bps, milestones } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::create_artist_treasury(&wallet, &mint, &curve.token_program, bps, milestones)
+                }
+                Treasury::RequestRelease { mint, milestone } => instructions::request_release(&wallet, &mint, milestone),
+                Treasury::Vote { mint, milestone, amount, reject } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::cast_vote(&wallet, &mint, &curve.token_program, milestone, !reject, amount)
+                }
+                Treasury::WithdrawVote { mint } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::withdraw_vote(&wallet, &mint, &curve.token_program)
+                }
+                Treasury::Finalize { mint, milestone } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::finalize_vote(&curve.artist, &mint, milestone)
+                }
+                Treasury::Fail { mint } => instructions::fail_treasury(&mint),
+                Treasury::Refund { mint, amount, min_sol_out } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::claim_refund(&wallet, &mint, &curve.token_program, amount, min_sol_out)
+                }
+            };
+            ctx.execute(&signer, &[ix])
+        }
+        Command::Group { action: Group::Show } => {
+            let group = accounts::admin_group(&ctx.fetch(&pda::admin_group().0)?)?;
+            let mut proposals = ctx.owned_accounts::<AdminProposal>(None, None)?;
+            proposals.sort_by_key(|(_, p)| p.proposal_id);
+            let mut out = json::admin_group(&group);
+            out["proposals"] = proposals.iter().map(|(_, p)| json::admin_proposal(p)).collect();
+            print(out)
+        }
+        Command::Group { action } => {
+            let signer = ctx.signer()?;
+            let wallet = signer.pubkey();
+            let ix = match action {
+                Group::Show => unreachable!("handled above"),
+                Group::Init { members, threshold } => instructions::init_admin_group(&wallet, members, threshold),
+                Group::Propose { action } => {
+                    let group = accounts::admin_group(&ctx.fetch(&pda::admin_group().0)?)?;
+                    eprintln!("proposal: {}", group.proposal_count);
+                    instructions::propose_admin_action(&wallet, group.proposal_count, action.into())
+                }
+                Group::Approve { proposal_id } => instructions::approve_admin_action(&wallet, proposal_id),
+                Group::Execute { proposal_id } => {
+                    let proposal = accounts::admin_proposal(&ctx.fetch(&pda::admin_proposal(proposal_id).0)?)?;
+                    instructions::execute_admin_action(&wallet, proposal_id, &proposal.action)
+                }
+            };
+            ctx.execute(&signer, &[ix])
+        }
+        Command::Admin { action } => {
+            let signer = ctx.signer()?;
+            let ix = match action {
+                Admin::SetBoostTier { tier, price_lamports, duration_secs } => {
+                    instructions::set_boost_tier(&signer.pubkey(), tier, price_lamports, duration_secs)
+                }
+                Admin::SetSwapFeeDiscount { discount_bps } => {
+                    instructions::set_swap_fee_discount(&signer.pubkey(), discount_bps)
+                }
+                Admin::SetFeeChangeDelay { delay_secs } => {
+                    instructions::set_fee_change_delay(&signer.pubkey(), delay_secs)
+                }
+                Admin::InitLendingPool { mint, params } => {
+                    let curve = accounts::bonding_curve(&ctx.fetch(&pda::bonding_curve(&mint).0)?)?;
+                    instructions::init_lending_pool(&signer.pubkey(), &mint, &curve.token_program, params.into())
+                }
+                Admin::SetLendingParams { mint, params } => {
+                    instructions::set_lending_params(&signer.pubkey(), &mint, params.into())
+                }
+                Admin::ActivateFeeChange => instructions::activate_fee_change(),
+                Admin::MigratePlatformConfig => instructions::migrate_platform_config(&signer.pubkey()),
+                Admin::MigrateCurve { mint } => instructions::migrate_bonding_curve(&signer.pubkey(), &mint),
+                Admin::MigrateAccount { address } => instructions::migrate_account(&signer.pubkey(), &address),
+                Admin::InitCurveStats { mint } => instructions::init_curve_stats(&signer.pubkey(), &mint),
+                Admin::InitTokenMetadata { mint } => instructions::init_token_metadata(&signer.pubkey(), &mint),
+                Admin::InitPriceHistory { mint, interval_secs } => {
+                    instructions::init_price_history(&signer.pubkey(), &mint, interval_secs)
+                }
+            };
+            ctx.execute(&signer, &[ix])
+        }
+    }
+}
+
+struct Ctx {
+    client: RpcClient,
+    keypair: Option<PathBuf>,
+    dry_run: bool,
+}
+
+impl Ctx {
+    fn signer(&self) -> Result<Keypair> {
+        let path = match &self.keypair {
+            Some(path) => path.clone(),
+            None => PathBuf::from(std::env::var("HOME").context("HOME not set")?)
+                .join(".config/solana/id.json"),
+        };
+        read_keypair_file(&path).map_err(|e| anyhow!("failed to read keypair {}: {e}", path.display()))
+    }
+
+    fn fetch(&self, address: &Pubkey) -> Result<Vec<u8>> {
+        self.client
+            .get_account_data(address)?
+            .ok_or_else(|| anyhow!("account {address} not found"))
+    }
+
+    /// Creates the stats and price history a curve launched before them is
+    /// missing, so the trade sent with them doesn't fail.
+    fn legacy_curve_setup(&self, payer: &Pubkey, mint: &Pubkey) -> Result<Vec<Instruction>> {
+        let mut ixs = Vec::new();
+        if self.client.get_account_data(&pda::curve_stats(mint).0)?.is_none() {
+            ixs.push(instructions::init_curve_stats(payer, mint));
+        }
+        if self.client.get_account_data(&pda::price_history(mint).0)?.is_none() {
+            ixs.push(instructions::init_price_history(payer, mint, DEFAULT_PRICE_HISTORY_INTERVAL));
+        }
+        Ok(ixs)
+    }
+
+    /// Every `T` account (limit orders, DCA plans), optionally filtered by
+    /// the owner and mint stored at the start of the account.
+    fn owned_accounts<T: AccountDeserialize + Discriminator>(
+        &self,
+        owner: Option<Pubkey>,
+        mint: Option<Pubkey>,
+    ) -> Result<Vec<(Pubkey, T)>> {
+        let mut filters = vec![Filter::Memcmp { offset: 0, bytes: T::DISCRIMINATOR.to_vec() }];
+        if let Some(owner) = owner {
+            filters.push(Filter::Memcmp { offset: 8, bytes: owner.to_bytes().to_vec() });
+        }
+        if let Some(mint) = mint {
+            filters.push(Filter::Memcmp { offset: 40, bytes: mint.to_bytes().to_vec() });
+        }
+        self.client
+            .get_program_accounts(&PROGRAM_ID, &filters)?
+            .into_iter()
+            .map(|(address, data)| Ok((address, accounts::decode(&data)?)))
+            .collect()
+    }
+
+    /// Curve for `mint`, fetched once per keeper pass.
+    fn cached_curve<'a>(
+        &self,
+        curves: &'a mut HashMap<Pubkey, BondingCurve>,
+        mint: &Pubkey,
+    ) -> Result<&'a BondingCurve> {
+        Ok(match curves.entry(*mint) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(accounts::bonding_curve(&self.fetch(&pda::bonding_curve(mint).0)?)?),
+        })
+    }
+
+    /// `(mint, token_program)` of each index component, in index order.
+    fn index_components(&self, index: &Pubkey) -> Result<Vec<(Pubkey, Pubkey)>> {
+        let index = accounts::index(&self.fetch(index)?)?;
+        index
+            .components
+            .iter()
+            .map(|c| {
+                let curve = accounts::bonding_curve(&self.fetch(&pda::bonding_curve(&c.mint).0)?)?;
+                Ok((c.mint, curve.token_program))
+            })
+            .collect()
+    }
+
+    fn fee_bps(&self) -> Result<u64> {
+        let config = accounts::platform_config(&self.fetch(&pda::platform_config().0)?)?;
+        let due = config.pending_fee_effective_at != 0 && unix_now()? as i64 >= config.pending_fee_effective_at;
+        Ok(u64::from(if due { config.pending_fee_bps } else { config.fee_bps }))
+    }
+
+    /// Simulates a read-only instruction and decodes its return data. The
+    /// RPC rejects a message with no signers, so a fee payer is named even
+    /// though nothing is signed: the signer when there is one, else the fee
+    /// vault (any funded system account will do).
+    fn view<T: AnchorDeserialize>(&self, ix: &Instruction) -> Result<T> {
+        let payer = self.signer().map(|s| s.pubkey()).unwrap_or_else(|_| pda::fee_vault().0);
+        let tx = Transaction::new_with_payer(std::slice::from_ref(ix), Some(&payer));
+        let sim = self.client.simulate_transaction(&tx)?;
+        if let Some(err) = sim.err {
+            bail!(describe_failure(&err, &sim.logs));
+        }
+        let data = sim.return_data.ok_or_else(|| anyhow!("instruction returned no data"))?;
+        Ok(T::try_from_slice(&data)?)
+    }
+
+    /// Simulate, then (unless `--dry-run`) sign, send and confirm.
+    fn execute(&self, signer: &Keypair, ixs: &[Instruction]) -> Result<()> {
+        let blockhash = self.client.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&signer.pubkey()), &[signer], blockhash);
+        let sim = self.client.simulate_transaction(&tx)?;
+        if let Some(err) = sim.err {
+            for line in &sim.logs {
+                eprintln!("  {line}");
+            }
+            bail!("simulation failed: {}", describe_failure(&err, &sim.logs));
+        }
+        if self.dry_run {
+            return print(json!({ "simulated": true, "units_consumed": sim.units_consumed, "logs": sim.logs }));
+        }
+        let signature = self.client.send_and_confirm(&tx)?;
+        print(json!({ "signature": signature.to_string(), "units_consumed": sim.units_consumed }))
+    }
+}
+
+fn describe_failure(err: &Value, logs: &[String]) -> String {
+    match error::from_logs(logs) {
+        Some(e) => format!("{e} ({err})"),
+        None => err.to_string(),
+    }
+}
+
+fn unix_now() -> Result<u64> {
+    Ok(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs())
+}
+
+fn apply_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let bps = u128::from(math::BPS_DENOMINATOR.saturating_sub(u64::from(slippage_bps)));
+    (u128::from(amount) * bps / u128::from(math::BPS_DENOMINATOR)) as u64
+}
+
+fn print(value: Value) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&value)?);
+    Ok(())
+}
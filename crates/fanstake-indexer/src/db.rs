@@ -0,0 +1,343 @@
+//! SQLite schema and writes. Every write for one transaction happens inside
+//! the same SQL transaction as its checkpoint, so a crash never half-applies
+//! a transaction or skips one.
+
+use anyhow::Result;
+use fanstake_sdk::{
+    AdminActionExecutedEvent, BondingCurve, Boost, BuybackEvent, GiftEvent, IndexTradeEvent, LiquidationEvent,
+    MilestoneReleasedEvent, TradeEvent,
+};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+pub fn open(path: &str) -> Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    conn.execute_batch(include_str!("schema.sql"))?;
+    Ok(conn)
+}
+
+pub struct Checkpoint {
+    pub signature: String,
+    pub slot: u64,
+}
+
+pub fn checkpoint(conn: &Connection) -> Result<Option<Checkpoint>> {
+    Ok(conn
+        .query_row("SELECT last_signature, last_slot FROM checkpoint WHERE id = 1", [], |row| {
+            Ok(Checkpoint { signature: row.get(0)?, slot: row.get(1)? })
+        })
+        .optional()?)
+}
+
+pub fn set_checkpoint(tx: &Transaction, signature: &str, slot: u64) -> Result<()> {
+    tx.execute(
+        "INSERT INTO checkpoint (id, last_signature, last_slot) VALUES (1, ?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET last_signature = ?1, last_slot = ?2",
+        params![signature, slot],
+    )?;
+    Ok(())
+}
+
+pub fn upsert_curve(tx: &Transaction, address: &str, curve: &BondingCurve, slot: u64) -> Result<()> {
+    tx.execute(
+        "INSERT INTO curves (mint, address, artist, name, symbol, uri, artist_share_bps, total_supply,
+             virtual_sol_reserves, virtual_token_reserves, real_sol_reserves, real_token_reserves,
+             price, is_active, created_at, updated_slot)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+         ON CONFLICT (mint) DO UPDATE SET
+             name = ?4, symbol = ?5, uri = ?6, total_supply = ?8,
+             virtual_sol_reserves = ?9, virtual_token_reserves = ?10,
+             real_sol_reserves = ?11, real_token_reserves = ?12,
+             price = ?13, is_active = ?14, updated_slot = ?16",
+        params![
+            curve.mint.to_string(),
+            address,
+            curve.artist.to_string(),
+            curve.name,
+            curve.symbol,
+            curve.uri,
+            curve.artist_share_bps,
+            curve.total_supply,
+            curve.virtual_sol_reserves,
+            curve.virtual_token_reserves,
+            curve.real_sol_reserves,
+            curve.real_token_reserves,
+            curve.spot_price(),
+            curve.is_active,
+            curve.created_at,
+            slot,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Records a trade and folds it into the curve's reserves and the trader's position.
+pub fn insert_trade(tx: &Transaction, signature: &str, event_index: usize, slot: u64, e: &TradeEvent) -> Result<()> {
+    let mint = e.mint.to_string();
+    let trader = e.trader.to_string();
+    let inserted = tx.execute(
+        "INSERT OR IGNORE INTO trades (signature, event_index, slot, mint, trader, side, sol_amount,
+             token_amount, fee, price_before, price_after, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            signature,
+            event_index,
+            slot,
+            mint,
+            trader,
+            if e.is_buy { "buy" } else { "sell" },
+            e.sol_amount,
+            e.token_amount,
+            e.fee,
+            e.price_before,
+            e.price_after,
+            e.timestamp,
+        ],
+    )?;
+    if inserted == 0 {
+        return Ok(());
+    }
+
+    tx.execute(
+        "UPDATE curves SET virtual_sol_reserves = ?2, virtual_token_reserves = ?3,
+             real_sol_reserves = ?4, real_token_reserves = ?5, price = ?6, updated_slot = ?7
+         WHERE mint = ?1 AND updated_slot <= ?7",
+        params![
+            mint,
+            e.virtual_sol_reserves,
+            e.virtual_token_reserves,
+            e.real_sol_reserves,
+            e.real_token_reserves,
+            e.price_after,
+            slot,
+        ],
+    )?;
+
+    let (bought, sold, spent, received) = if e.is_buy {
+        (e.token_amount, 0, e.sol_amount, 0)
+    } else {
+        (0, e.token_amount, 0, e.sol_amount - e.fee)
+    };
+    tx.execute(
+        "INSERT INTO positions (mint, wallet, tokens_bought, tokens_sold, sol_spent, sol_received,
+             fees_paid, trade_count, first_trade_at, last_trade_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1, ?8, ?8)
+         ON CONFLICT (mint, wallet) DO UPDATE SET
+             tokens_bought = tokens_bought + ?3, tokens_sold = tokens_sold + ?4,
+             sol_spent = sol_spent + ?5, sol_received = sol_received + ?6,
+             fees_paid = fees_paid + ?7, trade_count = trade_count + 1, last_trade_at = ?8",
+        params![mint, trader, bought, sold, spent, received, e.fee, e.timestamp],
+    )?;
+    Ok(())
+}
+
+/// Sets a holder's balance; zero balances are removed.
+pub fn set_holder(tx: &Transaction, mint: &str, owner: &str, amount: u64, slot: u64) -> Result<()> {
+    if amount == 0 {
+        tx.execute("DELETE FROM holders WHERE mint = ?1 AND owner = ?2", params![mint, owner])?;
+    } else {
+        tx.execute(
+            "INSERT INTO holders (mint, owner, amount, updated_slot) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (mint, owner) DO UPDATE SET amount = ?3, updated_slot = ?4",
+            params![mint, owner, amount, slot],
+        )?;
+    }
+    Ok(())
+}
+
+pub fn is_tracked_mint(tx: &Transaction, mint: &str) -> Result<bool> {
+    Ok(tx
+        .query_row("SELECT 1 FROM curves WHERE mint = ?1", [mint], |_| Ok(()))
+        .optional()?
+        .is_some())
+}
+
+/// The matching trade (trader = payer) is recorded separately from its `TradeEvent`.
+pub fn insert_gift(tx: &Transaction, signature: &str, event_index: usize, slot: u64, e: &GiftEvent) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO gifts (signature, event_index, slot, mint, payer, recipient, sol_amount,
+             token_amount, memo_hash, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.mint.to_string(),
+            e.payer.to_string(),
+            e.recipient.to_string(),
+            e.sol_amount,
+            e.token_amount,
+            e.memo_hash.as_ref().map(|h| h.as_slice()),
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_index_trade(
+    tx: &Transaction,
+    signature: &str,
+    event_index: usize,
+    slot: u64,
+    e: &IndexTradeEvent,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO index_trades (signature, event_index, slot, index_address, trader, side,
+             sol_amount, index_amount, index_supply, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.index.to_string(),
+            e.trader.to_string(),
+            if e.is_buy { "buy" } else { "redeem" },
+            e.sol_amount,
+            e.index_amount,
+            e.index_supply,
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+/// The collateral sale itself is recorded separately from its `TradeEvent`.
+pub fn insert_liquidation(
+    tx: &Transaction,
+    signature: &str,
+    event_index: usize,
+    slot: u64,
+    e: &LiquidationEvent,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO liquidations (signature, event_index, slot, mint, borrower, liquidator,
+             collateral, debt, proceeds, bonus, bad_debt, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.mint.to_string(),
+            e.borrower.to_string(),
+            e.liquidator.to_string(),
+            e.collateral,
+            e.debt,
+            e.proceeds,
+            e.bonus,
+            e.bad_debt,
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_milestone_release(
+    tx: &Transaction,
+    signature: &str,
+    event_index: usize,
+    slot: u64,
+    e: &MilestoneReleasedEvent,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO milestone_releases (signature, event_index, slot, mint, milestone, amount,
+             votes_for, votes_against, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.mint.to_string(),
+            e.milestone,
+            e.amount,
+            e.votes_for,
+            e.votes_against,
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_buyback(tx: &Transaction, signature: &str, event_index: usize, slot: u64, e: &BuybackEvent) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO buybacks (signature, event_index, slot, mint, caller, sol_amount, tokens_burned,
+             price_after, from_treasury, scheduled, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.mint.to_string(),
+            e.caller.to_string(),
+            e.sol_amount,
+            e.tokens_burned,
+            e.price_after,
+            e.from_treasury,
+            e.scheduled,
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn insert_admin_action(
+    tx: &Transaction,
+    signature: &str,
+    event_index: usize,
+    slot: u64,
+    e: &AdminActionExecutedEvent,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO admin_actions (signature, event_index, slot, proposal_id, action, approvals, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            signature,
+            event_index,
+            slot,
+            e.proposal_id,
+            format!("{:?}", e.action),
+            e.approvals,
+            e.timestamp,
+        ],
+    )?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn insert_fee_change(
+    tx: &Transaction,
+    signature: &str,
+    event_index: usize,
+    slot: u64,
+    kind: &str,
+    old_fee_bps: u16,
+    new_fee_bps: u16,
+    effective_at: i64,
+    timestamp: i64,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR IGNORE INTO fee_changes (signature, event_index, slot, kind, old_fee_bps, new_fee_bps,
+             effective_at, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![signature, event_index, slot, kind, old_fee_bps, new_fee_bps, effective_at, timestamp],
+    )?;
+    Ok(())
+}
+
+pub fn upsert_boost(tx: &Transaction, boost: &Boost, slot: u64) -> Result<()> {
+    tx.execute(
+        "INSERT INTO boosts (mint, tier, last_payer, started_at, expires_at, total_paid, updated_slot)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT (mint) DO UPDATE SET tier = ?2, last_payer = ?3, started_at = ?4,
+             expires_at = ?5, total_paid = ?6, updated_slot = ?7",
+        params![
+            boost.mint.to_string(),
+            boost.tier,
+            boost.last_payer.to_string(),
+            boost.started_at,
+            boost.expires_at,
+            boost.total_paid,
+            slot,
+        ],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! `fanstake-indexer` — follows FanStake transactions from any RPC (test
+//! validator included) and maintains a SQLite index of curves, trades,
+//! holders, positions and boosts for the web app to query.
+//!
+//! Transactions are applied oldest-first and the last applied signature is
+//! checkpointed with each one, so the indexer resumes where it stopped.
+//!
+//! Holder balances come from the token balance changes of FanStake
+//! transactions; plain SPL transfers between wallets aren't seen.
+
+mod db;
+mod process;
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use fanstake_sdk::rpc::{RpcClient, SignatureInfo};
+use fanstake_sdk::PROGRAM_ID;
+use solana_sdk::signature::Signature;
+
+/// Max signatures per `getSignaturesForAddress` page.
+const PAGE_LIMIT: usize = 1000;
+
+#[derive(Parser)]
+#[command(name = "fanstake-indexer", version, about = "Index FanStake activity into SQLite")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(short, long, env = "FANSTAKE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// SQLite database file
+    #[arg(short, long, env = "FANSTAKE_INDEX_DB", default_value = "fanstake-index.db")]
+    db: String,
+
+    /// On a fresh database, ignore transactions before this slot
+    #[arg(long, default_value_t = 0)]
+    start_slot: u64,
+
+    /// Poll interval once caught up
+    #[arg(long, default_value_t = 2000)]
+    poll_ms: u64,
+
+    /// Exit after catching up instead of following
+    #[arg(long)]
+    once: bool,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let client = RpcClient::new(&cli.url);
+    let mut conn = db::open(&cli.db)?;
+
+    loop {
+        let until = db::checkpoint(&conn)?;
+        if let Some(cp) = &until {
+            eprintln!("resuming after slot {} ({})", cp.slot, cp.signature);
+        }
+        let until_sig: Option<Signature> = until.as_ref().map(|cp| cp.signature.parse()).transpose()?;
+        let pending = pending_signatures(&client, until_sig.as_ref(), cli.start_slot)?;
+
+        for info in &pending {
+            let tx = conn.transaction()?;
+            if !info.failed {
+                match client.get_transaction(&info.signature)? {
+                    Some(result) => process::apply(&tx, &client, info, &result)?,
+                    None => eprintln!("{}: not available from RPC, skipping", info.signature),
+                }
+            }
+            db::set_checkpoint(&tx, &info.signature.to_string(), info.slot)?;
+            tx.commit()?;
+        }
+        if !pending.is_empty() {
+            eprintln!("indexed {} transactions through slot {}", pending.len(), pending[pending.len() - 1].slot);
+        }
+
+        if cli.once {
+            return Ok(());
+        }
+        sleep(Duration::from_millis(cli.poll_ms));
+    }
+}
+
+/// Program signatures newer than `until`, oldest first.
+fn pending_signatures(client: &RpcClient, until: Option<&Signature>, start_slot: u64) -> Result<Vec<SignatureInfo>> {
+    let mut all = Vec::new();
+    let mut before = None;
+    loop {
+        let page = client.get_signatures_for_address(&PROGRAM_ID, before.as_ref(), until, PAGE_LIMIT)?;
+        let done = page.len() < PAGE_LIMIT;
+        before = page.last().map(|info| info.signature);
+        let reached_start = page.iter().any(|info| info.slot < start_slot);
+        all.extend(page.into_iter().filter(|info| info.slot >= start_slot));
+        if done || reached_start {
+            break;
+        }
+    }
+    all.reverse();
+    Ok(all)
+}
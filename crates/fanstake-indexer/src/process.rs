@@ -0,0 +1,249 @@
+//! Applies one confirmed transaction to the index.
+
+use anchor_lang::Discriminator;
+use anyhow::{Context, Result};
+use fanstake::instruction;
+use fanstake_sdk::events::{self, Event};
+use fanstake_sdk::rpc::{decode_transaction, RpcClient, SignatureInfo};
+use fanstake_sdk::{accounts, PROGRAM_ID};
+use rusqlite::Transaction;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+pub fn apply(tx: &Transaction, client: &RpcClient, info: &SignatureInfo, result: &Value) -> Result<()> {
+    let signature = info.signature.to_string();
+    let slot = info.slot;
+    let meta = &result["meta"];
+
+    // Curve and boost metadata: re-read the account after instructions that
+    // change it. Both `bonding_curve` and `boost` are the first account.
+    let versioned = decode_transaction(&result["transaction"])?;
+    let keys = account_keys(versioned.message.static_account_keys(), meta);
+    for ix in versioned.message.instructions() {
+        if keys.get(usize::from(ix.program_id_index)) != Some(&PROGRAM_ID) || ix.data.len() < 8 {
+            continue;
+        }
+        let Some(&first) = ix.accounts.first().and_then(|&i| keys.get(usize::from(i))) else { continue };
+        let disc = &ix.data[..8];
+        if disc == instruction::CreateArtistToken::DISCRIMINATOR
+            || disc == instruction::CreateArtistToken2022::DISCRIMINATOR
+            || disc == instruction::UpdateArtistToken::DISCRIMINATOR
+        {
+            if let Some(curve) = fetch(client, &first, accounts::bonding_curve)? {
+                crate::db::upsert_curve(tx, &first.to_string(), &curve, slot)?;
+            }
+        } else if disc == instruction::PurchaseBoost::DISCRIMINATOR {
+            if let Some(boost) = fetch(client, &first, accounts::boost)? {
+                crate::db::upsert_boost(tx, &boost, slot)?;
+            }
+        }
+    }
+
+    // Events: trades, gifts, index trades, liquidations, milestone releases, buybacks, admin actions, fee changes
+    let logs: Vec<&str> = meta["logMessages"]
+        .as_array()
+        .map(|logs| logs.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    for (index, event) in events::from_logs(&logs).into_iter().enumerate() {
+        match event {
+            Event::Trade(trade) => crate::db::insert_trade(tx, &signature, index, slot, &trade)?,
+            Event::Gift(gift) => crate::db::insert_gift(tx, &signature, index, slot, &gift)?,
+            Event::IndexTrade(trade) => crate::db::insert_index_trade(tx, &signature, index, slot, &trade)?,
+            Event::Liquidation(liquidation) => {
+                crate::db::insert_liquidation(tx, &signature, index, slot, &liquidation)?
+            }
+            Event::MilestoneReleased(release) => {
+                crate::db::insert_milestone_release(tx, &signature, index, slot, &release)?
+            }
+            Event::Buyback(buyback) => crate::db::insert_buyback(tx, &signature, index, slot, &buyback)?,
+            Event::AdminActionExecuted(action) => {
+                crate::db::insert_admin_action(tx, &signature, index, slot, &action)?
+            }
+            Event::FeeChangeQueued(change) => crate::db::insert_fee_change(
+                tx,
+                &signature,
+                index,
+                slot,
+                "queued",
+                change.old_fee_bps,
+                change.new_fee_bps,
+                change.effective_at,
+                change.timestamp,
+            )?,
+            Event::FeeChangeActivated(change) => crate::db::insert_fee_change(
+                tx,
+                &signature,
+                index,
+                slot,
+                "activated",
+                change.old_fee_bps,
+                change.new_fee_bps,
+                change.timestamp,
+                change.timestamp,
+            )?,
+        }
+    }
+
+    // Holders: every token balance this transaction touched, for tracked mints.
+    // An account present before but missing after was closed.
+    for (field, is_post) in [("preTokenBalances", false), ("postTokenBalances", true)] {
+        for balance in meta[field].as_array().into_iter().flatten() {
+            let (Some(mint), Some(owner)) = (balance["mint"].as_str(), balance["owner"].as_str()) else { continue };
+            if !crate::db::is_tracked_mint(tx, mint)? {
+                continue;
+            }
+            let amount = if is_post {
+                balance["uiTokenAmount"]["amount"]
+                    .as_str()
+                    .context("token balance without amount")?
+                    .parse()?
+            } else {
+                0
+            };
+            crate::db::set_holder(tx, mint, owner, amount, slot)?;
+        }
+    }
+    Ok(())
+}
+
+/// Static keys followed by address-table lookups, in the runtime's order.
+fn account_keys(static_keys: &[Pubkey], meta: &Value) -> Vec<Pubkey> {
+    let loaded = ["writable", "readonly"].into_iter().flat_map(|kind| {
+        meta["loadedAddresses"][kind]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|k| k.as_str()?.parse().ok())
+    });
+    static_keys.iter().copied().chain(loaded).collect()
+}
+
+fn fetch<T>(
+    client: &RpcClient,
+    address: &Pubkey,
+    decode: fn(&[u8]) -> Result<T, fanstake_sdk::error::SdkError>,
+) -> Result<Option<T>> {
+    match client.get_account_data(address)? {
+        Some(data) => Ok(Some(decode(&data)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AccountDeserialize, Space};
+    use fanstake_sdk::BondingCurve;
+
+    /// `getTransaction` result for a 1 SOL buy of the legacy fixture mint by
+    /// a wallet that already held 25,000 tokens.
+    const BUY: &str = include_str!("../tests/fixtures/buy_transaction.json");
+    const MINT: &str = "US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx";
+    const TRADER: &str = "8SFqwqnq4whPhs8icwHA2hQg3hUoN1qrCLK1SBx3WKwe";
+
+    fn launched_curve() -> BondingCurve {
+        let mut data = BondingCurve::DISCRIMINATOR.to_vec();
+        data.resize(data.len() + BondingCurve::INIT_SPACE, 0);
+        let mut curve = BondingCurve::try_deserialize(&mut &data[..]).unwrap();
+        let mint = MINT.parse().unwrap();
+        let token_program = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".parse().unwrap();
+        curve.launch(mint, mint, "Legacy".into(), "LEG".into(), String::new(), 0, token_program, 0, 254);
+        curve
+    }
+
+    /// What `main` does for each signature: apply, checkpoint, commit.
+    fn index(conn: &mut rusqlite::Connection, result: &Value) {
+        let signature = decode_transaction(&result["transaction"]).unwrap().signatures[0];
+        let info = SignatureInfo { signature, slot: result["slot"].as_u64().unwrap(), failed: false, block_time: None };
+        // A trade never re-reads an account, so the client is never called
+        let client = RpcClient::new("http://127.0.0.1:1");
+        let tx = conn.transaction().unwrap();
+        apply(&tx, &client, &info, result).unwrap();
+        crate::db::set_checkpoint(&tx, &signature.to_string(), info.slot).unwrap();
+        tx.commit().unwrap();
+    }
+
+    #[test]
+    fn a_recorded_buy_fills_the_index() {
+        let result: Value = serde_json::from_str(BUY).unwrap();
+        let mut conn = crate::db::open(":memory:").unwrap();
+        let tx = conn.transaction().unwrap();
+        crate::db::upsert_curve(&tx, "4FU4sHuZJ7knDM67c5WwDD663sd2Sd83WMufuBPn31jx", &launched_curve(), 1).unwrap();
+        tx.commit().unwrap();
+
+        index(&mut conn, &result);
+        // Replaying the same transaction changes nothing
+        index(&mut conn, &result);
+
+        let trade: (String, u64, u64, u64, i64, u64) = conn
+            .query_row(
+                "SELECT side, sol_amount, token_amount, fee, event_index, slot FROM trades WHERE mint = ?1 AND trader = ?2",
+                [MINT, TRADER],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?)),
+            )
+            .unwrap();
+        assert_eq!(trade, ("buy".into(), 1_000_000_000, 34_277_831_558_567, 10_000_000, 0, 312_000_123));
+        let trades: u64 = conn.query_row("SELECT COUNT(*) FROM trades", [], |row| row.get(0)).unwrap();
+        assert_eq!(trades, 1);
+
+        let position: (u64, u64, u64, u64, u64) = conn
+            .query_row(
+                "SELECT tokens_bought, tokens_sold, sol_spent, fees_paid, trade_count FROM positions
+                 WHERE mint = ?1 AND wallet = ?2",
+                [MINT, TRADER],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+            )
+            .unwrap();
+        assert_eq!(position, (34_277_831_558_567, 0, 1_000_000_000, 10_000_000, 1));
+
+        let holder: (String, u64) = conn
+            .query_row("SELECT owner, amount FROM holders WHERE mint = ?1", [MINT], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!(holder, (TRADER.into(), 34_302_831_558_567));
+
+        let (real_sol, price): (u64, u64) = conn
+            .query_row("SELECT real_sol_reserves, price FROM curves WHERE mint = ?1", [MINT], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!((real_sol, price), (990_000_000, 29_834_734_389));
+
+        let checkpoint = crate::db::checkpoint(&conn).unwrap().unwrap();
+        assert_eq!(
+            checkpoint.signature,
+            "5n3LFE2VHVbBcgjMdNsPDtBSgN64hKzf2kDWbjwZtCNAsA5xVr37ApHRg7JXpGvYQfT9YfXghCN4Wd63BZZVS8wm"
+        );
+        assert_eq!(checkpoint.slot, 312_000_123);
+    }
+
+    #[test]
+    fn holders_of_untracked_mints_are_skipped() {
+        let result: Value = serde_json::from_str(BUY).unwrap();
+        let mut conn = crate::db::open(":memory:").unwrap();
+        index(&mut conn, &result);
+
+        let holders: u64 = conn.query_row("SELECT COUNT(*) FROM holders", [], |row| row.get(0)).unwrap();
+        assert_eq!(holders, 0);
+        assert!(crate::db::checkpoint(&conn).unwrap().is_some());
+    }
+
+    #[test]
+    fn events_come_from_fanstake_program_data() {
+        let result: Value = serde_json::from_str(BUY).unwrap();
+        let logs: Vec<&str> = result["meta"]["logMessages"].as_array().unwrap().iter().filter_map(Value::as_str).collect();
+
+        let events = events::from_logs(&logs);
+        let [Event::Trade(trade)] = &events[..] else { panic!("expected one trade") };
+        assert_eq!(trade.mint.to_string(), MINT);
+        assert_eq!(trade.trader.to_string(), TRADER);
+        assert!(trade.is_buy);
+        assert_eq!((trade.price_before, trade.price_after), (27_958_993_476, 29_834_734_389));
+        assert_eq!(trade.timestamp, 1_760_000_000);
+
+        // The same payload logged by another program isn't a FanStake event
+        let data = logs.iter().find(|line| line.starts_with("Program data: ")).unwrap();
+        let token = "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        let spoofed = [format!("{token} invoke [1]"), data.to_string(), format!("{token} success")];
+        assert!(events::from_logs(&spoofed).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+-- FanStake index. Amounts are raw u64s (lamports / token base units) stored
+-- as INTEGER; prices are PRICE_SCALE fixed-point.
+
+CREATE TABLE IF NOT EXISTS checkpoint (
+    id             INTEGER PRIMARY KEY CHECK (id = 1),
+    last_signature TEXT    NOT NULL,
+    last_slot      INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS curves (
+    mint                   TEXT PRIMARY KEY,
+    address                TEXT    NOT NULL,
+    artist                 TEXT    NOT NULL,
+    name                   TEXT    NOT NULL,
+    symbol                 TEXT    NOT NULL,
+    uri                    TEXT    NOT NULL,
+    artist_share_bps       INTEGER NOT NULL,
+    total_supply           INTEGER NOT NULL,
+    virtual_sol_reserves   INTEGER NOT NULL,
+    virtual_token_reserves INTEGER NOT NULL,
+    real_sol_reserves      INTEGER NOT NULL,
+    real_token_reserves    INTEGER NOT NULL,
+    price                  INTEGER NOT NULL,
+    is_active              INTEGER NOT NULL,
+    created_at             INTEGER NOT NULL,
+    updated_slot           INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS curves_artist ON curves (artist);
+
+CREATE TABLE IF NOT EXISTS trades (
+    signature    TEXT    NOT NULL,
+    event_index  INTEGER NOT NULL,
+    slot         INTEGER NOT NULL,
+    mint         TEXT    NOT NULL,
+    trader       TEXT    NOT NULL,
+    side         TEXT    NOT NULL CHECK (side IN ('buy', 'sell')),
+    sol_amount   INTEGER NOT NULL,
+    token_amount INTEGER NOT NULL,
+    fee          INTEGER NOT NULL,
+    price_before INTEGER NOT NULL,
+    price_after  INTEGER NOT NULL,
+    timestamp    INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS trades_mint ON trades (mint, slot DESC);
+CREATE INDEX IF NOT EXISTS trades_trader ON trades (trader, slot DESC);
+CREATE INDEX IF NOT EXISTS trades_slot ON trades (slot DESC);
+
+CREATE TABLE IF NOT EXISTS gifts (
+    signature    TEXT    NOT NULL,
+    event_index  INTEGER NOT NULL,
+    slot         INTEGER NOT NULL,
+    mint         TEXT    NOT NULL,
+    payer        TEXT    NOT NULL,
+    recipient    TEXT    NOT NULL,
+    sol_amount   INTEGER NOT NULL,
+    token_amount INTEGER NOT NULL,
+    memo_hash    BLOB,
+    timestamp    INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS gifts_recipient ON gifts (recipient, slot DESC);
+
+-- Index buys/redeems; the component trades land in `trades` with the same signature
+CREATE TABLE IF NOT EXISTS index_trades (
+    signature     TEXT    NOT NULL,
+    event_index   INTEGER NOT NULL,
+    slot          INTEGER NOT NULL,
+    index_address TEXT    NOT NULL,
+    trader        TEXT    NOT NULL,
+    side          TEXT    NOT NULL CHECK (side IN ('buy', 'redeem')),
+    sol_amount    INTEGER NOT NULL,
+    index_amount  INTEGER NOT NULL,
+    index_supply  INTEGER NOT NULL,
+    timestamp     INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS index_trades_index ON index_trades (index_address, slot DESC);
+
+-- Lending liquidations; the collateral sale lands in `trades` with the same signature
+CREATE TABLE IF NOT EXISTS liquidations (
+    signature   TEXT    NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot        INTEGER NOT NULL,
+    mint        TEXT    NOT NULL,
+    borrower    TEXT    NOT NULL,
+    liquidator  TEXT    NOT NULL,
+    collateral  INTEGER NOT NULL,
+    debt        INTEGER NOT NULL,
+    proceeds    INTEGER NOT NULL,
+    bonus       INTEGER NOT NULL,
+    bad_debt    INTEGER NOT NULL,
+    timestamp   INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS liquidations_mint ON liquidations (mint, slot DESC);
+
+-- Artist treasury milestones paid out after a passing holder vote
+CREATE TABLE IF NOT EXISTS milestone_releases (
+    signature     TEXT    NOT NULL,
+    event_index   INTEGER NOT NULL,
+    slot          INTEGER NOT NULL,
+    mint          TEXT    NOT NULL,
+    milestone     INTEGER NOT NULL,
+    amount        INTEGER NOT NULL,
+    votes_for     INTEGER NOT NULL,
+    votes_against INTEGER NOT NULL,
+    timestamp     INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS milestone_releases_mint ON milestone_releases (mint, milestone);
+
+-- Artist buyback-and-burns; the buy lands in `trades` with the same signature
+CREATE TABLE IF NOT EXISTS buybacks (
+    signature     TEXT    NOT NULL,
+    event_index   INTEGER NOT NULL,
+    slot          INTEGER NOT NULL,
+    mint          TEXT    NOT NULL,
+    caller        TEXT    NOT NULL,
+    sol_amount    INTEGER NOT NULL,
+    tokens_burned INTEGER NOT NULL,
+    price_after   INTEGER NOT NULL,
+    from_treasury INTEGER NOT NULL,
+    scheduled     INTEGER NOT NULL,
+    timestamp     INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS buybacks_mint ON buybacks (mint, slot DESC);
+
+-- Platform fee changes: one row when queued, another when they take effect
+CREATE TABLE IF NOT EXISTS fee_changes (
+    signature    TEXT    NOT NULL,
+    event_index  INTEGER NOT NULL,
+    slot         INTEGER NOT NULL,
+    kind         TEXT    NOT NULL CHECK (kind IN ('queued', 'activated')),
+    old_fee_bps  INTEGER NOT NULL,
+    new_fee_bps  INTEGER NOT NULL,
+    effective_at INTEGER NOT NULL,
+    timestamp    INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+-- Config changes executed by the admin group; `action` is its debug rendering
+CREATE TABLE IF NOT EXISTS admin_actions (
+    signature   TEXT    NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot        INTEGER NOT NULL,
+    proposal_id INTEGER NOT NULL,
+    action      TEXT    NOT NULL,
+    approvals   INTEGER NOT NULL,
+    timestamp   INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS holders (
+    mint         TEXT    NOT NULL,
+    owner        TEXT    NOT NULL,
+    amount       INTEGER NOT NULL,
+    updated_slot INTEGER NOT NULL,
+    PRIMARY KEY (mint, owner)
+);
+CREATE INDEX IF NOT EXISTS holders_owner ON holders (owner);
+
+CREATE TABLE IF NOT EXISTS positions (
+    mint           TEXT    NOT NULL,
+    wallet         TEXT    NOT NULL,
+    tokens_bought  INTEGER NOT NULL DEFAULT 0,
+    tokens_sold    INTEGER NOT NULL DEFAULT 0,
+    sol_spent      INTEGER NOT NULL DEFAULT 0,
+    sol_received   INTEGER NOT NULL DEFAULT 0,
+    fees_paid      INTEGER NOT NULL DEFAULT 0,
+    trade_count    INTEGER NOT NULL DEFAULT 0,
+    first_trade_at INTEGER NOT NULL,
+    last_trade_at  INTEGER NOT NULL,
+    PRIMARY KEY (mint, wallet)
+);
+CREATE INDEX IF NOT EXISTS positions_wallet ON positions (wallet);
+
+CREATE TABLE IF NOT EXISTS boosts (
+    mint         TEXT PRIMARY KEY,
+    tier         INTEGER NOT NULL,
+    last_payer   TEXT    NOT NULL,
+    started_at   INTEGER NOT NULL,
+    expires_at   INTEGER NOT NULL,
+    total_paid   INTEGER NOT NULL,
+    updated_slot INTEGER NOT NULL
+);
@@ -0,0 +1,432 @@
+//! Constant-product bonding curve math for FanStake.
+//!
+//! This is the single source of truth for trade quotes: the on-chain program
+//! calls these functions in `buy`/`sell`, and off-chain clients (SDK, CLI,
+//! bots) call the same code, so quotes match on-chain results to the lamport.
+//! `app/src/lib/curveMath.ts` is a line-for-line port for the web app.
+//!
+//! All functions are pure integer math, round down like the program does, and
+//! return `None` on overflow instead of panicking.
+
+#![no_std]
+
+/// Basis-point denominator (10_000 = 100%).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Token base units per whole token (6 decimals).
+pub const TOKEN_UNIT: u128 = 1_000_000;
+
+/// Prices are lamports per whole token as 1e9 fixed-point (`price / PRICE_SCALE` = lamports).
+pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+/// Seconds per year for lending interest (365 days).
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Fixed-point scale of lending borrow indices (1.0 = `BORROW_INDEX_SCALE`).
+pub const BORROW_INDEX_SCALE: u128 = 1_000_000_000_000;
+
+/// Reserve snapshot of a bonding curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveReserves {
+    pub virtual_sol: u64,
+    pub virtual_token: u64,
+    pub real_sol: u64,
+    pub real_token: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuyQuote {
+    pub sol_in: u64,       // Gross SOL paid by the buyer
+    pub fee: u64,          // Platform fee taken from `sol_in`
+    pub treasury: u64,     // Artist treasury cut, taken after the fee
+    pub sol_to_curve: u64, // SOL added to the reserves
+    pub tokens_out: u64,   // Tokens minted to the buyer
+    pub price_after: u64,  // Spot price after the trade
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SellQuote {
+    pub tokens_in: u64,     // Tokens burned from the seller
+    pub sol_out_gross: u64, // SOL removed from the reserves
+    pub fee: u64,           // Platform fee taken from `sol_out_gross`
+    pub sol_out: u64,       // SOL paid to the seller
+    pub price_after: u64,   // Spot price after the trade
+}
+
+impl CurveReserves {
+    /// Reserves after applying a buy quote.
+    pub fn after_buy(&self, quote: &BuyQuote) -> Option<Self> {
+        Some(Self {
+            virtual_sol: self.virtual_sol.checked_add(quote.sol_to_curve)?,
+            virtual_token: self.virtual_token.checked_sub(quote.tokens_out)?,
+            real_sol: self.real_sol.checked_add(quote.sol_to_curve)?,
+            real_token: self.real_token.checked_sub(quote.tokens_out)?,
+        })
+    }
+
+    /// Reserves after applying a sell quote.
+    pub fn after_sell(&self, quote: &SellQuote) -> Option<Self> {
+        Some(Self {
+            virtual_sol: self.virtual_sol.checked_sub(quote.sol_out_gross)?,
+            virtual_token: self.virtual_token.checked_add(quote.tokens_in)?,
+            real_sol: self.real_sol.checked_sub(quote.sol_out_gross)?,
+            real_token: self.real_token.checked_add(quote.tokens_in)?,
+        })
+    }
+
+    pub fn spot_price(&self) -> Option<u64> {
+        spot_price(self.virtual_sol, self.virtual_token)
+    }
+}
+
+/// Fee on `amount` at `fee_bps`, rounded down.
+pub fn fee_for(amount: u64, fee_bps: u64) -> Option<u64> {
+    amount.checked_mul(fee_bps)?.checked_div(BPS_DENOMINATOR)
+}
+
+/// Tokens out for a constant-product swap of `sol_in` (already net of fees).
+pub fn tokens_for_sol(reserves: &CurveReserves, sol_in: u64) -> Option<u64> {
+    let out = (sol_in as u128)
+        .checked_mul(reserves.virtual_token as u128)?
+        .checked_div((reserves.virtual_sol as u128).checked_add(sol_in as u128)?)?;
+    u64::try_from(out).ok()
+}
+
+/// Gross SOL out for a constant-product swap of `tokens_in` (before fees).
+pub fn sol_for_tokens(reserves: &CurveReserves, tokens_in: u64) -> Option<u64> {
+    let out = (tokens_in as u128)
+        .checked_mul(reserves.virtual_sol as u128)?
+        .checked_div((reserves.virtual_token as u128).checked_add(tokens_in as u128)?)?;
+    u64::try_from(out).ok()
+}
+
+/// Quote for buying with `sol_amount` lamports (fee included). Does not check
+/// `real_token` — the program rejects quotes with `tokens_out > real_token`.
+pub fn quote_buy(reserves: &CurveReserves, sol_amount: u64, fee_bps: u64) -> Option<BuyQuote> {
+    quote_buy_with_treasury(reserves, sol_amount, fee_bps, 0)
+}
+
+/// [`quote_buy`] on a curve that routes `treasury_bps` of each buy (after
+/// the fee) to the artist treasury instead of the reserves.
+pub fn quote_buy_with_treasury(
+    reserves: &CurveReserves,
+    sol_amount: u64,
+    fee_bps: u64,
+    treasury_bps: u64,
+) -> Option<BuyQuote> {
+    let fee = fee_for(sol_amount, fee_bps)?;
+    let after_fee = sol_amount.checked_sub(fee)?;
+    let treasury = fee_for(after_fee, treasury_bps)?;
+    let sol_to_curve = after_fee.checked_sub(treasury)?;
+    let tokens_out = tokens_for_sol(reserves, sol_to_curve)?;
+    let mut quote = BuyQuote {
+        sol_in: sol_amount,
+        fee,
+        treasury,
+        sol_to_curve,
+        tokens_out,
+        price_after: 0,
+    };
+    quote.price_after = spot_price(
+        reserves.virtual_sol.checked_add(sol_to_curve)?,
+        reserves.virtual_token.checked_sub(tokens_out)?,
+    )?;
+    Some(quote)
+}
+
+/// Quote for selling `token_amount` tokens. Does not check `real_sol` — the
+/// program rejects quotes with `sol_out_gross > real_sol`.
+pub fn quote_sell(reserves: &CurveReserves, token_amount: u64, fee_bps: u64) -> Option<SellQuote> {
+    let sol_out_gross = sol_for_tokens(reserves, token_amount)?;
+    let fee = fee_for(sol_out_gross, fee_bps)?;
+    let sol_out = sol_out_gross.checked_sub(fee)?;
+    let price_after = spot_price(
+        reserves.virtual_sol.checked_sub(sol_out_gross)?,
+        reserves.virtual_token.checked_add(token_amount)?,
+    )?;
+    Some(SellQuote {
+        tokens_in: token_amount,
+        sol_out_gross,
+        fee,
+        sol_out,
+        price_after,
+    })
+}
+
+/// Per-leg fee of a cross-curve swap: `fee_bps` less `discount_bps` of it.
+pub fn swap_fee_bps(fee_bps: u64, discount_bps: u64) -> Option<u64> {
+    pro_rata(fee_bps, BPS_DENOMINATOR.checked_sub(discount_bps)?, BPS_DENOMINATOR)
+}
+
+/// Quote for selling `amount_in` on `from` and buying on `to` with the
+/// proceeds, each leg at `leg_fee_bps` (see [`swap_fee_bps`]).
+pub fn quote_swap(
+    from: &CurveReserves,
+    to: &CurveReserves,
+    amount_in: u64,
+    leg_fee_bps: u64,
+) -> Option<(SellQuote, BuyQuote)> {
+    let sell = quote_sell(from, amount_in, leg_fee_bps)?;
+    let buy = quote_buy(to, sell.sol_out, leg_fee_bps)?;
+    Some((sell, buy))
+}
+
+/// Marginal price in lamports per whole token, `PRICE_SCALE` fixed-point.
+pub fn spot_price(virtual_sol: u64, virtual_token: u64) -> Option<u64> {
+    let price = (virtual_sol as u128)
+        .checked_mul(TOKEN_UNIT * PRICE_SCALE)?
+        .checked_div(virtual_token as u128)?;
+    u64::try_from(price).ok()
+}
+
+/// Fully diluted market cap in lamports: spot price × `total_supply`.
+pub fn market_cap(virtual_sol: u64, virtual_token: u64, total_supply: u64) -> Option<u64> {
+    let cap = (virtual_sol as u128)
+        .checked_mul(total_supply as u128)?
+        .checked_div(virtual_token as u128)?;
+    u64::try_from(cap).ok()
+}
+
+/// Value of `amount` token base units at the spot price, in lamports.
+pub fn token_value(virtual_sol: u64, virtual_token: u64, amount: u64) -> Option<u64> {
+    let value = (virtual_sol as u128)
+        .checked_mul(amount as u128)?
+        .checked_div(virtual_token as u128)?;
+    u64::try_from(value).ok()
+}
+
+/// Value of `amount` token base units at `price` (see `PRICE_SCALE`), in lamports.
+pub fn value_at_price(price: u64, amount: u64) -> Option<u64> {
+    let value = (price as u128)
+        .checked_mul(amount as u128)?
+        .checked_div(TOKEN_UNIT * PRICE_SCALE)?;
+    u64::try_from(value).ok()
+}
+
+/// `amount × numerator / denominator`, rounded down.
+pub fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> Option<u64> {
+    let share = (amount as u128)
+        .checked_mul(numerator as u128)?
+        .checked_div(denominator as u128)?;
+    u64::try_from(share).ok()
+}
+
+/// Index tokens minted for a deposit worth `deposit_value` lamports into a
+/// basket worth `basket_value` backing `supply` index tokens. The first
+/// deposit mints one index base unit per lamport.
+pub fn index_tokens_for_deposit(supply: u64, basket_value: u64, deposit_value: u64) -> Option<u64> {
+    if supply == 0 {
+        return Some(deposit_value);
+    }
+    pro_rata(supply, deposit_value, basket_value)
+}
+
+/// Share of a lending pool's assets that is lent out, in basis points.
+pub fn utilization_bps(borrowed: u64, idle: u64) -> Option<u64> {
+    let total = borrowed.checked_add(idle)?;
+    if total == 0 {
+        return Some(0);
+    }
+    pro_rata(borrowed, BPS_DENOMINATOR, total)
+}
+
+/// Annual borrow rate in basis points: `base_rate_bps` plus `slope_bps`
+/// scaled by utilization (the full slope applies at 100% utilization).
+pub fn borrow_rate_bps(utilization_bps: u64, base_rate_bps: u64, slope_bps: u64) -> Option<u64> {
+    base_rate_bps.checked_add(pro_rata(slope_bps, utilization_bps, BPS_DENOMINATOR)?)
+}
+
+/// Borrow index after `elapsed` seconds at an annual `rate_bps`. Interest
+/// compounds each time the index is accrued.
+pub fn accrue_borrow_index(index: u128, rate_bps: u64, elapsed: u64) -> Option<u128> {
+    let growth = index
+        .checked_mul(rate_bps as u128)?
+        .checked_mul(elapsed as u128)?
+        .checked_div(BPS_DENOMINATOR as u128 * SECONDS_PER_YEAR as u128)?;
+    index.checked_add(growth)
+}
+
+/// `debt` taken at borrow index `index_then`, grown to `index_now`. Rounds
+/// up so borrowers never owe less than they accrued.
+pub fn grow_debt(debt: u64, index_then: u128, index_now: u128) -> Option<u64> {
+    if index_then == 0 {
+        return None;
+    }
+    let grown = (debt as u128).checked_mul(index_now)?.div_ceil(index_then);
+    u64::try_from(grown).ok()
+}
+
+/// Pool shares minted for depositing `amount` lamports into a pool whose
+/// `total_shares` are backed by `total_assets`. The first deposit mints one
+/// share per lamport.
+pub fn shares_for_deposit(total_shares: u64, total_assets: u64, amount: u64) -> Option<u64> {
+    if total_shares == 0 || total_assets == 0 {
+        return Some(amount);
+    }
+    pro_rata(amount, total_shares, total_assets)
+}
+
+/// How far a buy moves the spot price, in basis points.
+pub fn buy_price_impact_bps(reserves: &CurveReserves, sol_amount: u64, fee_bps: u64) -> Option<u64> {
+    let before = reserves.spot_price()?;
+    let after = quote_buy(reserves, sol_amount, fee_bps)?.price_after;
+    impact_bps(before, after)
+}
+
+/// How far a sell moves the spot price, in basis points.
+pub fn sell_price_impact_bps(reserves: &CurveReserves, token_amount: u64, fee_bps: u64) -> Option<u64> {
+    let before = reserves.spot_price()?;
+    let after = quote_sell(reserves, token_amount, fee_bps)?.price_after;
+    impact_bps(before, after)
+}
+
+fn impact_bps(before: u64, after: u64) -> Option<u64> {
+    let diff = before.abs_diff(after) as u128;
+    let bps = diff.checked_mul(BPS_DENOMINATOR as u128)?.checked_div(before as u128)?;
+    u64::try_from(bps).ok()
+}
+
+/// Smallest gross SOL amount (fee included) whose buy would take every
+/// remaining real token — i.e. how much SOL is left until the curve sells out.
+/// The final buy may have to be trimmed slightly, since the program rejects
+/// buys whose `tokens_out` exceeds `real_token`.
+pub fn sol_to_graduation(reserves: &CurveReserves, fee_bps: u64) -> Option<u64> {
+    if reserves.real_token == 0 {
+        return Some(0);
+    }
+    // Net SOL `a` with floor(a * vT / (vS + a)) >= R  ⇔  a >= R * vS / (vT - R)
+    let remaining = reserves.real_token as u128;
+    let denom = (reserves.virtual_token as u128).checked_sub(remaining)?;
+    if denom == 0 {
+        return None;
+    }
+    let net = remaining
+        .checked_mul(reserves.virtual_sol as u128)?
+        .checked_add(denom - 1)?
+        / denom;
+    let net = u64::try_from(net).ok()?;
+
+    // Smallest gross amount whose post-fee value covers `net`
+    let keep_bps = BPS_DENOMINATOR.checked_sub(fee_bps)?;
+    if keep_bps == 0 {
+        return None;
+    }
+    let covers = |gross: u64| -> Option<bool> { Some(gross.checked_sub(fee_for(gross, fee_bps)?)? >= net) };
+    let mut gross = u64::try_from(
+        (net as u128 * BPS_DENOMINATOR as u128).div_ceil(keep_bps as u128),
+    )
+    .ok()?;
+    while gross > 0 && covers(gross - 1)? {
+        gross -= 1;
+    }
+    while !covers(gross)? {
+        gross = gross.checked_add(1)?;
+    }
+    Some(gross)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reserves of a freshly launched curve.
+    const LAUNCH: CurveReserves = CurveReserves {
+        virtual_sol: 30_000_000_000,
+        virtual_token: 1_073_000_000_000_000,
+        real_sol: 0,
+        real_token: 793_100_000_000_000,
+    };
+
+    #[test]
+    fn quote_buy_at_launch() {
+        let quote = quote_buy(&LAUNCH, 1_000_000_000, 100).unwrap();
+        assert_eq!(
+            quote,
+            BuyQuote {
+                sol_in: 1_000_000_000,
+                fee: 10_000_000,
+                treasury: 0,
+                sol_to_curve: 990_000_000,
+                tokens_out: 34_277_831_558_567,
+                price_after: 29_834_734_389,
+            }
+        );
+        assert_eq!(LAUNCH.spot_price(), Some(27_958_993_476));
+    }
+
+    #[test]
+    fn quote_buy_rounds_down_and_rejects_bad_input() {
+        let dust = quote_buy(&LAUNCH, 99, 100).unwrap();
+        assert_eq!((dust.fee, dust.sol_to_curve), (0, 99));
+        let zero = quote_buy(&LAUNCH, 0, 100).unwrap();
+        assert_eq!((zero.tokens_out, zero.price_after), (0, LAUNCH.spot_price().unwrap()));
+        // Fee above 100% and fee overflow
+        assert_eq!(quote_buy(&LAUNCH, 1_000_000, BPS_DENOMINATOR + 1), None);
+        assert_eq!(quote_buy(&LAUNCH, u64::MAX, 100), None);
+    }
+
+    #[test]
+    fn quote_buy_with_treasury_splits_after_fee() {
+        assert_eq!(quote_buy_with_treasury(&LAUNCH, 1_000_000_000, 100, 0), quote_buy(&LAUNCH, 1_000_000_000, 100));
+
+        let quote = quote_buy_with_treasury(&LAUNCH, 1_000_000_000, 100, 500).unwrap();
+        assert_eq!(quote.fee, 10_000_000);
+        assert_eq!(quote.treasury, 49_500_000);
+        assert_eq!(quote.sol_to_curve, 940_500_000);
+        assert_eq!(quote.tokens_out, 32_616_037_232_753);
+        assert_eq!(quote.price_after, 29_739_501_095);
+
+        let all = quote_buy_with_treasury(&LAUNCH, 1_000_000_000, 100, BPS_DENOMINATOR).unwrap();
+        assert_eq!((all.treasury, all.sol_to_curve, all.tokens_out), (990_000_000, 0, 0));
+        assert_eq!(quote_buy_with_treasury(&LAUNCH, 1_000_000_000, 100, BPS_DENOMINATOR + 1), None);
+    }
+
+    #[test]
+    fn quote_sell_after_buy_never_returns_more() {
+        let buy = quote_buy(&LAUNCH, 1_000_000_000, 100).unwrap();
+        let after = LAUNCH.after_buy(&buy).unwrap();
+        let sell = quote_sell(&after, buy.tokens_out, 100).unwrap();
+        assert_eq!(
+            sell,
+            SellQuote {
+                tokens_in: buy.tokens_out,
+                sol_out_gross: 989_999_999,
+                fee: 9_899_999,
+                sol_out: 980_100_000,
+                price_after: 27_958_993_477,
+            }
+        );
+        assert!(sell.sol_out_gross <= buy.sol_to_curve);
+        let back = after.after_sell(&sell).unwrap();
+        assert_eq!(back.real_token, LAUNCH.real_token);
+        assert_eq!(back.real_sol, 1);
+    }
+
+    #[test]
+    fn quote_sell_edge_cases() {
+        let zero = quote_sell(&LAUNCH, 0, 100).unwrap();
+        assert_eq!((zero.sol_out_gross, zero.fee, zero.sol_out), (0, 0, 0));
+        let empty = CurveReserves { virtual_sol: 0, virtual_token: 0, real_sol: 0, real_token: 0 };
+        assert_eq!(quote_sell(&empty, 0, 100), None);
+        assert_eq!(quote_sell(&LAUNCH, 1_000_000_000_000, BPS_DENOMINATOR + 1), None);
+    }
+
+    #[test]
+    fn pro_rata_rounds_down_and_checks_bounds() {
+        assert_eq!(pro_rata(10, 1, 3), Some(3));
+        assert_eq!(pro_rata(u64::MAX, 3, 3), Some(u64::MAX));
+        assert_eq!(pro_rata(1, 1, 0), None);
+        assert_eq!(pro_rata(u64::MAX, 2, 1), None);
+        assert_eq!(pro_rata(0, 5, 7), Some(0));
+    }
+
+    #[test]
+    fn grow_debt_rounds_up() {
+        assert_eq!(grow_debt(1_000, BORROW_INDEX_SCALE, BORROW_INDEX_SCALE), Some(1_000));
+        assert_eq!(grow_debt(1, 3, 4), Some(2));
+        assert_eq!(grow_debt(3, 3, 4), Some(4));
+        assert_eq!(grow_debt(0, 3, 4), Some(0));
+        assert_eq!(grow_debt(1_000, 0, BORROW_INDEX_SCALE), None);
+        assert_eq!(grow_debt(u64::MAX, 1, 2), None);
+    }
+}
@@ -0,0 +1,217 @@
+//! Account decoders. Each one checks the 8-byte Anchor discriminator before
+//! deserializing, so passing the wrong account fails loudly instead of
+//! returning garbage.
+
+use anchor_lang::{AccountDeserialize, ZeroCopy};
+
+use crate::error::SdkError;
+use crate::{
+    AdminGroup, AdminProposal, Airdrop, ArtistTreasury, Badge, BondingCurve, Boost, Buyback, BuyerRecord, CurveStats, DcaPlan, Index,
+    LenderPosition, LendingPool, LimitOrder, Loan, PlatformConfig, Position, PriceHistory, TreasuryVote,
+    VestingSchedule,
+};
+
+/// Decodes any Borsh-serialized FanStake account from raw account data.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T, SdkError> {
+    let mut data = data;
+    T::try_deserialize(&mut data).map_err(|e| SdkError::Decode(e.to_string()))
+}
+
+/// Decodes a zero-copy FanStake account, copying it out of the raw data.
+pub fn decode_zero_copy<T: ZeroCopy + anchor_lang::Discriminator>(data: &[u8]) -> Result<T, SdkError> {
+    let disc = T::DISCRIMINATOR;
+    if data.len() < disc.len() || &data[..disc.len()] != disc {
+        return Err(SdkError::Decode("account discriminator mismatch".into()));
+    }
+    let body = &data[disc.len()..];
+    let size = std::mem::size_of::<T>();
+    if body.len() < size {
+        return Err(SdkError::Decode("account data too small".into()));
+    }
+    Ok(anchor_lang::__private::bytemuck::pod_read_unaligned(&body[..size]))
+}
+
+pub fn bonding_curve(data: &[u8]) -> Result<BondingCurve, SdkError> {
+    decode(data)
+}
+
+pub fn vesting_schedule(data: &[u8]) -> Result<VestingSchedule, SdkError> {
+    decode(data)
+}
+
+pub fn platform_config(data: &[u8]) -> Result<PlatformConfig, SdkError> {
+    decode(data)
+}
+
+pub fn curve_stats(data: &[u8]) -> Result<CurveStats, SdkError> {
+    decode(data)
+}
+
+pub fn buyer_record(data: &[u8]) -> Result<BuyerRecord, SdkError> {
+    decode(data)
+}
+
+pub fn position(data: &[u8]) -> Result<Position, SdkError> {
+    decode(data)
+}
+
+pub fn badge(data: &[u8]) -> Result<Badge, SdkError> {
+    decode(data)
+}
+
+pub fn boost(data: &[u8]) -> Result<Boost, SdkError> {
+    decode(data)
+}
+
+pub fn airdrop(data: &[u8]) -> Result<Airdrop, SdkError> {
+    decode(data)
+}
+
+pub fn limit_order(data: &[u8]) -> Result<LimitOrder, SdkError> {
+    decode(data)
+}
+
+pub fn dca_plan(data: &[u8]) -> Result<DcaPlan, SdkError> {
+    decode(data)
+}
+
+pub fn lending_pool(data: &[u8]) -> Result<LendingPool, SdkError> {
+    decode(data)
+}
+
+pub fn lender_position(data: &[u8]) -> Result<LenderPosition, SdkError> {
+    decode(data)
+}
+
+pub fn loan(data: &[u8]) -> Result<Loan, SdkError> {
+    decode(data)
+}
+
+pub fn artist_treasury(data: &[u8]) -> Result<ArtistTreasury, SdkError> {
+    decode(data)
+}
+
+pub fn treasury_vote(data: &[u8]) -> Result<TreasuryVote, SdkError> {
+    decode(data)
+}
+
+pub fn buyback(data: &[u8]) -> Result<Buyback, SdkError> {
+    decode(data)
+}
+
+pub fn admin_group(data: &[u8]) -> Result<AdminGroup, SdkError> {
+    decode(data)
+}
+
+pub fn admin_proposal(data: &[u8]) -> Result<AdminProposal, SdkError> {
+    decode(data)
+}
+
+pub fn index(data: &[u8]) -> Result<Index, SdkError> {
+    decode(data)
+}
+
+pub fn price_history(data: &[u8]) -> Result<PriceHistory, SdkError> {
+    decode_zero_copy(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::{AccountSerialize, Discriminator, Space};
+
+    /// A freshly allocated account: discriminator, then zeroes.
+    fn blank<T: AccountDeserialize + Discriminator + Space>() -> T {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.resize(T::DISCRIMINATOR.len() + T::INIT_SPACE, 0);
+        decode(&data).unwrap()
+    }
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn bonding_curve_round_trips() {
+        let mut curve: BondingCurve = blank();
+        let token_program = anchor_spl::token_2022::ID;
+        curve.launch(
+            Pubkey::new_from_array([1; 32]),
+            Pubkey::new_from_array([2; 32]),
+            "Artist".into(),
+            "ART".into(),
+            "https://example.com/art.json".into(),
+            500,
+            token_program,
+            1_700_000_000,
+            253,
+        );
+        curve.treasury_bps = 1_000;
+        curve.eligible_tokens = 42;
+
+        let decoded = bonding_curve(&serialize(&curve)).unwrap();
+        assert_eq!(decoded.artist, curve.artist);
+        assert_eq!(decoded.mint, curve.mint);
+        assert_eq!((decoded.name.as_str(), decoded.symbol.as_str()), ("Artist", "ART"));
+        assert_eq!(decoded.uri, curve.uri);
+        assert_eq!(decoded.virtual_sol_reserves, curve.virtual_sol_reserves);
+        assert_eq!(decoded.virtual_token_reserves, curve.virtual_token_reserves);
+        assert_eq!(decoded.real_token_reserves, curve.real_token_reserves);
+        assert_eq!(decoded.artist_share_bps, 500);
+        assert!(decoded.is_active);
+        assert_eq!((decoded.created_at, decoded.bump), (1_700_000_000, 253));
+        assert_eq!(decoded.token_program, token_program);
+        assert_eq!(decoded.treasury_bps, 1_000);
+        assert_eq!(decoded.version, curve.version);
+        assert_eq!(decoded.eligible_tokens, 42);
+    }
+
+    #[test]
+    fn vesting_schedule_round_trips() {
+        let vesting = VestingSchedule {
+            mint: Pubkey::new_from_array([7; 32]),
+            artist: Pubkey::new_from_array([11; 32]),
+            vesting_end: 1_700_086_400,
+            bump: 254,
+            version: 1,
+        };
+        let decoded = vesting_schedule(&serialize(&vesting)).unwrap();
+        assert_eq!((decoded.mint, decoded.artist), (vesting.mint, vesting.artist));
+        assert_eq!((decoded.vesting_end, decoded.bump, decoded.version), (1_700_086_400, 254, 1));
+    }
+
+    #[test]
+    fn platform_config_round_trips() {
+        let mut config: PlatformConfig = blank();
+        config.authority = Pubkey::new_from_array([12; 32]);
+        config.fee_bps = 250;
+        config.fee_vault = Pubkey::new_from_array([13; 32]);
+        config.total_artists = 7;
+        config.boost_tiers[1].price_lamports = 1_000_000;
+        config.admin_group = Pubkey::new_from_array([14; 32]);
+        config.paused = true;
+        config.pending_fee_bps = 300;
+        config.pending_fee_effective_at = 1_700_000_000;
+        config.pending_delay_effective_at = 1_700_000_001;
+
+        let decoded = platform_config(&serialize(&config)).unwrap();
+        assert_eq!((decoded.authority, decoded.fee_vault), (config.authority, config.fee_vault));
+        assert_eq!((decoded.fee_bps, decoded.total_artists), (250, 7));
+        assert_eq!(decoded.boost_tiers[1].price_lamports, 1_000_000);
+        assert_eq!(decoded.admin_group, config.admin_group);
+        assert!(decoded.paused);
+        assert_eq!((decoded.pending_fee_bps, decoded.pending_fee_effective_at), (300, 1_700_000_000));
+        assert_eq!(decoded.pending_delay_effective_at, 1_700_000_001);
+    }
+
+    #[test]
+    fn decoders_reject_other_accounts() {
+        let vesting: VestingSchedule = blank();
+        assert!(matches!(bonding_curve(&serialize(&vesting)), Err(SdkError::Decode(_))));
+        assert!(platform_config(&[]).is_err());
+        assert!(price_history(&serialize(&vesting)).is_err());
+    }
+}
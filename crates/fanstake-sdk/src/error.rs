@@ -0,0 +1,142 @@
+//! Typed errors. Program failures surface as `custom program error: 0x17..`
+//! (or `Error Number: 60xx` in logs); these helpers turn them back into
+//! [`FanStakeError`] variants.
+
+use crate::FanStakeError;
+
+/// Anchor offsets user error codes by 6000.
+pub use anchor_lang::error::ERROR_CODE_OFFSET;
+
+/// Every variant, in declaration order (index = code - ERROR_CODE_OFFSET).
+const ALL: &[FanStakeError] = &[
+    FanStakeError::NameTooLong,
+    FanStakeError::SymbolTooLong,
+    FanStakeError::UriTooLong,
+    FanStakeError::ArtistShareTooHigh,
+    FanStakeError::CurveNotActive,
+    FanStakeError::InvalidAmount,
+    FanStakeError::SlippageExceeded,
+    FanStakeError::InsufficientTokens,
+    FanStakeError::InsufficientSol,
+    FanStakeError::Unauthorized,
+    FanStakeError::TokensStillVesting,
+    FanStakeError::InvalidRecipientCount,
+    FanStakeError::InvalidExpiry,
+    FanStakeError::AirdropExpired,
+    FanStakeError::AirdropNotExpired,
+    FanStakeError::InvalidRecipientIndex,
+    FanStakeError::AlreadyClaimed,
+    FanStakeError::InvalidMerkleProof,
+    FanStakeError::InvalidBoostTier,
+    FanStakeError::TwapWindowUnavailable,
+    FanStakeError::InvalidInterval,
+    FanStakeError::MathOverflow,
+    FanStakeError::BadgeNotEarned,
+    FanStakeError::InvalidIndexComponents,
+    FanStakeError::IndexAccountMismatch,
+    FanStakeError::SameCurve,
+    FanStakeError::InvalidFeeDiscount,
+    FanStakeError::MissingOrderEscrow,
+    FanStakeError::OrderNotTriggered,
+    FanStakeError::InvalidDcaSchedule,
+    FanStakeError::InsufficientDcaBalance,
+    FanStakeError::DcaNotDue,
+    FanStakeError::DcaPlanComplete,
+    FanStakeError::DcaPriceAboveMax,
+    FanStakeError::InvalidLendingParams,
+    FanStakeError::InsufficientLiquidity,
+    FanStakeError::InsufficientShares,
+    FanStakeError::InsufficientCollateral,
+    FanStakeError::LoanUnhealthy,
+    FanStakeError::LoanHealthy,
+    FanStakeError::InvalidTreasuryConfig,
+    FanStakeError::CurveAlreadyTrading,
+    FanStakeError::MilestoneNotNext,
+    FanStakeError::VoteAlreadyOpen,
+    FanStakeError::VoteNotOpen,
+    FanStakeError::VoteStillOpen,
+    FanStakeError::ArtistCannotVote,
+    FanStakeError::InsufficientTreasuryBalance,
+    FanStakeError::TreasuryFailed,
+    FanStakeError::TreasuryActive,
+    FanStakeError::InvalidBuybackConfig,
+    FanStakeError::BuybackRateLimited,
+    FanStakeError::BuybackPriceAboveMax,
+    FanStakeError::BuybackNotDue,
+    FanStakeError::InsufficientBuybackBalance,
+    FanStakeError::InvalidAdminGroup,
+    FanStakeError::NotAdminMember,
+    FanStakeError::AlreadyApproved,
+    FanStakeError::ProposalNotApproved,
+    FanStakeError::ProposalClosed,
+    FanStakeError::AdminGroupRequired,
+    FanStakeError::PlatformPaused,
+    FanStakeError::InvalidPlatformFee,
+    FanStakeError::FeeChangeTooSoon,
+    FanStakeError::FeeChangeNotDue,
+    FanStakeError::AlreadyMigrated,
+    FanStakeError::MetadataAccountsMissing,
+    FanStakeError::TokensNotEligible,
+    FanStakeError::NotTransferring,
+    FanStakeError::UnexpectedOrderEscrow,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SdkError {
+    #[error("failed to decode account: {0}")]
+    Decode(String),
+    #[error("program error {}: {0}", u32::from(*.0))]
+    Program(FanStakeError),
+}
+
+/// Maps a custom program error code (e.g. from `InstructionError::Custom`) to its variant.
+pub fn from_code(code: u32) -> Option<FanStakeError> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)? as usize;
+    ALL.get(index).copied()
+}
+
+/// Finds the first FanStake error in a transaction's log messages
+/// (`... Error Number: 6007. Error Message: ...`).
+pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Option<FanStakeError> {
+    logs.iter().find_map(|line| {
+        let (_, rest) = line.as_ref().split_once("Error Number: ")?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        from_code(digits.parse().ok()?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_is_in_declaration_order() {
+        for (i, error) in ALL.iter().enumerate() {
+            assert_eq!(u32::from(*error), ERROR_CODE_OFFSET + i as u32, "{error:?}");
+        }
+        // With the order checked, this only holds if no variant is missing
+        let last = FanStakeError::UnexpectedOrderEscrow;
+        assert_eq!(ALL.last().map(|e| u32::from(*e)), Some(u32::from(last)));
+    }
+
+    #[test]
+    fn codes_map_back_to_variants() {
+        let code = u32::from(FanStakeError::SlippageExceeded);
+        assert_eq!(code, 6006);
+        assert!(matches!(from_code(code), Some(FanStakeError::SlippageExceeded)));
+        assert!(from_code(ERROR_CODE_OFFSET - 1).is_none());
+        assert!(from_code(ERROR_CODE_OFFSET + ALL.len() as u32).is_none());
+    }
+
+    #[test]
+    fn errors_are_found_in_logs() {
+        let logs = [
+            "Program JCAt7JFiHxMBQ9TcEZYbWkp2GZpF3ZbdYdwD5ZBP6Nkf invoke [1]",
+            "Program log: Instruction: Sell",
+            "Program log: AnchorError thrown in programs/fanstake/src/lib.rs:1240. Error Code: SlippageExceeded. Error Number: 6006. Error Message: Slippage tolerance exceeded..",
+            "Program JCAt7JFiHxMBQ9TcEZYbWkp2GZpF3ZbdYdwD5ZBP6Nkf failed: custom program error: 0x1776",
+        ];
+        assert!(matches!(from_logs(&logs), Some(FanStakeError::SlippageExceeded)));
+        assert!(from_logs(&logs[..2]).is_none());
+    }
+}
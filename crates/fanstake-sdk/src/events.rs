@@ -0,0 +1,84 @@
+//! Decoders for events the program `emit!`s (`Program data: <base64>` log lines).
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+use crate::{
+    AdminActionExecutedEvent, BuybackEvent, FeeChangeActivatedEvent, FeeChangeQueuedEvent, GiftEvent, IndexTradeEvent, LiquidationEvent, MilestoneReleasedEvent, TradeEvent, PROGRAM_ID,
+};
+
+pub enum Event {
+    Trade(TradeEvent),
+    Gift(GiftEvent),
+    IndexTrade(IndexTradeEvent),
+    Liquidation(LiquidationEvent),
+    MilestoneReleased(MilestoneReleasedEvent),
+    Buyback(BuybackEvent),
+    AdminActionExecuted(AdminActionExecutedEvent),
+    FeeChangeQueued(FeeChangeQueuedEvent),
+    FeeChangeActivated(FeeChangeActivatedEvent),
+}
+
+/// Decodes one event payload (discriminator + Borsh body).
+pub fn decode(data: &[u8]) -> Option<Event> {
+    let (disc, mut body) = data.split_at_checked(8)?;
+    if disc == TradeEvent::DISCRIMINATOR {
+        return TradeEvent::deserialize(&mut body).ok().map(Event::Trade);
+    }
+    if disc == GiftEvent::DISCRIMINATOR {
+        return GiftEvent::deserialize(&mut body).ok().map(Event::Gift);
+    }
+    if disc == IndexTradeEvent::DISCRIMINATOR {
+        return IndexTradeEvent::deserialize(&mut body).ok().map(Event::IndexTrade);
+    }
+    if disc == LiquidationEvent::DISCRIMINATOR {
+        return LiquidationEvent::deserialize(&mut body).ok().map(Event::Liquidation);
+    }
+    if disc == MilestoneReleasedEvent::DISCRIMINATOR {
+        return MilestoneReleasedEvent::deserialize(&mut body).ok().map(Event::MilestoneReleased);
+    }
+    if disc == BuybackEvent::DISCRIMINATOR {
+        return BuybackEvent::deserialize(&mut body).ok().map(Event::Buyback);
+    }
+    if disc == AdminActionExecutedEvent::DISCRIMINATOR {
+        return AdminActionExecutedEvent::deserialize(&mut body).ok().map(Event::AdminActionExecuted);
+    }
+    if disc == FeeChangeQueuedEvent::DISCRIMINATOR {
+        return FeeChangeQueuedEvent::deserialize(&mut body).ok().map(Event::FeeChangeQueued);
+    }
+    if disc == FeeChangeActivatedEvent::DISCRIMINATOR {
+        return FeeChangeActivatedEvent::deserialize(&mut body).ok().map(Event::FeeChangeActivated);
+    }
+    None
+}
+
+/// All FanStake events in a transaction's logs, in emission order. Only
+/// `Program data:` lines logged while FanStake is the executing program are
+/// considered, so look-alike payloads from other programs are ignored.
+pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Vec<Event> {
+    let program_id = PROGRAM_ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for line in logs {
+        let line = line.as_ref();
+        if let Some(rest) = line.strip_prefix("Program data: ") {
+            if stack.last() == Some(&program_id.as_str()) {
+                if let Some(event) = BASE64.decode(rest).ok().as_deref().and_then(decode) {
+                    events.push(event);
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let (Some(id), Some(status)) = (words.next(), words.next()) else { continue };
+            match status {
+                "invoke" => stack.push(id),
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
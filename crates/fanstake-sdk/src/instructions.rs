@@ -0,0 +1,1372 @@
+//! Instruction builders. Each returns a ready-to-sign [`Instruction`] with
+//! PDAs derived and Anchor discriminators encoded.
+
+use anchor_lang::prelude::{Pubkey, Rent};
+use anchor_lang::solana_program::sysvar::SysvarId;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token, metadata, token, token_2022};
+use fanstake::{accounts, instruction};
+
+use crate::{
+    pda, AdminAction, BadgeKind, BuybackConfig, DcaPlan, IndexComponent, LendingParams, LimitOrder, MilestoneInput, OrderSide, PROGRAM_ID,
+};
+
+/// Decimals of every artist mint
+const TOKEN_DECIMALS: u8 = 6;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn initialize(authority: &Pubkey, platform_fee_bps: u16) -> Instruction {
+    build(
+        accounts::Initialize {
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize { platform_fee_bps },
+    )
+}
+
+/// `mint` must be a fresh keypair that also signs the transaction.
+pub fn create_artist_token_2022(
+    artist: &Pubkey,
+    mint: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    artist_share_bps: u16,
+) -> Instruction {
+    build(
+        accounts::CreateArtistToken2022 {
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            artist: *artist,
+            artist_token_account: pda::token_account_with_program(artist, mint, &token_2022::ID),
+            artist_vesting: pda::artist_vesting(mint).0,
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            extra_account_meta_list: pda::extra_account_metas(mint).0,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateArtistToken2022 { name, symbol, uri, artist_share_bps },
+    )
+}
+
+/// `mint` must be a fresh keypair that also signs the transaction.
+pub fn create_artist_token(
+    artist: &Pubkey,
+    mint: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    artist_share_bps: u16,
+) -> Instruction {
+    build(
+        accounts::CreateArtistToken {
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            artist: *artist,
+            artist_token_account: pda::token_account(artist, mint),
+            artist_vesting: pda::artist_vesting(mint).0,
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            metadata: pda::metadata(mint).0,
+            token_metadata_program: metadata::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: Rent::id(),
+        },
+        instruction::CreateArtistToken { name, symbol, uri, artist_share_bps },
+    )
+}
+
+pub fn claim_artist_share(artist: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimArtistShare {
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            artist: *artist,
+            artist_token_account: pda::token_account(artist, mint),
+            artist_vesting: pda::artist_vesting(mint).0,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimArtistShare {},
+    )
+}
+
+/// Also updates the token metadata: Metaplex for SPL Token curves (call
+/// [`init_token_metadata`] first if the curve has none), the mint's own
+/// metadata extension for Token-2022 curves.
+pub fn update_artist_token(artist: &Pubkey, mint: &Pubkey, token_program: &Pubkey, new_uri: String) -> Instruction {
+    let is_2022 = *token_program == token_2022::ID;
+    build(
+        accounts::UpdateArtistToken {
+            bonding_curve: pda::bonding_curve(mint).0,
+            artist: *artist,
+            metadata: pda::metadata(mint).0,
+            token_metadata_program: (!is_2022).then_some(metadata::ID),
+            mint: *mint,
+            token_2022_program: is_2022.then_some(token_2022::ID),
+        },
+        instruction::UpdateArtistToken { new_uri },
+    )
+}
+
+/// Creates `wallet`'s token account for `mint` if it doesn't exist yet;
+/// prepend it to a first [`buy`].
+pub fn create_token_account_idempotent(
+    payer: &Pubkey,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        payer,
+        wallet,
+        mint,
+        token_program,
+    )
+}
+
+/// `token_program` is the curve's (`BondingCurve::token_program`). The buyer's
+/// token account must already exist.
+pub fn buy(
+    user: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    sol_amount: u64,
+    min_tokens_out: u64,
+) -> Instruction {
+    build(
+        accounts::Buy {
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            user: *user,
+            user_token_account: pda::token_account_with_program(user, mint, token_program),
+            curve_stats: pda::curve_stats(mint).0,
+            buyer_record: pda::buyer_record(mint, user).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            position: pda::position(mint, user).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Buy { sol_amount, min_tokens_out },
+    )
+}
+
+/// Buys tokens delivered to `recipient`, creating their token account if
+/// needed. `payer` signs and pays; `memo_hash` is an optional hash of a gift note.
+pub fn gift_buy(
+    payer: &Pubkey,
+    recipient: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    memo_hash: Option<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts::GiftBuy {
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            payer: *payer,
+            recipient: *recipient,
+            recipient_token_account: pda::token_account_with_program(recipient, mint, token_program),
+            recipient_buyer_record: pda::buyer_record(mint, recipient).0,
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::GiftBuy { sol_amount, min_tokens_out, memo_hash },
+    )
+}
+
+pub fn sell(user: &Pubkey, mint: &Pubkey, token_program: &Pubkey, token_amount: u64, min_sol_out: u64) -> Instruction {
+    build(
+        accounts::Sell {
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            user: *user,
+            user_token_account: pda::token_account_with_program(user, mint, token_program),
+            curve_stats: pda::curve_stats(mint).0,
+            buyer_record: pda::buyer_record(mint, user).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            artist_vesting: pda::artist_vesting(mint).0,
+            position: pda::position(mint, user).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Sell { token_amount, min_sol_out },
+    )
+}
+
+/// `token_programs[i]` is the token program of `components[i]`'s curve.
+pub fn create_index(
+    curator: &Pubkey,
+    index_id: u64,
+    name: String,
+    symbol: String,
+    components: Vec<IndexComponent>,
+    token_programs: &[Pubkey],
+) -> Instruction {
+    let index = pda::index(curator, index_id).0;
+    let legs: Vec<AccountMeta> = components
+        .iter()
+        .zip(token_programs)
+        .flat_map(|(component, token_program)| {
+            [
+                AccountMeta::new_readonly(pda::bonding_curve(&component.mint).0, false),
+                AccountMeta::new_readonly(component.mint, false),
+                AccountMeta::new(pda::token_account_with_program(&index, &component.mint, token_program), false),
+            ]
+        })
+        .collect();
+    let mut ix = build(
+        accounts::CreateIndex {
+            index,
+            index_mint: pda::index_mint(&index).0,
+            curator: *curator,
+            token_program: token::ID,
+            token_2022_program: token_2022_program(token_programs),
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateIndex { index_id, name, symbol, components },
+    );
+    ix.accounts.extend(legs);
+    ix
+}
+
+/// `components` are `(mint, token_program)` pairs in the index's order. A
+/// full 10-component index needs an address lookup table to fit in a transaction.
+pub fn buy_index(
+    user: &Pubkey,
+    index: &Pubkey,
+    components: &[(Pubkey, Pubkey)],
+    sol_amount: u64,
+    min_index_out: u64,
+) -> Instruction {
+    let index_mint = pda::index_mint(index).0;
+    let token_programs: Vec<Pubkey> = components.iter().map(|(_, program)| *program).collect();
+    let mut ix = build(
+        accounts::BuyIndex {
+            index: *index,
+            index_mint,
+            user: *user,
+            user_index_account: pda::token_account(user, &index_mint),
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            token_program: token::ID,
+            token_2022_program: token_2022_program(&token_programs),
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BuyIndex { sol_amount, min_index_out },
+    );
+    ix.accounts.extend(index_leg_accounts(index, components));
+    ix
+}
+
+/// Same `components` as [`buy_index`].
+pub fn redeem_index(
+    user: &Pubkey,
+    index: &Pubkey,
+    components: &[(Pubkey, Pubkey)],
+    index_amount: u64,
+    min_sol_out: u64,
+) -> Instruction {
+    let index_mint = pda::index_mint(index).0;
+    let token_programs: Vec<Pubkey> = components.iter().map(|(_, program)| *program).collect();
+    let mut ix = build(
+        accounts::RedeemIndex {
+            index: *index,
+            index_mint,
+            user: *user,
+            user_index_account: pda::token_account(user, &index_mint),
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            token_program: token::ID,
+            token_2022_program: token_2022_program(&token_programs),
+            system_program: system_program::ID,
+        },
+        instruction::RedeemIndex { index_amount, min_sol_out },
+    );
+    ix.accounts.extend(index_leg_accounts(index, components));
+    ix
+}
+
+/// Token-2022 program account, only when some component needs it.
+fn token_2022_program(token_programs: &[Pubkey]) -> Option<Pubkey> {
+    token_programs.contains(&token_2022::ID).then_some(token_2022::ID)
+}
+
+fn index_leg_accounts(index: &Pubkey, components: &[(Pubkey, Pubkey)]) -> Vec<AccountMeta> {
+    components
+        .iter()
+        .flat_map(|(mint, token_program)| {
+            [
+                AccountMeta::new(pda::bonding_curve(mint).0, false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(pda::token_account_with_program(index, mint, token_program), false),
+                AccountMeta::new(pda::curve_stats(mint).0, false),
+                AccountMeta::new(pda::price_history(mint).0, false),
+                AccountMeta::new(pda::curve_vault(mint).0, false),
+            ]
+        })
+        .collect()
+}
+
+/// One side of a [`swap_artist_tokens`].
+pub struct SwapLeg {
+    pub mint: Pubkey,
+    pub token_program: Pubkey, // The curve's `BondingCurve::token_program`
+}
+
+/// The `to` token account is created if needed.
+pub fn swap_artist_tokens(user: &Pubkey, from: &SwapLeg, to: &SwapLeg, amount_in: u64, min_out: u64) -> Instruction {
+    build(
+        accounts::SwapArtistTokens {
+            user: *user,
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            from_curve: pda::bonding_curve(&from.mint).0,
+            from_mint: from.mint,
+            from_token_account: pda::token_account_with_program(user, &from.mint, &from.token_program),
+            from_curve_stats: pda::curve_stats(&from.mint).0,
+            from_price_history: pda::price_history(&from.mint).0,
+            from_curve_vault: pda::curve_vault(&from.mint).0,
+            from_buyer_record: pda::buyer_record(&from.mint, user).0,
+            from_artist_vesting: pda::artist_vesting(&from.mint).0,
+            from_position: pda::position(&from.mint, user).0,
+            to_curve: pda::bonding_curve(&to.mint).0,
+            to_mint: to.mint,
+            to_token_account: pda::token_account_with_program(user, &to.mint, &to.token_program),
+            to_curve_stats: pda::curve_stats(&to.mint).0,
+            to_price_history: pda::price_history(&to.mint).0,
+            to_curve_vault: pda::curve_vault(&to.mint).0,
+            to_buyer_record: pda::buyer_record(&to.mint, user).0,
+            to_position: pda::position(&to.mint, user).0,
+            from_token_program: from.token_program,
+            to_token_program: to.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SwapArtistTokens { amount_in, min_out },
+    )
+}
+
+/// `amount` is lamports for a buy order, tokens for a sell order.
+#[allow(clippy::too_many_arguments)]
+pub fn place_limit_order(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    order_id: u64,
+    side: OrderSide,
+    amount: u64,
+    trigger_price: u64,
+    min_out: u64,
+    keeper_tip: u64,
+) -> Instruction {
+    let order = pda::limit_order(mint, owner, order_id).0;
+    let is_sell = side == OrderSide::Sell;
+    build(
+        accounts::PlaceLimitOrder {
+            order,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            owner: *owner,
+            owner_token_account: pda::token_account_with_program(owner, mint, token_program),
+            escrow_token_account: is_sell.then(|| pda::token_account_with_program(&order, mint, token_program)),
+            artist_vesting: pda::artist_vesting(mint).0,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::PlaceLimitOrder { order_id, side, amount, trigger_price, min_out, keeper_tip },
+    )
+}
+
+pub fn cancel_order(order: &LimitOrder, token_program: &Pubkey) -> Instruction {
+    let address = pda::limit_order(&order.mint, &order.owner, order.order_id).0;
+    let is_sell = order.side == OrderSide::Sell;
+    build(
+        accounts::CancelOrder {
+            order: address,
+            bonding_curve: pda::bonding_curve(&order.mint).0,
+            mint: order.mint,
+            owner: order.owner,
+            owner_token_account: pda::token_account_with_program(&order.owner, &order.mint, token_program),
+            escrow_token_account: is_sell
+                .then(|| pda::token_account_with_program(&address, &order.mint, token_program)),
+            token_program: *token_program,
+        },
+        instruction::CancelOrder {},
+    )
+}
+
+/// Permissionless; check the trigger against the curve's spot price first.
+pub fn execute_order(keeper: &Pubkey, order: &LimitOrder, token_program: &Pubkey) -> Instruction {
+    let address = pda::limit_order(&order.mint, &order.owner, order.order_id).0;
+    let mint = &order.mint;
+    build(
+        accounts::ExecuteOrder {
+            order: address,
+            owner: order.owner,
+            keeper: *keeper,
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            owner_token_account: pda::token_account_with_program(&order.owner, mint, token_program),
+            buyer_record: pda::buyer_record(mint, &order.owner).0,
+            position: pda::position(mint, &order.owner).0,
+            escrow_token_account: (order.side == OrderSide::Sell)
+                .then(|| pda::token_account_with_program(&address, mint, token_program)),
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteOrder {},
+    )
+}
+
+/// `deposit` lamports fund the plan; each execution spends
+/// `amount_per_interval` plus `keeper_tip` of it.
+#[allow(clippy::too_many_arguments)]
+pub fn create_dca_plan(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    plan_id: u64,
+    amount_per_interval: u64,
+    interval_secs: i64,
+    num_intervals: u32,
+    max_price: u64,
+    keeper_tip: u64,
+    deposit: u64,
+) -> Instruction {
+    build(
+        accounts::CreateDcaPlan {
+            plan: pda::dca_plan(mint, owner, plan_id).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            owner: *owner,
+            owner_token_account: pda::token_account_with_program(owner, mint, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateDcaPlan {
+            plan_id,
+            amount_per_interval,
+            interval_secs,
+            num_intervals,
+            max_price,
+            keeper_tip,
+            deposit,
+        },
+    )
+}
+
+pub fn withdraw_dca(plan: &DcaPlan, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawDca { plan: pda::dca_plan(&plan.mint, &plan.owner, plan.plan_id).0, owner: plan.owner },
+        instruction::WithdrawDca { amount },
+    )
+}
+
+pub fn close_dca_plan(plan: &DcaPlan) -> Instruction {
+    build(
+        accounts::CloseDcaPlan { plan: pda::dca_plan(&plan.mint, &plan.owner, plan.plan_id).0, owner: plan.owner },
+        instruction::CloseDcaPlan {},
+    )
+}
+
+/// Permissionless; the plan must be due and the spot price at or below its cap.
+pub fn execute_dca(keeper: &Pubkey, plan: &DcaPlan, token_program: &Pubkey) -> Instruction {
+    let mint = &plan.mint;
+    build(
+        accounts::ExecuteDca {
+            plan: pda::dca_plan(mint, &plan.owner, plan.plan_id).0,
+            owner: plan.owner,
+            keeper: *keeper,
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            owner_token_account: pda::token_account_with_program(&plan.owner, mint, token_program),
+            buyer_record: pda::buyer_record(mint, &plan.owner).0,
+            position: pda::position(mint, &plan.owner).0,
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteDca {},
+    )
+}
+
+pub fn init_lending_pool(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    params: LendingParams,
+) -> Instruction {
+    let pool = pda::lending_pool(mint).0;
+    build(
+        accounts::InitLendingPool {
+            pool,
+            platform_config: pda::platform_config().0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            collateral_vault: pda::token_account_with_program(&pool, mint, token_program),
+            authority: *authority,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitLendingPool { params },
+    )
+}
+
+pub fn set_lending_params(authority: &Pubkey, mint: &Pubkey, params: LendingParams) -> Instruction {
+    build(
+        accounts::SetLendingParams {
+            pool: pda::lending_pool(mint).0,
+            platform_config: pda::platform_config().0,
+            authority: *authority,
+        },
+        instruction::SetLendingParams { params },
+    )
+}
+
+pub fn deposit_liquidity(lender: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::DepositLiquidity {
+            pool: pda::lending_pool(mint).0,
+            lender_position: pda::lender_position(mint, lender).0,
+            lender: *lender,
+            system_program: system_program::ID,
+        },
+        instruction::DepositLiquidity { amount },
+    )
+}
+
+pub fn withdraw_liquidity(lender: &Pubkey, mint: &Pubkey, shares: u64) -> Instruction {
+    build(
+        accounts::WithdrawLiquidity {
+            pool: pda::lending_pool(mint).0,
+            lender_position: pda::lender_position(mint, lender).0,
+            lender: *lender,
+        },
+        instruction::WithdrawLiquidity { shares },
+    )
+}
+
+pub fn deposit_collateral(borrower: &Pubkey, mint: &Pubkey, token_program: &Pubkey, amount: u64) -> Instruction {
+    let pool = pda::lending_pool(mint).0;
+    build(
+        accounts::DepositCollateral {
+            pool,
+            loan: pda::loan(mint, borrower).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            borrower: *borrower,
+            borrower_token_account: pda::token_account_with_program(borrower, mint, token_program),
+            collateral_vault: pda::token_account_with_program(&pool, mint, token_program),
+            artist_vesting: pda::artist_vesting(mint).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::DepositCollateral { amount },
+    )
+}
+
+pub fn withdraw_collateral(borrower: &Pubkey, mint: &Pubkey, token_program: &Pubkey, amount: u64) -> Instruction {
+    let pool = pda::lending_pool(mint).0;
+    build(
+        accounts::WithdrawCollateral {
+            pool,
+            loan: pda::loan(mint, borrower).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            curve_stats: pda::curve_stats(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            borrower: *borrower,
+            borrower_token_account: pda::token_account_with_program(borrower, mint, token_program),
+            collateral_vault: pda::token_account_with_program(&pool, mint, token_program),
+            token_program: *token_program,
+        },
+        instruction::WithdrawCollateral { amount },
+    )
+}
+
+pub fn borrow(borrower: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Borrow {
+            pool: pda::lending_pool(mint).0,
+            loan: pda::loan(mint, borrower).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            curve_stats: pda::curve_stats(mint).0,
+            platform_config: pda::platform_config().0,
+            borrower: *borrower,
+        },
+        instruction::Borrow { amount },
+    )
+}
+
+/// Repays at most the current debt; pass `u64::MAX` to repay in full.
+pub fn repay(borrower: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Repay {
+            pool: pda::lending_pool(mint).0,
+            loan: pda::loan(mint, borrower).0,
+            borrower: *borrower,
+            system_program: system_program::ID,
+        },
+        instruction::Repay { amount },
+    )
+}
+
+/// Permissionless; fails unless the loan's health is below 1.
+pub fn liquidate(liquidator: &Pubkey, borrower: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let pool = pda::lending_pool(mint).0;
+    build(
+        accounts::Liquidate {
+            pool,
+            loan: pda::loan(mint, borrower).0,
+            borrower: *borrower,
+            liquidator: *liquidator,
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            collateral_vault: pda::token_account_with_program(&pool, mint, token_program),
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Liquidate {},
+    )
+}
+
+/// Must run before the curve's first trade.
+pub fn create_artist_treasury(
+    artist: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    treasury_bps: u16,
+    milestones: Vec<MilestoneInput>,
+) -> Instruction {
+    let treasury = pda::artist_treasury(mint).0;
+    build(
+        accounts::CreateArtistTreasury {
+            treasury,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            vote_escrow: pda::token_account_with_program(&treasury, mint, token_program),
+            artist: *artist,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateArtistTreasury { treasury_bps, milestones },
+    )
+}
+
+pub fn request_release(artist: &Pubkey, mint: &Pubkey, milestone: u8) -> Instruction {
+    build(
+        accounts::RequestRelease {
+            treasury: pda::artist_treasury(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            artist: *artist,
+        },
+        instruction::RequestRelease { milestone },
+    )
+}
+
+pub fn cast_vote(
+    voter: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    milestone: u8,
+    approve: bool,
+    amount: u64,
+) -> Instruction {
+    let treasury = pda::artist_treasury(mint).0;
+    build(
+        accounts::CastVote {
+            treasury,
+            vote: pda::treasury_vote(mint, voter).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            voter: *voter,
+            buyer_record: pda::buyer_record(mint, voter).0,
+            voter_token_account: pda::token_account_with_program(voter, mint, token_program),
+            vote_escrow: pda::token_account_with_program(&treasury, mint, token_program),
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::CastVote { milestone, approve, amount },
+    )
+}
+
+pub fn withdraw_vote(voter: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let treasury = pda::artist_treasury(mint).0;
+    build(
+        accounts::WithdrawVote {
+            treasury,
+            vote: pda::treasury_vote(mint, voter).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            voter: *voter,
+            voter_token_account: pda::token_account_with_program(voter, mint, token_program),
+            vote_escrow: pda::token_account_with_program(&treasury, mint, token_program),
+            token_program: *token_program,
+        },
+        instruction::WithdrawVote {},
+    )
+}
+
+/// Permissionless once the vote has ended; `artist` is the treasury's artist.
+pub fn finalize_vote(artist: &Pubkey, mint: &Pubkey, milestone: u8) -> Instruction {
+    build(
+        accounts::FinalizeVote {
+            treasury: pda::artist_treasury(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            artist: *artist,
+            curve_vault: pda::curve_vault(mint).0,
+            system_program: system_program::ID,
+        },
+        instruction::FinalizeVote { milestone },
+    )
+}
+
+/// Permissionless once the next milestone's deadline has passed.
+pub fn fail_treasury(mint: &Pubkey) -> Instruction {
+    build(
+        accounts::FailTreasury {
+            treasury: pda::artist_treasury(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+        },
+        instruction::FailTreasury {},
+    )
+}
+
+pub fn claim_refund(
+    holder: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    token_amount: u64,
+    min_sol_out: u64,
+) -> Instruction {
+    build(
+        accounts::ClaimRefund {
+            treasury: pda::artist_treasury(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            mint: *mint,
+            holder: *holder,
+            holder_token_account: pda::token_account_with_program(holder, mint, token_program),
+            buyer_record: pda::buyer_record(mint, holder).0,
+            curve_stats: pda::curve_stats(mint).0,
+            price_history: pda::price_history(mint).0,
+            curve_vault: pda::curve_vault(mint).0,
+            fee_vault: pda::fee_vault().0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimRefund { token_amount, min_sol_out },
+    )
+}
+
+pub fn init_buyback(artist: &Pubkey, mint: &Pubkey, token_program: &Pubkey, config: BuybackConfig) -> Instruction {
+    let buyback = pda::buyback(mint).0;
+    build(
+        accounts::InitBuyback {
+            buyback,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            burn_account: pda::token_account_with_program(&buyback, mint, token_program),
+            artist: *artist,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitBuyback { config },
+    )
+}
+
+pub fn set_buyback_config(artist: &Pubkey, mint: &Pubkey, config: BuybackConfig) -> Instruction {
+    build(
+        accounts::SetBuybackConfig { buyback: pda::buyback(mint).0, artist: *artist },
+        instruction::SetBuybackConfig { config },
+    )
+}
+
+pub fn fund_buyback(funder: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::FundBuyback {
+            buyback: pda::buyback(mint).0,
+            funder: *funder,
+            system_program: system_program::ID,
+        },
+        instruction::FundBuyback { amount },
+    )
+}
+
+pub fn withdraw_buyback(artist: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawBuyback { buyback: pda::buyback(mint).0, artist: *artist },
+        instruction::WithdrawBuyback { amount },
+    )
+}
+
+fn run_buyback_accounts(caller: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> accounts::RunBuyback {
+    let buyback = pda::buyback(mint).0;
+    accounts::RunBuyback {
+        buyback,
+        caller: *caller,
+        bonding_curve: pda::bonding_curve(mint).0,
+        platform_config: pda::platform_config().0,
+        mint: *mint,
+        burn_account: pda::token_account_with_program(&buyback, mint, token_program),
+        curve_stats: pda::curve_stats(mint).0,
+        price_history: pda::price_history(mint).0,
+        curve_vault: pda::curve_vault(mint).0,
+        fee_vault: pda::fee_vault().0,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    }
+}
+
+/// Artist only; `from_treasury` pays from the buyback PDA instead of the artist's wallet.
+pub fn buyback(
+    artist: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    from_treasury: bool,
+) -> Instruction {
+    build(
+        run_buyback_accounts(artist, mint, token_program),
+        instruction::Buyback { sol_amount, min_tokens_out, from_treasury },
+    )
+}
+
+/// Permissionless once a scheduled run is due; the keeper earns the tip.
+pub fn execute_buyback(keeper: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(run_buyback_accounts(keeper, mint, token_program), instruction::ExecuteBuyback {})
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_airdrop(
+    creator: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    airdrop_id: u64,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_recipients: u32,
+    expires_at: i64,
+) -> Instruction {
+    let airdrop = pda::airdrop(mint, creator, airdrop_id).0;
+    build(
+        accounts::CreateAirdrop {
+            airdrop,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            escrow: pda::airdrop_escrow(&airdrop).0,
+            creator: *creator,
+            creator_token_account: pda::token_account_with_program(creator, mint, token_program),
+            artist_vesting: pda::artist_vesting(mint).0,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::CreateAirdrop {
+            airdrop_id,
+            merkle_root,
+            total_amount,
+            num_recipients,
+            expires_at,
+        },
+    )
+}
+
+pub fn claim_airdrop(
+    claimant: &Pubkey,
+    airdrop: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts::ClaimAirdrop {
+            airdrop: *airdrop,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            escrow: pda::airdrop_escrow(airdrop).0,
+            claimant: *claimant,
+            claimant_token_account: pda::token_account_with_program(claimant, mint, token_program),
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimAirdrop { index, amount, proof },
+    )
+}
+
+pub fn reclaim_unclaimed(creator: &Pubkey, mint: &Pubkey, token_program: &Pubkey, airdrop_id: u64) -> Instruction {
+    let airdrop = pda::airdrop(mint, creator, airdrop_id).0;
+    build(
+        accounts::ReclaimUnclaimed {
+            airdrop,
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            escrow: pda::airdrop_escrow(&airdrop).0,
+            creator: *creator,
+            creator_token_account: pda::token_account_with_program(creator, mint, token_program),
+            token_program: *token_program,
+        },
+        instruction::ReclaimUnclaimed {},
+    )
+}
+
+pub fn set_boost_tier(authority: &Pubkey, tier: u8, price_lamports: u64, duration_secs: i64) -> Instruction {
+    build(
+        accounts::SetBoostTier {
+            platform_config: pda::platform_config().0,
+            authority: *authority,
+        },
+        instruction::SetBoostTier { tier, price_lamports, duration_secs },
+    )
+}
+
+pub fn set_swap_fee_discount(authority: &Pubkey, discount_bps: u16) -> Instruction {
+    build(
+        accounts::SetSwapFeeDiscount {
+            platform_config: pda::platform_config().0,
+            authority: *authority,
+        },
+        instruction::SetSwapFeeDiscount { discount_bps },
+    )
+}
+
+pub fn set_fee_change_delay(authority: &Pubkey, delay_secs: i64) -> Instruction {
+    build(
+        accounts::SetFeeChangeDelay {
+            platform_config: pda::platform_config().0,
+            authority: *authority,
+        },
+        instruction::SetFeeChangeDelay { delay_secs },
+    )
+}
+
+/// Hands config changes to an M-of-N group; signed by the current authority.
+pub fn init_admin_group(authority: &Pubkey, members: Vec<Pubkey>, threshold: u8) -> Instruction {
+    build(
+        accounts::InitAdminGroup {
+            admin_group: pda::admin_group().0,
+            platform_config: pda::platform_config().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitAdminGroup { members, threshold },
+    )
+}
+
+/// `proposal_id` is the group's current `proposal_count`.
+pub fn propose_admin_action(proposer: &Pubkey, proposal_id: u64, action: AdminAction) -> Instruction {
+    build(
+        accounts::ProposeAdminAction {
+            admin_group: pda::admin_group().0,
+            proposal: pda::admin_proposal(proposal_id).0,
+            proposer: *proposer,
+            system_program: system_program::ID,
+        },
+        instruction::ProposeAdminAction { action },
+    )
+}
+
+pub fn approve_admin_action(member: &Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::ApproveAdminAction {
+            admin_group: pda::admin_group().0,
+            proposal: pda::admin_proposal(proposal_id).0,
+            member: *member,
+        },
+        instruction::ApproveAdminAction {},
+    )
+}
+
+/// Appends the extra account `action` needs: the recipient of `WithdrawFees`
+/// or the pool of `SetLendingParams`.
+pub fn execute_admin_action(executor: &Pubkey, proposal_id: u64, action: &AdminAction) -> Instruction {
+    let mut ix = build(
+        accounts::ExecuteAdminAction {
+            admin_group: pda::admin_group().0,
+            proposal: pda::admin_proposal(proposal_id).0,
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            executor: *executor,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteAdminAction {},
+    );
+    match action {
+        AdminAction::WithdrawFees { recipient, .. } => ix.accounts.push(AccountMeta::new(*recipient, false)),
+        AdminAction::SetLendingParams { mint, .. } => {
+            ix.accounts.push(AccountMeta::new(pda::lending_pool(mint).0, false))
+        }
+        _ => {}
+    }
+    ix
+}
+
+/// Permissionless once a queued fee change is due.
+pub fn activate_fee_change() -> Instruction {
+    build(
+        accounts::ActivateFeeChange { platform_config: pda::platform_config().0 },
+        instruction::ActivateFeeChange {},
+    )
+}
+
+/// Permissionless; `payer` covers the rent of the larger layout.
+pub fn migrate_platform_config(payer: &Pubkey) -> Instruction {
+    build(
+        accounts::MigratePlatformConfig {
+            platform_config: pda::platform_config().0,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigratePlatformConfig {},
+    )
+}
+
+/// Permissionless; `payer` covers the rent of the larger layout.
+pub fn migrate_bonding_curve(payer: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateBondingCurve {
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateBondingCurve {},
+    )
+}
+
+/// Permissionless; works on any program account. `payer` covers the rent of
+/// the larger layout.
+pub fn migrate_account(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAccount {
+            account: *account,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAccount {},
+    )
+}
+
+pub fn purchase_boost(payer: &Pubkey, mint: &Pubkey, tier: u8) -> Instruction {
+    build(
+        accounts::PurchaseBoost {
+            boost: pda::boost(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            platform_config: pda::platform_config().0,
+            fee_vault: pda::fee_vault().0,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::PurchaseBoost { tier },
+    )
+}
+
+pub fn init_curve_stats(payer: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::InitCurveStats {
+            curve_stats: pda::curve_stats(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitCurveStats {},
+    )
+}
+
+/// Read-only; simulate it and decode [`crate::TwapResult`] from the return data.
+pub fn get_twap(mint: &Pubkey, window: i64) -> Instruction {
+    build(
+        accounts::GetTwap {
+            curve_stats: pda::curve_stats(mint).0,
+        },
+        instruction::GetTwap { window },
+    )
+}
+
+pub fn init_price_history(payer: &Pubkey, mint: &Pubkey, interval_secs: i64) -> Instruction {
+    build(
+        accounts::InitPriceHistory {
+            price_history: pda::price_history(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::InitPriceHistory { interval_secs },
+    )
+}
+
+pub fn set_price_history_interval(artist: &Pubkey, mint: &Pubkey, interval_secs: i64) -> Instruction {
+    build(
+        accounts::SetPriceHistoryInterval {
+            price_history: pda::price_history(mint).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            artist: *artist,
+        },
+        instruction::SetPriceHistoryInterval { interval_secs },
+    )
+}
+
+/// Read-only; simulate it and decode [`crate::BuyQuoteResult`] from the return data.
+pub fn quote_buy(mint: &Pubkey, sol_amount: u64) -> Instruction {
+    build(quote_accounts(mint), instruction::QuoteBuy { sol_amount })
+}
+
+/// Read-only; simulate it and decode [`crate::SellQuoteResult`] from the return data.
+pub fn quote_sell(mint: &Pubkey, token_amount: u64) -> Instruction {
+    build(quote_accounts(mint), instruction::QuoteSell { token_amount })
+}
+
+fn quote_accounts(mint: &Pubkey) -> accounts::Quote {
+    accounts::Quote {
+        bonding_curve: pda::bonding_curve(mint).0,
+        platform_config: pda::platform_config().0,
+    }
+}
+
+pub fn open_position(wallet: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::OpenPosition {
+            position: pda::position(mint, wallet).0,
+            bonding_curve: pda::bonding_curve(mint).0,
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::OpenPosition {},
+    )
+}
+
+/// Fails with `BadgeNotEarned` unless the wallet's buyer record qualifies.
+/// `token_program` is the curve's; the wallet's token account must exist.
+pub fn mint_badge(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey, kind: BadgeKind) -> Instruction {
+    build(
+        accounts::MintBadge {
+            badge: pda::badge(mint, wallet, kind).0,
+            buyer_record: pda::buyer_record(mint, wallet).0,
+            holder_token_account: pda::token_account_with_program(wallet, mint, token_program),
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::MintBadge { kind },
+    )
+}
+
+pub fn init_token_metadata(payer: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::InitTokenMetadata {
+            bonding_curve: pda::bonding_curve(mint).0,
+            mint: *mint,
+            metadata: pda::metadata(mint).0,
+            payer: *payer,
+            token_metadata_program: metadata::ID,
+            system_program: system_program::ID,
+            rent: Rent::id(),
+        },
+        instruction::InitTokenMetadata {},
+    )
+}
+
+/// Token-2022 `transfer_checked` of an artist token between wallets' ATAs,
+/// with the accounts the vesting transfer hook needs appended.
+pub fn transfer_2022(owner: &Pubkey, mint: &Pubkey, destination_owner: &Pubkey, amount: u64) -> Instruction {
+    let mut ix = token_2022::spl_token_2022::instruction::transfer_checked(
+        &token_2022::ID,
+        &pda::token_account_with_program(owner, mint, &token_2022::ID),
+        mint,
+        &pda::token_account_with_program(destination_owner, mint, &token_2022::ID),
+        owner,
+        &[],
+        amount,
+        TOKEN_DECIMALS,
+    )
+    .expect("static token program id");
+    ix.accounts.extend(transfer_hook_accounts(mint, owner));
+    ix
+}
+
+/// Accounts Token-2022 needs to run the FanStake transfer hook on `mint`
+/// for tokens leaving an account owned by `source_owner`.
+fn transfer_hook_accounts(mint: &Pubkey, source_owner: &Pubkey) -> [AccountMeta; 5] {
+    [
+        AccountMeta::new_readonly(pda::extra_account_metas(mint).0, false),
+        AccountMeta::new_readonly(pda::artist_vesting(mint).0, false),
+        AccountMeta::new(pda::bonding_curve(mint).0, false),
+        AccountMeta::new(pda::buyer_record(mint, source_owner).0, false),
+        AccountMeta::new_readonly(PROGRAM_ID, false),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AnchorDeserialize, Discriminator};
+
+    fn keys(ix: &Instruction) -> Vec<Pubkey> {
+        ix.accounts.iter().map(|meta| meta.pubkey).collect()
+    }
+
+    #[test]
+    fn buy_matches_the_program() {
+        let user = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let ix = buy(&user, &mint, &token::ID, 1_000_000_000, 5);
+
+        assert_eq!(ix.program_id, PROGRAM_ID);
+        assert_eq!(&ix.data[..8], instruction::Buy::DISCRIMINATOR);
+        let args = instruction::Buy::try_from_slice(&ix.data[8..]).unwrap();
+        assert_eq!((args.sol_amount, args.min_tokens_out), (1_000_000_000, 5));
+        assert_eq!(
+            keys(&ix),
+            [
+                pda::bonding_curve(&mint).0,
+                pda::platform_config().0,
+                mint,
+                user,
+                pda::token_account_with_program(&user, &mint, &token::ID),
+                pda::curve_stats(&mint).0,
+                pda::buyer_record(&mint, &user).0,
+                pda::price_history(&mint).0,
+                pda::curve_vault(&mint).0,
+                pda::fee_vault().0,
+                pda::position(&mint, &user).0,
+                token::ID,
+                system_program::ID,
+            ]
+        );
+        let signers: Vec<_> = ix.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+        assert_eq!(signers, [user]);
+    }
+
+    #[test]
+    fn sell_matches_the_program() {
+        let user = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let ix = sell(&user, &mint, &token_2022::ID, 300, 7);
+
+        assert_eq!(&ix.data[..8], instruction::Sell::DISCRIMINATOR);
+        let args = instruction::Sell::try_from_slice(&ix.data[8..]).unwrap();
+        assert_eq!((args.token_amount, args.min_sol_out), (300, 7));
+        assert_eq!(
+            keys(&ix),
+            [
+                pda::bonding_curve(&mint).0,
+                pda::platform_config().0,
+                mint,
+                user,
+                pda::token_account_with_program(&user, &mint, &token_2022::ID),
+                pda::curve_stats(&mint).0,
+                pda::buyer_record(&mint, &user).0,
+                pda::price_history(&mint).0,
+                pda::curve_vault(&mint).0,
+                pda::fee_vault().0,
+                pda::artist_vesting(&mint).0,
+                pda::position(&mint, &user).0,
+                token_2022::ID,
+                system_program::ID,
+            ]
+        );
+    }
+
+    #[test]
+    fn builders_use_their_discriminators() {
+        let wallet = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let cases: [(Instruction, &[u8]); 5] = [
+            (initialize(&wallet, 100), instruction::Initialize::DISCRIMINATOR),
+            (
+                create_artist_token_2022(&wallet, &mint, "A".into(), "A".into(), "a".into(), 0),
+                instruction::CreateArtistToken2022::DISCRIMINATOR,
+            ),
+            (gift_buy(&wallet, &mint, &mint, &token::ID, 1, 0, None), instruction::GiftBuy::DISCRIMINATOR),
+            (cast_vote(&wallet, &mint, &token::ID, 0, true, 1), instruction::CastVote::DISCRIMINATOR),
+            (migrate_account(&wallet, &mint), instruction::MigrateAccount::DISCRIMINATOR),
+        ];
+        for (ix, discriminator) in cases {
+            assert_eq!(&ix.data[..8], discriminator);
+        }
+    }
+
+    #[test]
+    fn buy_orders_skip_the_escrow() {
+        let owner = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let ix = place_limit_order(&owner, &mint, &token_2022::ID, 0, OrderSide::Buy, 1, 1, 0, 0);
+
+        // Anchor passes an omitted optional account as the program id
+        assert_eq!(ix.accounts[5].pubkey, PROGRAM_ID);
+        assert_eq!(ix.accounts.len(), 10);
+    }
+
+    #[test]
+    fn token_2022_sell_orders_skip_the_hook_accounts() {
+        let owner = Pubkey::new_from_array([1; 32]);
+        let mint = Pubkey::new_from_array([2; 32]);
+        let ix = place_limit_order(&owner, &mint, &token_2022::ID, 4, OrderSide::Sell, 1, 1, 0, 0);
+        let order = pda::limit_order(&mint, &owner, 4).0;
+
+        assert_eq!(ix.accounts[5].pubkey, pda::token_account_with_program(&order, &mint, &token_2022::ID));
+        // Escrow moves burn and re-mint, so the mint is written and the hook never runs
+        assert_eq!(ix.accounts[2].pubkey, mint);
+        assert!(ix.accounts[2].is_writable);
+        assert_eq!(ix.accounts.len(), 10);
+
+        let spl = place_limit_order(&owner, &mint, &token::ID, 4, OrderSide::Sell, 1, 1, 0, 0);
+        assert_eq!(spl.accounts.len(), 10);
+    }
+}
@@ -0,0 +1,31 @@
+//! Rust client for the FanStake program.
+//!
+//! - [`pda`] — PDA derivation for every program-owned account
+//! - [`instructions`] — typed builders for every instruction
+//! - [`accounts`] — decoders for on-chain account data
+//! - [`error`] — mapping from custom error codes to [`FanStakeError`]
+//! - [`events`] — decoders for `emit!`ted program events in transaction logs
+//! - [`math`] — the program's own curve math, for exact off-chain quotes
+//! - `rpc` (feature `rpc`) — a small blocking JSON-RPC client
+//!
+//! Account and instruction layouts come straight from the `fanstake` crate,
+//! so the SDK can't drift from the deployed program's Anchor discriminators.
+
+pub mod accounts;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+pub use fanstake_math as math;
+
+pub use fanstake::{
+    AdminAction, AdminActionExecutedEvent, AdminGroup, AdminProposal, Airdrop, ArtistTreasury, Badge, BadgeKind, BondingCurve, Boost, BoostTier, BuyQuoteResult,
+    Buyback, BuybackConfig, BuybackEvent, BuyerRecord, CurveStats, DcaPlan, FanStakeError, FeeChangeActivatedEvent, FeeChangeQueuedEvent, GiftEvent, Index, IndexComponent, IndexTradeEvent,
+    LenderPosition, LendingParams, LendingPool, LimitOrder, LiquidationEvent, Loan, Milestone,
+    MilestoneInput, MilestoneReleasedEvent, Observation, OrderSide, PlatformConfig, Position,
+    PriceBucket, PriceHistory, SellQuoteResult, TradeEvent, TreasuryVote, TwapResult, VestingSchedule,
+    DEFAULT_PRICE_HISTORY_INTERVAL, ID as PROGRAM_ID,
+};
@@ -0,0 +1,186 @@
+//! PDA derivation. Each helper returns `(address, bump)`, mirroring the
+//! `seeds = [...]` constraints in the program.
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::{BadgeKind, PROGRAM_ID};
+
+pub fn platform_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"platform_config"], &PROGRAM_ID)
+}
+
+pub fn fee_vault() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"fee_vault"], &PROGRAM_ID)
+}
+
+pub fn bonding_curve(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"bonding_curve", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn curve_vault(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"curve_vault", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn artist_vesting(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"artist_vesting", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn curve_stats(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"curve_stats", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn price_history(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"price_history", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn buyer_record(mint: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"buyer_record", mint.as_ref(), wallet.as_ref()], &PROGRAM_ID)
+}
+
+pub fn position(mint: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"position", mint.as_ref(), wallet.as_ref()], &PROGRAM_ID)
+}
+
+pub fn badge(mint: &Pubkey, wallet: &Pubkey, kind: BadgeKind) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"badge", mint.as_ref(), wallet.as_ref(), &[kind as u8]], &PROGRAM_ID)
+}
+
+pub fn boost(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"boost", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn airdrop(mint: &Pubkey, creator: &Pubkey, airdrop_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"airdrop", mint.as_ref(), creator.as_ref(), &airdrop_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn airdrop_escrow(airdrop: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"airdrop_escrow", airdrop.as_ref()], &PROGRAM_ID)
+}
+
+pub fn limit_order(mint: &Pubkey, owner: &Pubkey, order_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"limit_order", mint.as_ref(), owner.as_ref(), &order_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn dca_plan(mint: &Pubkey, owner: &Pubkey, plan_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"dca_plan", mint.as_ref(), owner.as_ref(), &plan_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn lending_pool(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lending_pool", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn lender_position(mint: &Pubkey, lender: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lender_position", mint.as_ref(), lender.as_ref()], &PROGRAM_ID)
+}
+
+pub fn loan(mint: &Pubkey, borrower: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"loan", mint.as_ref(), borrower.as_ref()], &PROGRAM_ID)
+}
+
+pub fn artist_treasury(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"artist_treasury", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn treasury_vote(mint: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury_vote", mint.as_ref(), voter.as_ref()], &PROGRAM_ID)
+}
+
+pub fn buyback(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"buyback", mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn admin_group() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"admin_group"], &PROGRAM_ID)
+}
+
+pub fn admin_proposal(proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"admin_proposal", &proposal_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+pub fn index(curator: &Pubkey, index_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"index", curator.as_ref(), &index_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+pub fn index_mint(index: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"index_mint", index.as_ref()], &PROGRAM_ID)
+}
+
+/// Metaplex metadata account for a mint.
+pub fn metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    let program = anchor_spl::metadata::ID;
+    Pubkey::find_program_address(&[b"metadata", program.as_ref(), mint.as_ref()], &program)
+}
+
+/// Associated token account for a (legacy SPL Token) artist mint.
+pub fn token_account(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(wallet, mint)
+}
+
+/// Associated token account under either token program.
+pub fn token_account_with_program(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(wallet, mint, token_program)
+}
+
+/// Transfer-hook extra account list for a Token-2022 artist mint.
+pub fn extra_account_metas(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"extra-account-metas", mint.as_ref()], &PROGRAM_ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::pubkey;
+
+    // The legacy fixtures the program's integration tests load, at the
+    // addresses its `seeds = [...]` constraints resolve to
+    const LEGACY_MINT: Pubkey = pubkey!("US517G5965aydkZ46HS38QLi7UQiSojurfbQfKCELFx");
+    const LEGACY_WALLET: Pubkey = pubkey!("cGfHiC6Kgg3FpFZvgwGcswsCRtp4aBP2fzuXRQPizuN");
+
+    #[test]
+    fn matches_the_program_fixtures() {
+        assert_eq!(bonding_curve(&LEGACY_MINT).0, pubkey!("4FU4sHuZJ7knDM67c5WwDD663sd2Sd83WMufuBPn31jx"));
+        assert_eq!(artist_vesting(&LEGACY_MINT).0, pubkey!("CcQZrq12iLnMoit73fHpzipSpuctSQAMcexoQbSjUL6m"));
+        assert_eq!(
+            buyer_record(&LEGACY_MINT, &LEGACY_WALLET).0,
+            pubkey!("5PcA8dcw7vhM8txRfgF4k2Loe94ALbCPH7zHBxHee8nS")
+        );
+    }
+
+    #[test]
+    fn bumps_rederive_the_address() {
+        let mint = Pubkey::new_from_array([1; 32]);
+        let wallet = Pubkey::new_from_array([2; 32]);
+        let (curve, bump) = bonding_curve(&mint);
+        assert_eq!(
+            Pubkey::create_program_address(&[b"bonding_curve", mint.as_ref(), &[bump]], &PROGRAM_ID).unwrap(),
+            curve
+        );
+        let (order, bump) = limit_order(&mint, &wallet, 3);
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[b"limit_order", mint.as_ref(), wallet.as_ref(), &3u64.to_le_bytes(), &[bump]],
+                &PROGRAM_ID
+            )
+            .unwrap(),
+            order
+        );
+    }
+
+    #[test]
+    fn seeds_keep_accounts_apart() {
+        let mint = Pubkey::new_from_array([1; 32]);
+        let wallet = Pubkey::new_from_array([2; 32]);
+        assert_ne!(buyer_record(&mint, &wallet).0, position(&mint, &wallet).0);
+        assert_ne!(airdrop(&mint, &wallet, 0).0, airdrop(&mint, &wallet, 1).0);
+        assert_ne!(badge(&mint, &wallet, BadgeKind::FoundingFan).0, badge(&mint, &wallet, BadgeKind::DayOne).0);
+    }
+}
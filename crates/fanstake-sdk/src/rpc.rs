@@ -0,0 +1,302 @@
+//! Minimal blocking JSON-RPC client — just the calls FanStake tooling needs.
+//! Works against any RPC, `solana-test-validator` included.
+
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RpcError {
+    #[error("transport error: {0}")]
+    Transport(String),
+    #[error("rpc error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("unexpected response: {0}")]
+    Response(String),
+    #[error("transaction failed: {0}")]
+    Transaction(String),
+    #[error("timed out waiting for confirmation of {0}")]
+    Timeout(Signature),
+}
+
+/// `getProgramAccounts` filter.
+#[derive(Debug, Clone)]
+pub enum Filter {
+    DataSize(u64),
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+/// Result of `simulateTransaction`.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    pub return_data: Option<Vec<u8>>,
+}
+
+/// One entry from `getSignaturesForAddress`.
+#[derive(Debug, Clone)]
+pub struct SignatureInfo {
+    pub signature: Signature,
+    pub slot: u64,
+    pub failed: bool,
+    pub block_time: Option<i64>,
+}
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+    commitment: String,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+            commitment: "confirmed".into(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(body)
+            .map_err(|e| RpcError::Transport(e.to_string()))?
+            .into_json()
+            .map_err(|e| RpcError::Transport(e.to_string()))?;
+        if let Some(err) = response.get("error") {
+            return Err(RpcError::Rpc {
+                code: err["code"].as_i64().unwrap_or_default(),
+                message: err["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        Ok(response["result"].clone())
+    }
+
+    /// Raw account data, or `None` if the account doesn't exist.
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, RpcError> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": self.commitment }]),
+        )?;
+        match &result["value"] {
+            Value::Null => Ok(None),
+            value => decode_data(&value["data"]).map(Some),
+        }
+    }
+
+    pub fn get_balance(&self, address: &Pubkey) -> Result<u64, RpcError> {
+        let result = self.call("getBalance", json!([address.to_string(), { "commitment": self.commitment }]))?;
+        result["value"].as_u64().ok_or_else(|| RpcError::Response("missing balance".into()))
+    }
+
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[Filter],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>, RpcError> {
+        let filters: Vec<Value> = filters
+            .iter()
+            .map(|f| match f {
+                Filter::DataSize(size) => json!({ "dataSize": size }),
+                Filter::Memcmp { offset, bytes } => json!({
+                    "memcmp": { "offset": offset, "bytes": BASE64.encode(bytes), "encoding": "base64" }
+                }),
+            })
+            .collect();
+        let result = self.call(
+            "getProgramAccounts",
+            json!([program_id.to_string(), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "filters": filters,
+            }]),
+        )?;
+        let entries = result
+            .as_array()
+            .ok_or_else(|| RpcError::Response("expected account list".into()))?;
+        entries
+            .iter()
+            .map(|entry| {
+                let pubkey = parse_pubkey(&entry["pubkey"])?;
+                let data = decode_data(&entry["account"]["data"])?;
+                Ok((pubkey, data))
+            })
+            .collect()
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash, RpcError> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": self.commitment }]))?;
+        result["value"]["blockhash"]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| RpcError::Response("missing blockhash".into()))
+    }
+
+    pub fn get_slot(&self) -> Result<u64, RpcError> {
+        let result = self.call("getSlot", json!([{ "commitment": self.commitment }]))?;
+        result.as_u64().ok_or_else(|| RpcError::Response("missing slot".into()))
+    }
+
+    /// Simulates without signature checks and with a fresh blockhash, so
+    /// unsigned transactions (e.g. read-only quotes) can be simulated too.
+    pub fn simulate_transaction(&self, tx: &Transaction) -> Result<Simulation, RpcError> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([encode_tx(tx)?, {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+            }]),
+        )?;
+        let value = &result["value"];
+        let return_data = match &value["returnData"] {
+            Value::Null => None,
+            data => Some(decode_data(&data["data"])?),
+        };
+        Ok(Simulation {
+            err: match &value["err"] {
+                Value::Null => None,
+                err => Some(err.clone()),
+            },
+            logs: value["logs"]
+                .as_array()
+                .map(|logs| logs.iter().filter_map(|l| l.as_str().map(String::from)).collect())
+                .unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+            return_data,
+        })
+    }
+
+    pub fn send_transaction(&self, tx: &Transaction) -> Result<Signature, RpcError> {
+        let result = self.call(
+            "sendTransaction",
+            json!([encode_tx(tx)?, { "encoding": "base64", "preflightCommitment": self.commitment }]),
+        )?;
+        result
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| RpcError::Response("missing signature".into()))
+    }
+
+    /// Polls until the signature reaches `confirmed` (or fails / times out).
+    pub fn confirm_transaction(&self, signature: &Signature, timeout: Duration) -> Result<(), RpcError> {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            let result = self.call(
+                "getSignatureStatuses",
+                json!([[signature.to_string()], { "searchTransactionHistory": false }]),
+            )?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(RpcError::Transaction(status["err"].to_string()));
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(());
+                }
+            }
+            sleep(Duration::from_millis(500));
+        }
+        Err(RpcError::Timeout(*signature))
+    }
+
+    pub fn send_and_confirm(&self, tx: &Transaction) -> Result<Signature, RpcError> {
+        let signature = self.send_transaction(tx)?;
+        self.confirm_transaction(&signature, Duration::from_secs(60))?;
+        Ok(signature)
+    }
+
+    /// Signatures for `address`, newest first. `until` stops (exclusive) at a known signature.
+    pub fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&Signature>,
+        until: Option<&Signature>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>, RpcError> {
+        let mut config = json!({ "limit": limit, "commitment": self.commitment });
+        if let Some(before) = before {
+            config["before"] = json!(before.to_string());
+        }
+        if let Some(until) = until {
+            config["until"] = json!(until.to_string());
+        }
+        let result = self.call("getSignaturesForAddress", json!([address.to_string(), config]))?;
+        let entries = result
+            .as_array()
+            .ok_or_else(|| RpcError::Response("expected signature list".into()))?;
+        entries
+            .iter()
+            .map(|entry| {
+                Ok(SignatureInfo {
+                    signature: entry["signature"]
+                        .as_str()
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| RpcError::Response("bad signature".into()))?,
+                    slot: entry["slot"].as_u64().unwrap_or_default(),
+                    failed: !entry["err"].is_null(),
+                    block_time: entry["blockTime"].as_i64(),
+                })
+            })
+            .collect()
+    }
+
+    /// A confirmed transaction, or `None` if the node doesn't have it. The
+    /// transaction itself is base64 (`transaction[0]`, see [`decode_transaction`]);
+    /// `meta` (logs, token balances, loaded addresses) is plain JSON.
+    pub fn get_transaction(&self, signature: &Signature) -> Result<Option<Value>, RpcError> {
+        let result = self.call(
+            "getTransaction",
+            json!([signature.to_string(), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )?;
+        Ok((!result.is_null()).then_some(result))
+    }
+}
+
+/// Decodes the `transaction` field of a base64 [`RpcClient::get_transaction`] result.
+pub fn decode_transaction(transaction: &Value) -> Result<VersionedTransaction, RpcError> {
+    let bytes = decode_data(transaction)?;
+    bincode::deserialize(&bytes).map_err(|e| RpcError::Response(e.to_string()))
+}
+
+fn encode_tx(tx: &Transaction) -> Result<String, RpcError> {
+    let bytes = bincode::serialize(tx).map_err(|e| RpcError::Transport(e.to_string()))?;
+    Ok(BASE64.encode(bytes))
+}
+
+fn decode_data(data: &Value) -> Result<Vec<u8>, RpcError> {
+    let encoded = data[0]
+        .as_str()
+        .ok_or_else(|| RpcError::Response("expected base64 data".into()))?;
+    BASE64
+        .decode(encoded)
+        .map_err(|e| RpcError::Response(e.to_string()))
+}
+
+fn parse_pubkey(value: &Value) -> Result<Pubkey, RpcError> {
+    value
+        .as_str()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| RpcError::Response("bad pubkey".into()))
+}
@@ -1,12 +1,104 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{self, mpl_token_metadata, Metadata};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token_interface::{self, Token2022, TokenInterface};
+use anchor_spl::token_interface::spl_token_2022::extension::{
+    transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+};
+use fanstake_math::{CurveReserves, BPS_DENOMINATOR};
+use solana_sha256_hasher::hashv;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 declare_id!("JCAt7JFiHxMBQ9TcEZYbWkp2GZpF3ZbdYdwD5ZBP6Nkf");
 
 /// Vesting duration: 90 days in seconds
 const VESTING_DURATION: i64 = 90 * 24 * 60 * 60;
 
+/// Every artist token: 1B tokens with 6 decimals
+const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000;
+
+/// Tokens a new curve can sell before it's sold out
+const INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+/// Max bonding curve URI length, also reserved up front in Token-2022 metadata
+const MAX_URI_LEN: usize = 200;
+
+/// Max recipients per airdrop — bounds the claim bitmap at 8 KB
+const MAX_AIRDROP_RECIPIENTS: u32 = 65_536;
+
+/// Number of boost tier slots on the platform config
+pub const MAX_BOOST_TIERS: usize = 4;
+
+/// Prices are lamports per whole token as 1e9 fixed-point (`price / PRICE_SCALE` = lamports).
+pub use fanstake_math::PRICE_SCALE;
+
+/// TWAP observation ring size
+pub const TWAP_OBSERVATIONS: usize = 64;
+
+/// Minimum spacing between TWAP observations (5 minutes → ≥ 5h of history)
+pub const TWAP_OBSERVATION_INTERVAL: i64 = 5 * 60;
+
+/// OHLC buckets per price history account — keeps it under the 10 KB CPI init limit
+pub const PRICE_HISTORY_BUCKETS: usize = 192;
+
+/// Default candle size for new curves: hourly → 8 days of history
+pub const DEFAULT_PRICE_HISTORY_INTERVAL: i64 = 60 * 60;
+
+/// Allowed candle sizes: 1 minute to 1 week
+const MIN_PRICE_HISTORY_INTERVAL: i64 = 60;
+const MAX_PRICE_HISTORY_INTERVAL: i64 = 7 * 24 * 60 * 60;
+
+/// Badge criteria: buyer sequence cut-offs, diamond-hands hold time and
+/// backstage spend (lamports)
+pub const FOUNDING_FAN_MAX_SEQUENCE: u64 = 10;
+pub const DAY_ONE_MAX_SEQUENCE: u64 = 50;
+pub const DIAMOND_HANDS_MIN_HOLD: i64 = 30 * 24 * 60 * 60;
+pub const BACKSTAGE_MIN_SOL_SPENT: u64 = 10_000_000;
+
+/// Index baskets: component cap and index mint decimals (the first deposit
+/// mints one base unit per lamport, so one index token starts at ~1 SOL)
+pub const MAX_INDEX_COMPONENTS: usize = 10;
+const INDEX_DECIMALS: u8 = 9;
+
+/// Shortest DCA interval
+const MIN_DCA_INTERVAL: i64 = 60;
+
+/// Lending: TWAP window used to value collateral, and the cap on the
+/// liquidator bonus
+pub const LENDING_TWAP_WINDOW: i64 = 60 * 60;
+const MAX_LIQUIDATION_BONUS_BPS: u16 = 2_000;
+
+/// Artist treasury: max share of each buy, milestone count and description
+/// length, and how long holders vote on a release
+pub const MAX_TREASURY_BPS: u16 = 5_000;
+pub const MAX_MILESTONES: usize = 5;
+const MAX_MILESTONE_DESCRIPTION_LEN: usize = 64;
+pub const TREASURY_VOTE_DURATION: i64 = 3 * 24 * 60 * 60;
+
+/// Shortest interval between scheduled buybacks
+const MIN_BUYBACK_INTERVAL: i64 = 60;
+
+/// Admin group: most members, highest platform fee it can set, and how long
+/// a proposal stays open
+pub const MAX_ADMIN_MEMBERS: usize = 10;
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+pub const ADMIN_PROPOSAL_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// Notice given before a platform fee change takes effect, until the admin
+/// group sets another delay
+pub const DEFAULT_FEE_CHANGE_DELAY: i64 = 2 * 24 * 60 * 60;
+
+/// Boost pricing a new platform starts with: Basic 0.25 SOL / 24h, Prime 0.5 SOL / 48h
+pub const DEFAULT_BOOST_TIERS: [BoostTier; MAX_BOOST_TIERS] = [
+    BoostTier { price_lamports: 250_000_000, duration_secs: 24 * 60 * 60 },
+    BoostTier { price_lamports: 500_000_000, duration_secs: 48 * 60 * 60 },
+    BoostTier { price_lamports: 0, duration_secs: 0 },
+    BoostTier { price_lamports: 0, duration_secs: 0 },
+];
+
 /// FanStake — The stock market for music artists.
 /// Artists launch personal tokens on Solana via a bonding curve.
 /// Fans buy/sell tokens; price moves along the curve.
@@ -22,6 +114,9 @@ pub mod fanstake {
         config.fee_bps = platform_fee_bps; // e.g., 100 = 1%
         config.fee_vault = ctx.accounts.fee_vault.key();
         config.total_artists = 0;
+        config.fee_change_delay = DEFAULT_FEE_CHANGE_DELAY;
+        config.version = PlatformConfig::VERSION;
+        config.boost_tiers = DEFAULT_BOOST_TIERS;
         Ok(())
     }
 
@@ -37,29 +132,18 @@ pub mod fanstake {
         require!(symbol.len() <= 10, FanStakeError::SymbolTooLong);
         require!(artist_share_bps <= 2000, FanStakeError::ArtistShareTooHigh); // max 20%
 
-        // Calculate artist share before mutable borrow
-        const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000;
-        let artist_share_tokens = (TOTAL_SUPPLY as u128)
-            .checked_mul(artist_share_bps as u128).unwrap()
-            .checked_div(10_000).unwrap() as u64;
-
-        {
-            let curve = &mut ctx.accounts.bonding_curve;
-            curve.artist = ctx.accounts.artist.key();
-            curve.mint = ctx.accounts.mint.key();
-            curve.name = name;
-            curve.symbol = symbol;
-            curve.uri = uri;
-            curve.virtual_sol_reserves = 30_000_000_000;
-            curve.virtual_token_reserves = 1_073_000_000_000_000;
-            curve.real_sol_reserves = 0;
-            curve.real_token_reserves = 793_100_000_000_000;
-            curve.total_supply = TOTAL_SUPPLY;
-            curve.artist_share_bps = artist_share_bps;
-            curve.is_active = true;
-            curve.created_at = Clock::get()?.unix_timestamp;
-            curve.bump = ctx.bumps.bonding_curve;
-        } // mutable borrow dropped here
+        ctx.accounts.bonding_curve.launch(
+            ctx.accounts.artist.key(),
+            ctx.accounts.mint.key(),
+            name,
+            symbol,
+            uri,
+            artist_share_bps,
+            token::ID,
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.bonding_curve,
+        );
+        let artist_share_tokens = ctx.accounts.bonding_curve.artist_allocation();
 
         if artist_share_tokens > 0 {
             let mint_key = ctx.accounts.mint.key();
@@ -89,9 +173,54 @@ pub mod fanstake {
             vesting.artist = ctx.accounts.artist.key();
             vesting.vesting_end = Clock::get()?.unix_timestamp + VESTING_DURATION;
             vesting.bump = ctx.bumps.artist_vesting;
+            vesting.version = VestingSchedule::VERSION;
         }
         msg!("Vesting schedule created: locked for 90 days");
 
+        {
+            let price = ctx.accounts.bonding_curve.spot_price();
+            let stats = &mut ctx.accounts.curve_stats;
+            stats.mint = ctx.accounts.mint.key();
+            stats.ath_price = price;
+            stats.bump = ctx.bumps.curve_stats;
+            stats.version = CurveStats::VERSION;
+            stats.init_oracle(price, Clock::get()?.unix_timestamp);
+        }
+
+        {
+            let history = &mut ctx.accounts.price_history.load_init()?;
+            history.mint = ctx.accounts.mint.key();
+            history.interval_secs = DEFAULT_PRICE_HISTORY_INTERVAL;
+            history.bump = ctx.bumps.price_history;
+            history.version = PriceHistory::VERSION;
+        }
+
+        // Metaplex metadata so wallets and explorers show name, symbol and image.
+        // The curve PDA is both mint and update authority; `update_artist_token` keeps it in sync.
+        {
+            let mint_key = ctx.accounts.mint.key();
+            let seeds: &[&[u8]] = &[b"bonding_curve", mint_key.as_ref(), &[ctx.bumps.bonding_curve]];
+            metadata::create_metadata_accounts_v3(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_metadata_program.to_account_info(),
+                    metadata::CreateMetadataAccountsV3 {
+                        metadata: ctx.accounts.metadata.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        mint_authority: ctx.accounts.bonding_curve.to_account_info(),
+                        payer: ctx.accounts.artist.to_account_info(),
+                        update_authority: ctx.accounts.bonding_curve.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                        rent: ctx.accounts.rent.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                token_metadata(&ctx.accounts.bonding_curve),
+                true,
+                true,
+                None,
+            )?;
+        }
+
         // Update platform stats
         let config = &mut ctx.accounts.platform_config;
         config.total_artists += 1;
@@ -143,6 +272,7 @@ pub mod fanstake {
             vesting.artist = ctx.accounts.artist.key();
             vesting.vesting_end = Clock::get()?.unix_timestamp + VESTING_DURATION;
             vesting.bump = ctx.bumps.artist_vesting;
+            vesting.version = VestingSchedule::VERSION;
         }
         msg!("Vesting schedule created: locked for 90 days from claim");
         Ok(())
@@ -150,363 +280,6433 @@ pub mod fanstake {
 
     /// Artist updates their token's metadata URI (e.g. to fix an image).
     /// Only the original artist wallet can call this.
+    /// Whatever metadata the mint has is kept in sync, so its program must be
+    /// passed when it exists (curves created before metadata existed can add
+    /// it with `init_token_metadata`).
     pub fn update_artist_token(ctx: Context<UpdateArtistToken>, new_uri: String) -> Result<()> {
-        require!(new_uri.len() <= 200, FanStakeError::UriTooLong);
+        require!(new_uri.len() <= MAX_URI_LEN, FanStakeError::UriTooLong);
         ctx.accounts.bonding_curve.uri = new_uri;
+
+        let metadata_account = &ctx.accounts.metadata;
+        if metadata_account.owner == &mpl_token_metadata::ID && !metadata_account.data_is_empty() {
+            let metadata_program =
+                ctx.accounts.token_metadata_program.as_ref().ok_or(FanStakeError::MetadataAccountsMissing)?;
+            let curve = &ctx.accounts.bonding_curve;
+            let seeds: &[&[u8]] = &[b"bonding_curve", curve.mint.as_ref(), &[curve.bump]];
+            metadata::update_metadata_accounts_v2(
+                CpiContext::new_with_signer(
+                    metadata_program.to_account_info(),
+                    metadata::UpdateMetadataAccountsV2 {
+                        metadata: metadata_account.to_account_info(),
+                        update_authority: curve.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                None,
+                Some(token_metadata(curve)),
+                None,
+                None,
+            )?;
+        }
+
+        // Token-2022 mints carry their metadata in the mint itself. Rent for a
+        // max-length URI was prepaid at launch, so this never needs a top-up.
+        let mint = &ctx.accounts.mint;
+        if mint.owner == &token_interface::spl_token_2022::ID {
+            let token_program =
+                ctx.accounts.token_2022_program.as_ref().ok_or(FanStakeError::MetadataAccountsMissing)?;
+            let curve = &ctx.accounts.bonding_curve;
+            let seeds: &[&[u8]] = &[b"bonding_curve", curve.mint.as_ref(), &[curve.bump]];
+            token_interface::token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token_interface::TokenMetadataUpdateField {
+                        program_id: token_program.to_account_info(),
+                        metadata: mint.to_account_info(),
+                        update_authority: curve.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                token_interface::spl_token_metadata_interface::state::Field::Uri,
+                curve.uri.clone(),
+            )?;
+        }
         Ok(())
     }
 
     /// Fan buys artist tokens by sending SOL.
-    pub fn buy(ctx: Context<BuySell>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
-        // Extract values before mutable borrow
-        let curve_bump = ctx.accounts.bonding_curve.bump;
-        let curve_mint = ctx.accounts.bonding_curve.mint;
-        let fee_bps = ctx.accounts.platform_config.fee_bps as u64;
+    pub fn buy(ctx: Context<Buy>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let fill = execute_buy(
+            BuyAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                destination: ctx.accounts.user_token_account.to_account_info(),
+                payer: ctx.accounts.user.to_account_info(),
+                trader: ctx.accounts.user.key(),
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            sol_amount,
+            min_tokens_out,
+            fee_bps,
+            &[],
+        )?;
 
-        require!(ctx.accounts.bonding_curve.is_active, FanStakeError::CurveNotActive);
-        require!(sol_amount > 0, FanStakeError::InvalidAmount);
+        // Per-wallet stats
+        let now = fill.timestamp;
+        let record_bump = ctx.bumps.buyer_record;
+        let record = &mut ctx.accounts.buyer_record;
+        record.init_if_new(ctx.accounts.bonding_curve.mint, ctx.accounts.user.key(), record_bump);
+        record.record_buy(&mut ctx.accounts.curve_stats, sol_amount, now);
+        record.credit_tokens(&mut ctx.accounts.bonding_curve, fill.tokens_out);
+        update_position(&ctx.accounts.position, |p| p.record_buy(sol_amount, fill.tokens_out, now))?;
+        Ok(())
+    }
 
-        // Calculate platform fee
-        let fee = sol_amount.checked_mul(fee_bps).unwrap().checked_div(10_000).unwrap();
-        let sol_after_fee = sol_amount.checked_sub(fee).unwrap();
+    /// Buys artist tokens on behalf of another wallet. The payer covers the
+    /// SOL, fee and (if needed) the recipient's token account; the recipient
+    /// gets the tokens. Gifts don't create a buyer record or touch positions,
+    /// but count toward refunds and votes if the recipient already has one.
+    pub fn gift_buy(
+        ctx: Context<GiftBuy>,
+        sol_amount: u64,
+        min_tokens_out: u64,
+        memo_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let fill = execute_buy(
+            BuyAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                destination: ctx.accounts.recipient_token_account.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                trader: ctx.accounts.payer.key(),
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            sol_amount,
+            min_tokens_out,
+            fee_bps,
+            &[],
+        )?;
 
-        // Calculate tokens out using constant product formula
-        let tokens_out = {
-            let curve = &ctx.accounts.bonding_curve;
-            (sol_after_fee as u128)
-                .checked_mul(curve.virtual_token_reserves as u128)
-                .unwrap()
-                .checked_div(
-                    (curve.virtual_sol_reserves as u128)
-                        .checked_add(sol_after_fee as u128)
-                        .unwrap(),
-                )
-                .unwrap() as u64
-        };
+        credit_tokens(&ctx.accounts.recipient_buyer_record, &mut ctx.accounts.bonding_curve, fill.tokens_out)?;
+
+        emit!(GiftEvent {
+            mint: ctx.accounts.bonding_curve.mint,
+            payer: ctx.accounts.payer.key(),
+            recipient: ctx.accounts.recipient.key(),
+            sol_amount,
+            token_amount: fill.tokens_out,
+            memo_hash,
+            timestamp: fill.timestamp,
+        });
+
+        msg!("GIFT: {} tokens -> {}", fill.tokens_out, ctx.accounts.recipient.key());
+        Ok(())
+    }
+
+    /// Fan sells artist tokens back for SOL.
+    pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_out: u64) -> Result<()> {
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        // Vesting check — if seller is the artist, enforce lockup period
+        if ctx.accounts.user.key() == ctx.accounts.bonding_curve.artist {
+            require_vested(&ctx.accounts.artist_vesting)?;
+        }
+
+        let fill = execute_sell(
+            SellAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                source: ctx.accounts.user_token_account.to_account_info(),
+                owner: ctx.accounts.user.to_account_info(),
+                recipient: ctx.accounts.user.to_account_info(),
+                trader: ctx.accounts.user.key(),
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                vault_bump: ctx.bumps.curve_vault,
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            token_amount,
+            min_sol_out,
+            fee_bps,
+            &[],
+        )?;
+
+        // Per-wallet stats — wallets that never bought have no record to mark
+        record_sale(&ctx.accounts.buyer_record, &mut ctx.accounts.bonding_curve, token_amount)?;
+        update_position(&ctx.accounts.position, |p| p.record_sell(token_amount, fill.sol_out, fill.timestamp))?;
+        Ok(())
+    }
+
+    /// Creator funds an airdrop escrow with artist tokens and commits a Merkle root
+    /// over `(index, claimant, amount)` leaves. Unclaimed tokens can be reclaimed after `expires_at`.
+    /// Tokens move in and out of the escrow with `move_tokens`, here and in
+    /// `claim_airdrop` and `reclaim_unclaimed`.
+    pub fn create_airdrop(
+        ctx: Context<CreateAirdrop>,
+        airdrop_id: u64,
+        merkle_root: [u8; 32],
+        total_amount: u64,
+        num_recipients: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(total_amount > 0, FanStakeError::InvalidAmount);
+        require!(
+            num_recipients > 0 && num_recipients <= MAX_AIRDROP_RECIPIENTS,
+            FanStakeError::InvalidRecipientCount
+        );
+        require!(expires_at > Clock::get()?.unix_timestamp, FanStakeError::InvalidExpiry);
+        // The hook doesn't see escrow moves, so hold the artist to its lockup here
+        if ctx.accounts.token_program.key() == token_interface::spl_token_2022::ID
+            && ctx.accounts.creator.key() == ctx.accounts.bonding_curve.artist
+        {
+            let balance_after = ctx.accounts.creator_token_account.amount.saturating_sub(total_amount);
+            require_allocation_kept(&ctx.accounts.artist_vesting, &ctx.accounts.bonding_curve, balance_after)?;
+        }
+
+        {
+            let airdrop = &mut ctx.accounts.airdrop;
+            airdrop.creator = ctx.accounts.creator.key();
+            airdrop.mint = ctx.accounts.mint.key();
+            airdrop.airdrop_id = airdrop_id;
+            airdrop.merkle_root = merkle_root;
+            airdrop.total_amount = total_amount;
+            airdrop.claimed_amount = 0;
+            airdrop.num_recipients = num_recipients;
+            airdrop.num_claimed = 0;
+            airdrop.expires_at = expires_at;
+            airdrop.bump = ctx.bumps.airdrop;
+            airdrop.version = Airdrop::VERSION;
+            airdrop.claim_bitmap = vec![0u8; Airdrop::bitmap_len(num_recipients)];
+        }
+
+        // Move the airdrop supply into the escrow
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.creator_token_account.to_account_info(),
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            &[],
+            total_amount,
+        )?;
+
+        msg!("Airdrop {} created: {} tokens for {} recipients", airdrop_id, total_amount, num_recipients);
+        Ok(())
+    }
 
-        require!(tokens_out >= min_tokens_out, FanStakeError::SlippageExceeded);
-        require!(tokens_out <= ctx.accounts.bonding_curve.real_token_reserves, FanStakeError::InsufficientTokens);
+    /// Claimant proves their `(index, amount)` leaf against the airdrop root.
+    /// Each index can be claimed exactly once (tracked in the claim bitmap).
+    pub fn claim_airdrop(
+        ctx: Context<ClaimAirdrop>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let airdrop = &ctx.accounts.airdrop;
+        require!(Clock::get()?.unix_timestamp < airdrop.expires_at, FanStakeError::AirdropExpired);
+        require!(index < airdrop.num_recipients, FanStakeError::InvalidRecipientIndex);
+        require!(!airdrop.is_claimed(index), FanStakeError::AlreadyClaimed);
+
+        let leaf = airdrop_leaf(index, &ctx.accounts.claimant.key(), amount);
+        require!(
+            verify_merkle_proof(&proof, airdrop.merkle_root, leaf),
+            FanStakeError::InvalidMerkleProof
+        );
+
+        let mint_key = airdrop.mint;
+        let creator_key = airdrop.creator;
+        let id_bytes = airdrop.airdrop_id.to_le_bytes();
+        let bump = airdrop.bump;
 
-        // Update curve state
         {
-            let curve = &mut ctx.accounts.bonding_curve;
-            curve.virtual_sol_reserves = curve.virtual_sol_reserves.checked_add(sol_after_fee).unwrap();
-            curve.virtual_token_reserves = curve.virtual_token_reserves.checked_sub(tokens_out).unwrap();
-            curve.real_sol_reserves = curve.real_sol_reserves.checked_add(sol_after_fee).unwrap();
-            curve.real_token_reserves = curve.real_token_reserves.checked_sub(tokens_out).unwrap();
+            let airdrop = &mut ctx.accounts.airdrop;
+            airdrop.set_claimed(index);
+            airdrop.claimed_amount = airdrop.claimed_amount.checked_add(amount).unwrap();
+            airdrop.num_claimed += 1;
+        }
+
+        // Escrow → claimant (airdrop PDA signs)
+        let seeds: &[&[u8]] = &[b"airdrop", mint_key.as_ref(), creator_key.as_ref(), &id_bytes, &[bump]];
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.claimant_token_account.to_account_info(),
+            ctx.accounts.airdrop.to_account_info(),
+            &[seeds],
+            amount,
+        )?;
+
+        msg!("AIRDROP CLAIM: index {} -> {} tokens", index, amount);
+        Ok(())
+    }
+
+    /// After expiry, the creator takes back whatever is left in the escrow.
+    /// Closes both the escrow and the airdrop account (rent back to creator).
+    pub fn reclaim_unclaimed(ctx: Context<ReclaimUnclaimed>) -> Result<()> {
+        let airdrop = &ctx.accounts.airdrop;
+        require!(Clock::get()?.unix_timestamp >= airdrop.expires_at, FanStakeError::AirdropNotExpired);
+
+        let mint_key = airdrop.mint;
+        let creator_key = airdrop.creator;
+        let id_bytes = airdrop.airdrop_id.to_le_bytes();
+        let bump = airdrop.bump;
+        let seeds: &[&[u8]] = &[b"airdrop", mint_key.as_ref(), creator_key.as_ref(), &id_bytes, &[bump]];
+        let signer_seeds = &[seeds];
+
+        let leftover = ctx.accounts.escrow.amount;
+        if leftover > 0 {
+            move_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.mint,
+                &ctx.accounts.bonding_curve,
+                ctx.accounts.escrow.to_account_info(),
+                ctx.accounts.creator_token_account.to_account_info(),
+                ctx.accounts.airdrop.to_account_info(),
+                signer_seeds,
+                leftover,
+            )?;
         }
 
-        // Transfer SOL from buyer to curve vault
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.airdrop.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        msg!("Airdrop reclaimed: {} unclaimed tokens returned", leftover);
+        Ok(())
+    }
+
+    /// Admin sets (or disables, with price 0) a boost tier.
+    pub fn set_boost_tier(
+        ctx: Context<SetBoostTier>,
+        tier: u8,
+        price_lamports: u64,
+        duration_secs: i64,
+    ) -> Result<()> {
+        ctx.accounts.platform_config.set_boost_tier(tier, price_lamports, duration_secs)
+    }
+
+    /// Admin sets how much of the platform fee is waived on each leg of
+    /// `swap_artist_tokens` (0 = full fee twice, 5,000 = one fee's worth).
+    pub fn set_swap_fee_discount(ctx: Context<SetSwapFeeDiscount>, discount_bps: u16) -> Result<()> {
+        ctx.accounts.platform_config.set_swap_fee_discount(discount_bps)
+    }
+
+    /// Admin sets the notice a fee change needs until the admin group takes
+    /// over. Cuts wait out the current delay, as they do under the group.
+    pub fn set_fee_change_delay(ctx: Context<SetFeeChangeDelay>, delay_secs: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.platform_config.set_fee_change_delay(delay_secs, now)
+    }
+
+    /// Anyone pays to boost an artist's curve. Payment goes to the platform fee vault.
+    /// If a boost is already running, the new duration is added onto its expiry.
+    pub fn purchase_boost(ctx: Context<PurchaseBoost>, tier: u8) -> Result<()> {
+        require!((tier as usize) < MAX_BOOST_TIERS, FanStakeError::InvalidBoostTier);
+        let tier_config = ctx.accounts.platform_config.boost_tiers[tier as usize];
+        require!(tier_config.price_lamports > 0, FanStakeError::InvalidBoostTier);
+
         anchor_lang::system_program::transfer(
             CpiContext::new(
                 ctx.accounts.system_program.to_account_info(),
                 anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.user.to_account_info(),
-                    to: ctx.accounts.curve_vault.to_account_info(),
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.fee_vault.to_account_info(),
                 },
             ),
-            sol_after_fee,
+            tier_config.price_lamports,
         )?;
 
-        // Transfer fee to platform vault
-        if fee > 0 {
-            anchor_lang::system_program::transfer(
-                CpiContext::new(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.user.to_account_info(),
-                        to: ctx.accounts.fee_vault.to_account_info(),
-                    },
-                ),
-                fee,
-            )?;
+        let now = Clock::get()?.unix_timestamp;
+        let boost = &mut ctx.accounts.boost;
+        if boost.expires_at > now {
+            // Extend the running boost; keep the highest tier bought during it
+            boost.expires_at = boost.expires_at.checked_add(tier_config.duration_secs).unwrap();
+            boost.tier = boost.tier.max(tier);
+        } else {
+            boost.mint = ctx.accounts.bonding_curve.mint;
+            boost.started_at = now;
+            boost.expires_at = now.checked_add(tier_config.duration_secs).unwrap();
+            boost.tier = tier;
+            boost.bump = ctx.bumps.boost;
+            boost.version = Boost::VERSION;
         }
+        boost.last_payer = ctx.accounts.payer.key();
+        boost.total_paid = boost.total_paid.checked_add(tier_config.price_lamports).unwrap();
 
-        // Mint tokens to buyer (PDA signs)
-        let seeds: &[&[u8]] = &[b"bonding_curve", curve_mint.as_ref(), &[curve_bump]];
-        let signer_seeds = &[seeds];
+        msg!("BOOST: tier {} for {} until {}", tier, boost.mint, boost.expires_at);
+        Ok(())
+    }
 
-        token::mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: ctx.accounts.user_token_account.to_account_info(),
-                    authority: ctx.accounts.bonding_curve.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            tokens_out,
-        )?;
+    /// Creates the stats account for a curve launched before stats existed.
+    /// Permissionless — counters start from zero at the time of the call.
+    pub fn init_curve_stats(ctx: Context<InitCurveStats>) -> Result<()> {
+        let price = ctx.accounts.bonding_curve.spot_price();
+        let stats = &mut ctx.accounts.curve_stats;
+        stats.mint = ctx.accounts.bonding_curve.mint;
+        stats.ath_price = price;
+        stats.bump = ctx.bumps.curve_stats;
+        stats.version = CurveStats::VERSION;
+        stats.init_oracle(price, Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Time-weighted average price over (at least) the last `window` seconds,
+    /// returned via return data. Read-only — safe to call via CPI or simulation.
+    pub fn get_twap(ctx: Context<GetTwap>, window: i64) -> Result<TwapResult> {
+        require!(window > 0, FanStakeError::InvalidAmount);
+        let stats = &ctx.accounts.curve_stats;
+        let now = Clock::get()?.unix_timestamp;
+        let (price, actual_window) = stats
+            .twap(window, now)
+            .ok_or(FanStakeError::TwapWindowUnavailable)?;
+        Ok(TwapResult {
+            price,
+            window: actual_window,
+            spot_price: stats.last_price,
+        })
+    }
 
-        msg!("BUY: {} SOL -> {} tokens (fee: {} SOL)", sol_after_fee, tokens_out, fee);
+    /// Creates the price history account for a curve launched before it existed.
+    /// Permissionless; the caller picks the candle size.
+    pub fn init_price_history(ctx: Context<InitPriceHistory>, interval_secs: i64) -> Result<()> {
+        require!(
+            (MIN_PRICE_HISTORY_INTERVAL..=MAX_PRICE_HISTORY_INTERVAL).contains(&interval_secs),
+            FanStakeError::InvalidInterval
+        );
+        let history = &mut ctx.accounts.price_history.load_init()?;
+        history.mint = ctx.accounts.bonding_curve.mint;
+        history.interval_secs = interval_secs;
+        history.bump = ctx.bumps.price_history;
+        history.version = PriceHistory::VERSION;
         Ok(())
     }
 
-    /// Fan sells artist tokens back for SOL.
-    pub fn sell(ctx: Context<BuySell>, token_amount: u64, min_sol_out: u64) -> Result<()> {
-        // Extract values before mutable borrow
-        let curve_mint = ctx.accounts.bonding_curve.mint;
-        let fee_bps = ctx.accounts.platform_config.fee_bps as u64;
-        let vault_bump = ctx.bumps.curve_vault;
+    /// Artist changes the candle size. Existing buckets are cleared since they
+    /// can't be re-bucketed.
+    pub fn set_price_history_interval(ctx: Context<SetPriceHistoryInterval>, interval_secs: i64) -> Result<()> {
+        require!(
+            (MIN_PRICE_HISTORY_INTERVAL..=MAX_PRICE_HISTORY_INTERVAL).contains(&interval_secs),
+            FanStakeError::InvalidInterval
+        );
+        let history = &mut ctx.accounts.price_history.load_mut()?;
+        history.interval_secs = interval_secs;
+        history.head = 0;
+        history.count = 0;
+        history.buckets.iter_mut().for_each(|b| *b = PriceBucket::default());
+        Ok(())
+    }
 
-        require!(ctx.accounts.bonding_curve.is_active, FanStakeError::CurveNotActive);
+    /// Read-only quote for `buy(sol_amount)`: tokens out, fee and post-trade price via
+    /// return data. Fails exactly where `buy` would (inactive curve, sold out).
+    pub fn quote_buy(ctx: Context<Quote>, sol_amount: u64) -> Result<BuyQuoteResult> {
+        let curve = &ctx.accounts.bonding_curve;
+        require!(curve.is_active, FanStakeError::CurveNotActive);
+        require!(sol_amount > 0, FanStakeError::InvalidAmount);
+
+        let reserves = curve.reserves();
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let quote = fanstake_math::quote_buy_with_treasury(&reserves, sol_amount, fee_bps, curve.treasury_bps as u64)
+            .ok_or(FanStakeError::MathOverflow)?;
+        require!(quote.tokens_out <= reserves.real_token, FanStakeError::InsufficientTokens);
+
+        Ok(BuyQuoteResult {
+            tokens_out: quote.tokens_out,
+            fee: quote.fee,
+            price_after: quote.price_after,
+        })
+    }
+
+    /// Read-only quote for `sell(token_amount)`: SOL out, fee and post-trade price via
+    /// return data. Does not apply the artist vesting check.
+    pub fn quote_sell(ctx: Context<Quote>, token_amount: u64) -> Result<SellQuoteResult> {
+        let curve = &ctx.accounts.bonding_curve;
+        require!(curve.is_active, FanStakeError::CurveNotActive);
         require!(token_amount > 0, FanStakeError::InvalidAmount);
 
-        // Vesting check — if seller is the artist, enforce lockup period
-        if ctx.accounts.user.key() == ctx.accounts.bonding_curve.artist {
-            if let Some(vesting) = ctx.accounts.artist_vesting.as_ref() {
-                let now = Clock::get()?.unix_timestamp;
-                require!(now >= vesting.vesting_end, FanStakeError::TokensStillVesting);
-            }
-        }
+        let reserves = curve.reserves();
+        let quote = fanstake_math::quote_sell(&reserves, token_amount, ctx.accounts.platform_config.current_fee_bps()?)
+            .ok_or(FanStakeError::MathOverflow)?;
+        require!(quote.sol_out_gross <= reserves.real_sol, FanStakeError::InsufficientSol);
 
-        // Calculate SOL out using constant product formula
-        let sol_out_gross = {
-            let curve = &ctx.accounts.bonding_curve;
-            (token_amount as u128)
-                .checked_mul(curve.virtual_sol_reserves as u128)
-                .unwrap()
-                .checked_div(
-                    (curve.virtual_token_reserves as u128)
-                        .checked_add(token_amount as u128)
-                        .unwrap(),
-                )
-                .unwrap() as u64
-        };
+        Ok(SellQuoteResult {
+            sol_out: quote.sol_out,
+            fee: quote.fee,
+            price_after: quote.price_after,
+        })
+    }
 
-        // Calculate platform fee
-        let fee = sol_out_gross.checked_mul(fee_bps).unwrap().checked_div(10_000).unwrap();
-        let sol_out = sol_out_gross.checked_sub(fee).unwrap();
+    /// Opens a cost-basis `Position` for the caller on one curve. Optional —
+    /// once it exists, every buy, sell, swap and order/DCA fill for the wallet
+    /// updates it. Only trades made after opening are tracked.
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.mint = ctx.accounts.bonding_curve.mint;
+        position.wallet = ctx.accounts.wallet.key();
+        position.opened_at = Clock::get()?.unix_timestamp;
+        position.bump = ctx.bumps.position;
+        position.version = Position::VERSION;
+        Ok(())
+    }
 
-        require!(sol_out >= min_sol_out, FanStakeError::SlippageExceeded);
-        require!(sol_out_gross <= ctx.accounts.bonding_curve.real_sol_reserves, FanStakeError::InsufficientSol);
+    /// Issues a badge attestation once the wallet's buyer record meets the
+    /// criteria. The badge is a PDA owned by the program, so it can't be
+    /// transferred — other apps verify it by deriving the address.
+    pub fn mint_badge(ctx: Context<MintBadge>, kind: BadgeKind) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let record = &ctx.accounts.buyer_record;
+        let balance = ctx.accounts.holder_token_account.amount;
+        require!(record.qualifies_for(kind, balance, now), FanStakeError::BadgeNotEarned);
 
-        // Update curve state
-        {
-            let curve = &mut ctx.accounts.bonding_curve;
-            curve.virtual_sol_reserves = curve.virtual_sol_reserves.checked_sub(sol_out_gross).unwrap();
-            curve.virtual_token_reserves = curve.virtual_token_reserves.checked_add(token_amount).unwrap();
-            curve.real_sol_reserves = curve.real_sol_reserves.checked_sub(sol_out_gross).unwrap();
-            curve.real_token_reserves = curve.real_token_reserves.checked_add(token_amount).unwrap();
-        }
+        let badge = &mut ctx.accounts.badge;
+        badge.mint = record.mint;
+        badge.wallet = record.wallet;
+        badge.kind = kind;
+        badge.sequence = record.sequence;
+        badge.first_buy_at = record.first_buy_at;
+        badge.sol_spent = record.sol_spent;
+        badge.issued_at = now;
+        badge.bump = ctx.bumps.badge;
+        badge.version = Badge::VERSION;
 
-        // Burn tokens from seller
-        token::burn(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                token::Burn {
+        msg!("BADGE: {:?} for buyer #{}", kind, record.sequence);
+        Ok(())
+    }
+
+    /// Creates Metaplex metadata for a curve launched before metadata existed.
+    /// Permissionless — the content always comes from the curve account.
+    pub fn init_token_metadata(ctx: Context<InitTokenMetadata>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+        let seeds: &[&[u8]] = &[b"bonding_curve", curve.mint.as_ref(), &[curve.bump]];
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                metadata::CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
                     mint: ctx.accounts.mint.to_account_info(),
-                    from: ctx.accounts.user_token_account.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
+                    mint_authority: curve.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    update_authority: curve.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
                 },
+                &[seeds],
             ),
-            token_amount,
-        )?;
+            token_metadata(curve),
+            true,
+            true,
+            None,
+        )
+    }
+
+    /// Same as `create_artist_token`, but the mint is Token-2022 with the
+    /// metadata stored in the mint and a transfer hook (this program) that
+    /// keeps the artist's allocation locked until vesting ends — even for
+    /// plain wallet-to-wallet transfers.
+    pub fn create_artist_token_2022(
+        ctx: Context<CreateArtistToken2022>,
+        name: String,
+        symbol: String,
+        uri: String,
+        artist_share_bps: u16,
+    ) -> Result<()> {
+        require!(name.len() <= 32, FanStakeError::NameTooLong);
+        require!(symbol.len() <= 10, FanStakeError::SymbolTooLong);
+        require!(uri.len() <= MAX_URI_LEN, FanStakeError::UriTooLong);
+        require!(artist_share_bps <= 2000, FanStakeError::ArtistShareTooHigh); // max 20%
 
-        // Transfer SOL to seller from curve vault (PDA-signed CPI)
-        let vault_seeds: &[&[u8]] = &[b"curve_vault", curve_mint.as_ref(), &[vault_bump]];
-        let signer_seeds = &[vault_seeds];
+        let now = Clock::get()?.unix_timestamp;
+        let mint_key = ctx.accounts.mint.key();
+        let artist_key = ctx.accounts.artist.key();
+        ctx.accounts.bonding_curve.launch(
+            artist_key,
+            mint_key,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            artist_share_bps,
+            token_interface::spl_token_2022::ID,
+            now,
+            ctx.bumps.bonding_curve,
+        );
+        let seeds: &[&[u8]] = &[b"bonding_curve", mint_key.as_ref(), &[ctx.bumps.bonding_curve]];
 
-        anchor_lang::system_program::transfer(
+        // Fund the mint for the metadata extension, sized for a max-length URI
+        {
+            let metadata = token_interface::spl_token_metadata_interface::state::TokenMetadata {
+                name: name.clone(),
+                symbol: symbol.clone(),
+                uri: "x".repeat(MAX_URI_LEN),
+                ..Default::default()
+            };
+            let mint_info = ctx.accounts.mint.to_account_info();
+            let size = mint_info.data_len() + metadata.tlv_size_of()?;
+            let needed = Rent::get()?.minimum_balance(size).saturating_sub(mint_info.lamports());
+            if needed > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.artist.to_account_info(),
+                            to: mint_info,
+                        },
+                    ),
+                    needed,
+                )?;
+            }
+        }
+        token_interface::token_metadata_initialize(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.curve_vault.to_account_info(),
-                    to: ctx.accounts.user.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TokenMetadataInitialize {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    update_authority: ctx.accounts.bonding_curve.to_account_info(),
+                    mint_authority: ctx.accounts.bonding_curve.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
                 },
-                signer_seeds,
+                &[seeds],
             ),
-            sol_out,
+            name,
+            symbol,
+            uri,
         )?;
 
-        // Transfer fee to platform vault
-        if fee > 0 {
-            anchor_lang::system_program::transfer(
+        // Extra accounts the transfer hook needs, resolved by Token-2022 from the mint
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &transfer_hook_extra_accounts()?,
+        )?;
+
+        let artist_share_tokens = ctx.accounts.bonding_curve.artist_allocation();
+        if artist_share_tokens > 0 {
+            token_interface::mint_to(
                 CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.curve_vault.to_account_info(),
-                        to: ctx.accounts.fee_vault.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::MintTo {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.artist_token_account.to_account_info(),
+                        authority: ctx.accounts.bonding_curve.to_account_info(),
                     },
-                    signer_seeds,
+                    &[seeds],
                 ),
-                fee,
+                artist_share_tokens,
             )?;
+            msg!("Minted {} tokens to artist wallet", artist_share_tokens);
+        }
+
+        {
+            let vesting = &mut ctx.accounts.artist_vesting;
+            vesting.mint = mint_key;
+            vesting.artist = artist_key;
+            vesting.vesting_end = now + VESTING_DURATION;
+            vesting.bump = ctx.bumps.artist_vesting;
+            vesting.version = VestingSchedule::VERSION;
+        }
+
+        {
+            let price = ctx.accounts.bonding_curve.spot_price();
+            let stats = &mut ctx.accounts.curve_stats;
+            stats.mint = mint_key;
+            stats.ath_price = price;
+            stats.bump = ctx.bumps.curve_stats;
+            stats.version = CurveStats::VERSION;
+            stats.init_oracle(price, now);
+        }
+
+        {
+            let history = &mut ctx.accounts.price_history.load_init()?;
+            history.mint = mint_key;
+            history.interval_secs = DEFAULT_PRICE_HISTORY_INTERVAL;
+            history.bump = ctx.bumps.price_history;
+            history.version = PriceHistory::VERSION;
         }
 
-        msg!("SELL: {} tokens -> {} SOL (fee: {} SOL)", token_amount, sol_out, fee);
+        ctx.accounts.platform_config.total_artists += 1;
+
+        msg!("Artist token created (Token-2022): {} ({})", ctx.accounts.bonding_curve.name, ctx.accounts.bonding_curve.symbol);
         Ok(())
     }
-}
 
-// ============================================================
-// ACCOUNTS
-// ============================================================
+    /// Token-2022 transfer hook for artist mints. Rejects transfers out of the
+    /// artist's allocation account that would leave it below the locked
+    /// allocation before vesting ends. Tokens the artist bought on top stay
+    /// transferable. Tokens leaving a wallet take their refund and vote
+    /// eligibility with them (see `BuyerRecord::forfeit_tokens`). Only runs
+    /// inside a Token-2022 transfer; the program's own escrow moves don't
+    /// make one (see `move_tokens`).
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        let source = ctx.accounts.source_token.to_account_info();
+        {
+            let data = source.try_borrow_data()?;
+            let account = StateWithExtensions::<token_interface::spl_token_2022::state::Account>::unpack(&data)?;
+            let hook = account.get_extension::<TransferHookAccount>()?;
+            require!(bool::from(hook.transferring), FanStakeError::NotTransferring);
+        }
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + PlatformConfig::INIT_SPACE,
-        seeds = [b"platform_config"],
-        bump,
-    )]
-    pub platform_config: Account<'info, PlatformConfig>,
-    /// CHECK: Fee vault PDA
-    #[account(
-        seeds = [b"fee_vault"],
-        bump,
-    )]
-    pub fee_vault: AccountInfo<'info>,
-    #[account(mut)]
+        if let Some(mut record) = load_if_exists::<BuyerRecord>(&ctx.accounts.buyer_record)? {
+            if record.forfeit_tokens(&mut ctx.accounts.bonding_curve, amount) > 0 {
+                store(&ctx.accounts.buyer_record, &record)?;
+            }
+        }
+
+        let vesting = &ctx.accounts.artist_vesting;
+        let allocation_account = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &vesting.artist,
+            &vesting.mint,
+            &token_interface::spl_token_2022::ID,
+        );
+        if ctx.accounts.source_token.key() != allocation_account {
+            return Ok(());
+        }
+        if Clock::get()?.unix_timestamp >= vesting.vesting_end {
+            return Ok(());
+        }
+        // Token-2022 calls the hook after moving the tokens: this is the post-transfer balance
+        require!(
+            ctx.accounts.source_token.amount >= ctx.accounts.bonding_curve.artist_allocation(),
+            FanStakeError::TokensStillVesting
+        );
+        Ok(())
+    }
+
+    /// Curator creates an index over up to 10 artist curves, weighted in basis
+    /// points (must sum to 10,000). Remaining accounts, per component in
+    /// order: `[bonding_curve, mint, index_token_account]`; the index PDA's
+    /// token accounts are created here.
+    pub fn create_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateIndex<'info>>,
+        index_id: u64,
+        name: String,
+        symbol: String,
+        components: Vec<IndexComponent>,
+    ) -> Result<()> {
+        require!(name.len() <= 32, FanStakeError::NameTooLong);
+        require!(symbol.len() <= 10, FanStakeError::SymbolTooLong);
+        require!(
+            (1..=MAX_INDEX_COMPONENTS).contains(&components.len())
+                && components.iter().all(|c| c.weight_bps > 0)
+                && components.iter().map(|c| c.weight_bps as u64).sum::<u64>() == BPS_DENOMINATOR
+                && components.iter().enumerate().all(|(i, c)| components[..i].iter().all(|p| p.mint != c.mint)),
+            FanStakeError::InvalidIndexComponents
+        );
+        require!(
+            ctx.remaining_accounts.len() == components.len() * 3,
+            FanStakeError::IndexAccountMismatch
+        );
+
+        let index_key = ctx.accounts.index.key();
+        for (component, accounts) in components.iter().zip(ctx.remaining_accounts.chunks(3)) {
+            let [curve_info, mint, index_token_account] = accounts else { unreachable!() };
+            let curve = Account::<BondingCurve>::try_from(curve_info)?;
+            require!(
+                curve.mint == component.mint && mint.key() == component.mint,
+                FanStakeError::IndexAccountMismatch
+            );
+            require!(curve.is_active, FanStakeError::CurveNotActive);
+            let token_program = component_token_program(
+                &curve,
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_2022_program,
+            )?;
+            anchor_spl::associated_token::create_idempotent(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                anchor_spl::associated_token::Create {
+                    payer: ctx.accounts.curator.to_account_info(),
+                    associated_token: index_token_account.clone(),
+                    authority: ctx.accounts.index.to_account_info(),
+                    mint: mint.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program,
+                },
+            ))?;
+            msg!("Index component {}: {} bps", component.mint, component.weight_bps);
+        }
+
+        let index = &mut ctx.accounts.index;
+        index.curator = ctx.accounts.curator.key();
+        index.index_id = index_id;
+        index.index_mint = ctx.accounts.index_mint.key();
+        index.name = name;
+        index.symbol = symbol;
+        index.components = components;
+        index.created_at = Clock::get()?.unix_timestamp;
+        index.bump = ctx.bumps.index;
+        index.version = Index::VERSION;
+
+        msg!("Index created: {} ({})", index.name, index_key);
+        Ok(())
+    }
+
+    /// Buys every component of an index in one go, splitting `sol_amount` by
+    /// weight through the regular buy path, and mints index tokens for the
+    /// value added. Remaining accounts, per component in order:
+    /// `[bonding_curve, mint, index_token_account, curve_stats, price_history, curve_vault]`.
+    pub fn buy_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyIndex<'info>>,
+        sol_amount: u64,
+        min_index_out: u64,
+    ) -> Result<()> {
+        require!(sol_amount > 0, FanStakeError::InvalidAmount);
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let index_key = ctx.accounts.index.key();
+        let components = ctx.accounts.index.components.clone();
+        let legs = IndexLeg::load_all(&index_key, &components, ctx.remaining_accounts)?;
+
+        // Basket and deposit are both valued at each curve's pre-trade spot price
+        let mut basket_value: u64 = 0;
+        let mut deposit_value: u64 = 0;
+        let mut sol_left = sol_amount;
+        for (i, (component, mut leg)) in components.iter().zip(legs).enumerate() {
+            let leg_sol = if i + 1 == components.len() {
+                sol_left
+            } else {
+                fanstake_math::pro_rata(sol_amount, component.weight_bps as u64, BPS_DENOMINATOR)
+                    .ok_or(FanStakeError::MathOverflow)?
+            };
+            sol_left -= leg_sol;
+
+            let (virtual_sol, virtual_token) =
+                (leg.bonding_curve.virtual_sol_reserves, leg.bonding_curve.virtual_token_reserves);
+            let token_program = component_token_program(
+                &leg.bonding_curve,
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_2022_program,
+            )?;
+            let fill = execute_buy(
+                BuyAccounts {
+                    bonding_curve: &mut leg.bonding_curve,
+                    platform_config: &mut ctx.accounts.platform_config,
+                    curve_stats: &mut leg.curve_stats,
+                    price_history: &leg.price_history,
+                    mint: leg.mint.clone(),
+                    destination: leg.index_token_account.clone(),
+                    payer: ctx.accounts.user.to_account_info(),
+                    trader: ctx.accounts.user.key(),
+                    curve_vault: leg.curve_vault.clone(),
+                    fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                    token_program,
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                leg_sol,
+                0,
+                fee_bps,
+                &[],
+            )?;
+            leg.exit()?;
+
+            let value = |amount| {
+                fanstake_math::token_value(virtual_sol, virtual_token, amount).ok_or(FanStakeError::MathOverflow)
+            };
+            basket_value = basket_value.checked_add(value(leg.holding)?).ok_or(FanStakeError::MathOverflow)?;
+            deposit_value = deposit_value.checked_add(value(fill.tokens_out)?).ok_or(FanStakeError::MathOverflow)?;
+        }
+
+        let index_out = fanstake_math::index_tokens_for_deposit(
+            ctx.accounts.index_mint.supply,
+            basket_value,
+            deposit_value,
+        )
+        .ok_or(FanStakeError::MathOverflow)?;
+        require!(index_out > 0, FanStakeError::InvalidAmount);
+        require!(index_out >= min_index_out, FanStakeError::SlippageExceeded);
+
+        let index = &ctx.accounts.index;
+        let id_bytes = index.index_id.to_le_bytes();
+        let seeds: &[&[u8]] = &[b"index", index.curator.as_ref(), &id_bytes, &[index.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.index_mint.to_account_info(),
+                    to: ctx.accounts.user_index_account.to_account_info(),
+                    authority: index.to_account_info(),
+                },
+                &[seeds],
+            ),
+            index_out,
+        )?;
+
+        ctx.accounts.index_mint.reload()?;
+        emit!(IndexTradeEvent {
+            index: index_key,
+            trader: ctx.accounts.user.key(),
+            is_buy: true,
+            sol_amount,
+            index_amount: index_out,
+            index_supply: ctx.accounts.index_mint.supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("INDEX BUY: {} SOL -> {} index tokens", sol_amount, index_out);
+        Ok(())
+    }
+
+    /// Burns index tokens and sells the matching pro-rata share of every
+    /// component back to its curve, paying the SOL to the holder. Same
+    /// remaining accounts as `buy_index`.
+    pub fn redeem_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemIndex<'info>>,
+        index_amount: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        require!(index_amount > 0, FanStakeError::InvalidAmount);
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let index_key = ctx.accounts.index.key();
+        let components = ctx.accounts.index.components.clone();
+        let legs = IndexLeg::load_all(&index_key, &components, ctx.remaining_accounts)?;
+        let supply = ctx.accounts.index_mint.supply;
+
+        // Burn first — fails if the holder doesn't have `index_amount`
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.index_mint.to_account_info(),
+                    from: ctx.accounts.user_index_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            index_amount,
+        )?;
+
+        let index = &ctx.accounts.index;
+        let id_bytes = index.index_id.to_le_bytes();
+        let seeds: &[&[u8]] = &[b"index", index.curator.as_ref(), &id_bytes, &[index.bump]];
+        let mut sol_out: u64 = 0;
+        for mut leg in legs {
+            let tokens = fanstake_math::pro_rata(leg.holding, index_amount, supply)
+                .ok_or(FanStakeError::MathOverflow)?;
+            if tokens == 0 {
+                continue;
+            }
+            let token_program = component_token_program(
+                &leg.bonding_curve,
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_2022_program,
+            )?;
+            let fill = execute_sell(
+                SellAccounts {
+                    bonding_curve: &mut leg.bonding_curve,
+                    platform_config: &mut ctx.accounts.platform_config,
+                    curve_stats: &mut leg.curve_stats,
+                    price_history: &leg.price_history,
+                    mint: leg.mint.clone(),
+                    source: leg.index_token_account.clone(),
+                    owner: index.to_account_info(),
+                    recipient: ctx.accounts.user.to_account_info(),
+                    trader: ctx.accounts.user.key(),
+                    curve_vault: leg.curve_vault.clone(),
+                    vault_bump: leg.vault_bump,
+                    fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                    token_program,
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                tokens,
+                0,
+                fee_bps,
+                &[seeds],
+            )?;
+            leg.exit()?;
+            sol_out = sol_out.checked_add(fill.sol_out).ok_or(FanStakeError::MathOverflow)?;
+        }
+        require!(sol_out >= min_sol_out, FanStakeError::SlippageExceeded);
+
+        emit!(IndexTradeEvent {
+            index: index_key,
+            trader: ctx.accounts.user.key(),
+            is_buy: false,
+            sol_amount: sol_out,
+            index_amount,
+            index_supply: supply - index_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("INDEX REDEEM: {} index tokens -> {} SOL", index_amount, sol_out);
+        Ok(())
+    }
+
+    /// Rotates from one artist to another atomically: sells `amount_in` on the
+    /// `from` curve with the proceeds paid straight into the `to` curve's
+    /// vault, then buys on `to` with them. Each leg pays the platform fee less
+    /// `swap_fee_discount_bps`; `min_out` bounds the tokens received.
+    pub fn swap_artist_tokens(ctx: Context<SwapArtistTokens>, amount_in: u64, min_out: u64) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let from_mint = ctx.accounts.from_curve.mint;
+        let to_mint = ctx.accounts.to_curve.mint;
+        require_keys_neq!(from_mint, to_mint, FanStakeError::SameCurve);
+
+        // Vesting check — if the seller is the `from` artist, enforce lockup period
+        if user == ctx.accounts.from_curve.artist {
+            require_vested(&ctx.accounts.from_artist_vesting)?;
+        }
+
+        let config = &ctx.accounts.platform_config;
+        let fee_bps = fanstake_math::swap_fee_bps(config.current_fee_bps()?, config.swap_fee_discount_bps as u64)
+            .ok_or(FanStakeError::MathOverflow)?;
+
+        let sold = execute_sell(
+            SellAccounts {
+                bonding_curve: &mut ctx.accounts.from_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.from_curve_stats,
+                price_history: &ctx.accounts.from_price_history,
+                mint: ctx.accounts.from_mint.to_account_info(),
+                source: ctx.accounts.from_token_account.to_account_info(),
+                owner: ctx.accounts.user.to_account_info(),
+                recipient: ctx.accounts.to_curve_vault.to_account_info(),
+                trader: user,
+                curve_vault: ctx.accounts.from_curve_vault.to_account_info(),
+                vault_bump: ctx.bumps.from_curve_vault,
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.from_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            amount_in,
+            0,
+            fee_bps,
+            &[],
+        )?;
+
+        // The `to` vault already holds the proceeds; it only signs the fee transfer
+        let vault_seeds: &[&[u8]] = &[b"curve_vault", to_mint.as_ref(), &[ctx.bumps.to_curve_vault]];
+        let bought = execute_buy(
+            BuyAccounts {
+                bonding_curve: &mut ctx.accounts.to_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.to_curve_stats,
+                price_history: &ctx.accounts.to_price_history,
+                mint: ctx.accounts.to_mint.to_account_info(),
+                destination: ctx.accounts.to_token_account.to_account_info(),
+                payer: ctx.accounts.to_curve_vault.to_account_info(),
+                trader: user,
+                curve_vault: ctx.accounts.to_curve_vault.to_account_info(),
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.to_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            sold.sol_out,
+            min_out,
+            fee_bps,
+            &[vault_seeds],
+        )?;
+
+        // Per-wallet stats
+        let now = bought.timestamp;
+        record_sale(&ctx.accounts.from_buyer_record, &mut ctx.accounts.from_curve, amount_in)?;
+        let to_record = &mut ctx.accounts.to_buyer_record;
+        to_record.init_if_new(to_mint, user, ctx.bumps.to_buyer_record);
+        to_record.record_buy(&mut ctx.accounts.to_curve_stats, sold.sol_out, now);
+        to_record.credit_tokens(&mut ctx.accounts.to_curve, bought.tokens_out);
+        update_position(&ctx.accounts.from_position, |p| p.record_sell(amount_in, sold.sol_out, now))?;
+        update_position(&ctx.accounts.to_position, |p| p.record_buy(sold.sol_out, bought.tokens_out, now))?;
+
+        msg!("SWAP: {} {} -> {} {}", amount_in, from_mint, bought.tokens_out, to_mint);
+        Ok(())
+    }
+
+    /// Places a limit order on one curve. Buy orders escrow `amount` lamports
+    /// and fire once the spot price is at or below `trigger_price`; sell
+    /// orders escrow `amount` tokens and fire at or above it. `keeper_tip`
+    /// lamports are escrowed too and paid to whoever executes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        order_id: u64,
+        side: OrderSide,
+        amount: u64,
+        trigger_price: u64,
+        min_out: u64,
+        keeper_tip: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.bonding_curve.is_active, FanStakeError::CurveNotActive);
+        require!(amount > 0 && trigger_price > 0, FanStakeError::InvalidAmount);
+
+        match side {
+            OrderSide::Buy => {
+                // Only sell fills close the escrow, so a buy order's would strand its rent
+                require!(ctx.accounts.escrow_token_account.is_none(), FanStakeError::UnexpectedOrderEscrow);
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.owner.to_account_info(),
+                            to: ctx.accounts.order.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+            OrderSide::Sell => {
+                // Same lockup as `sell`: the artist can't list vesting tokens
+                if ctx.accounts.owner.key() == ctx.accounts.bonding_curve.artist {
+                    require_vested(&ctx.accounts.artist_vesting)?;
+                }
+                let escrow = ctx.accounts.escrow_token_account.as_ref().ok_or(FanStakeError::MissingOrderEscrow)?;
+                move_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.bonding_curve,
+                    ctx.accounts.owner_token_account.to_account_info(),
+                    escrow.to_account_info(),
+                    ctx.accounts.owner.to_account_info(),
+                    &[],
+                    amount,
+                )?;
+            }
+        }
+
+        if keeper_tip > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: ctx.accounts.order.to_account_info(),
+                    },
+                ),
+                keeper_tip,
+            )?;
+        }
+
+        let order = &mut ctx.accounts.order;
+        order.owner = ctx.accounts.owner.key();
+        order.mint = ctx.accounts.mint.key();
+        order.order_id = order_id;
+        order.side = side;
+        order.amount = amount;
+        order.trigger_price = trigger_price;
+        order.min_out = min_out;
+        order.keeper_tip = keeper_tip;
+        order.created_at = Clock::get()?.unix_timestamp;
+        order.bump = ctx.bumps.order;
+        order.version = LimitOrder::VERSION;
+
+        msg!("Order placed: {:?} {} at price {}", side, amount, trigger_price);
+        Ok(())
+    }
+
+    /// Owner cancels an order, getting back the escrow, tip and rent.
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        let order = &ctx.accounts.order;
+        if order.side == OrderSide::Sell {
+            let escrow = ctx.accounts.escrow_token_account.as_ref().ok_or(FanStakeError::MissingOrderEscrow)?;
+            let id_bytes = order.order_id.to_le_bytes();
+            let seeds: &[&[u8]] =
+                &[b"limit_order", order.mint.as_ref(), order.owner.as_ref(), &id_bytes, &[order.bump]];
+            move_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.mint,
+                &ctx.accounts.bonding_curve,
+                escrow.to_account_info(),
+                ctx.accounts.owner_token_account.to_account_info(),
+                order.to_account_info(),
+                &[seeds],
+                escrow.amount,
+            )?;
+            token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::CloseAccount {
+                    account: escrow.to_account_info(),
+                    destination: ctx.accounts.owner.to_account_info(),
+                    authority: order.to_account_info(),
+                },
+                &[seeds],
+            ))?;
+        }
+        msg!("Order cancelled: {}", order.key());
+        Ok(())
+    }
+
+    /// Permissionless: fills an order whose trigger price has been crossed,
+    /// through the regular buy/sell path, and pays the keeper its tip. The
+    /// order's `min_out` still bounds the fill.
+    pub fn execute_order(ctx: Context<ExecuteOrder>) -> Result<()> {
+        let order = &ctx.accounts.order;
+        let (side, amount, min_out, keeper_tip) = (order.side, order.amount, order.min_out, order.keeper_tip);
+        let owner = order.owner;
+        let spot = ctx.accounts.bonding_curve.spot_price();
+        let triggered = match side {
+            OrderSide::Buy => spot <= order.trigger_price,
+            OrderSide::Sell => spot >= order.trigger_price,
+        };
+        require!(triggered, FanStakeError::OrderNotTriggered);
+
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let mint = ctx.accounts.bonding_curve.mint;
+        let id_bytes = order.order_id.to_le_bytes();
+        let order_seeds: &[&[u8]] = &[b"limit_order", mint.as_ref(), owner.as_ref(), &id_bytes, &[order.bump]];
+        let out = match side {
+            OrderSide::Buy => {
+                // Move the escrow into the curve vault; the vault then pays the fee
+                ctx.accounts.order.sub_lamports(amount)?;
+                ctx.accounts.curve_vault.add_lamports(amount)?;
+                let vault_seeds: &[&[u8]] = &[b"curve_vault", mint.as_ref(), &[ctx.bumps.curve_vault]];
+                let fill = execute_buy(
+                    BuyAccounts {
+                        bonding_curve: &mut ctx.accounts.bonding_curve,
+                        platform_config: &mut ctx.accounts.platform_config,
+                        curve_stats: &mut ctx.accounts.curve_stats,
+                        price_history: &ctx.accounts.price_history,
+                        mint: ctx.accounts.mint.to_account_info(),
+                        destination: ctx.accounts.owner_token_account.to_account_info(),
+                        payer: ctx.accounts.curve_vault.to_account_info(),
+                        trader: owner,
+                        curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                        fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                    amount,
+                    min_out,
+                    fee_bps,
+                    &[vault_seeds],
+                )?;
+                credit_tokens(&ctx.accounts.buyer_record, &mut ctx.accounts.bonding_curve, fill.tokens_out)?;
+                update_position(&ctx.accounts.position, |p| p.record_buy(amount, fill.tokens_out, fill.timestamp))?;
+                fill.tokens_out
+            }
+            OrderSide::Sell => {
+                let escrow = ctx.accounts.escrow_token_account.as_ref().ok_or(FanStakeError::MissingOrderEscrow)?;
+                let fill = execute_sell(
+                    SellAccounts {
+                        bonding_curve: &mut ctx.accounts.bonding_curve,
+                        platform_config: &mut ctx.accounts.platform_config,
+                        curve_stats: &mut ctx.accounts.curve_stats,
+                        price_history: &ctx.accounts.price_history,
+                        mint: ctx.accounts.mint.to_account_info(),
+                        source: escrow.to_account_info(),
+                        owner: ctx.accounts.order.to_account_info(),
+                        recipient: ctx.accounts.owner.to_account_info(),
+                        trader: owner,
+                        curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                        vault_bump: ctx.bumps.curve_vault,
+                        fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                    amount,
+                    min_out,
+                    fee_bps,
+                    &[order_seeds],
+                )?;
+                token_interface::close_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::CloseAccount {
+                        account: escrow.to_account_info(),
+                        destination: ctx.accounts.owner.to_account_info(),
+                        authority: ctx.accounts.order.to_account_info(),
+                    },
+                    &[order_seeds],
+                ))?;
+                record_sale(&ctx.accounts.buyer_record, &mut ctx.accounts.bonding_curve, amount)?;
+                update_position(&ctx.accounts.position, |p| p.record_sell(amount, fill.sol_out, fill.timestamp))?;
+                fill.sol_out
+            }
+        };
+
+        // Tip the keeper; the rest of the order account goes back to the owner on close
+        ctx.accounts.order.sub_lamports(keeper_tip)?;
+        ctx.accounts.keeper.add_lamports(keeper_tip)?;
+
+        msg!("Order filled: {:?} {} -> {} (tip {})", side, amount, out, keeper_tip);
+        Ok(())
+    }
+
+    /// Opens a recurring buy plan: `amount_per_interval` lamports every
+    /// `interval_secs`, `num_intervals` times, skipped while the price is
+    /// above `max_price`. `deposit` lamports fund it up front; each
+    /// execution also pays `keeper_tip` from the plan. The first buy is due
+    /// immediately.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_dca_plan(
+        ctx: Context<CreateDcaPlan>,
+        plan_id: u64,
+        amount_per_interval: u64,
+        interval_secs: i64,
+        num_intervals: u32,
+        max_price: u64,
+        keeper_tip: u64,
+        deposit: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.bonding_curve.is_active, FanStakeError::CurveNotActive);
+        require!(amount_per_interval > 0 && max_price > 0, FanStakeError::InvalidAmount);
+        require!(
+            interval_secs >= MIN_DCA_INTERVAL && num_intervals > 0,
+            FanStakeError::InvalidDcaSchedule
+        );
+
+        if deposit > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: ctx.accounts.plan.to_account_info(),
+                    },
+                ),
+                deposit,
+            )?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let plan = &mut ctx.accounts.plan;
+        plan.owner = ctx.accounts.owner.key();
+        plan.mint = ctx.accounts.mint.key();
+        plan.plan_id = plan_id;
+        plan.amount_per_interval = amount_per_interval;
+        plan.interval_secs = interval_secs;
+        plan.num_intervals = num_intervals;
+        plan.intervals_executed = 0;
+        plan.max_price = max_price;
+        plan.keeper_tip = keeper_tip;
+        plan.next_execution_at = now;
+        plan.total_sol_spent = 0;
+        plan.total_tokens_bought = 0;
+        plan.created_at = now;
+        plan.bump = ctx.bumps.plan;
+        plan.version = DcaPlan::VERSION;
+
+        msg!(
+            "DCA plan created: {} lamports x {} every {}s (deposit {})",
+            amount_per_interval,
+            num_intervals,
+            interval_secs,
+            deposit
+        );
+        Ok(())
+    }
+
+    /// Owner withdraws unspent SOL from a plan. The plan stays open; close it
+    /// to also reclaim the rent.
+    pub fn withdraw_dca(ctx: Context<WithdrawDca>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let available = lamports_above_rent(&ctx.accounts.plan.to_account_info())?;
+        require!(amount <= available, FanStakeError::InsufficientDcaBalance);
+        ctx.accounts.plan.sub_lamports(amount)?;
+        ctx.accounts.owner.add_lamports(amount)?;
+        msg!("DCA withdrawal: {} lamports", amount);
+        Ok(())
+    }
+
+    /// Owner closes a plan, reclaiming the unspent SOL and the rent.
+    pub fn close_dca_plan(ctx: Context<CloseDcaPlan>) -> Result<()> {
+        msg!("DCA plan closed: {}", ctx.accounts.plan.key());
+        Ok(())
+    }
+
+    /// Permissionless: runs the next scheduled buy of a plan into the owner's
+    /// token account and pays the keeper its tip. Fails while the price is
+    /// above the plan's cap, so keepers simply retry later. Intervals missed
+    /// entirely are skipped rather than bunched up.
+    pub fn execute_dca(ctx: Context<ExecuteDca>) -> Result<()> {
+        let plan = &ctx.accounts.plan;
+        let now = Clock::get()?.unix_timestamp;
+        require!(plan.intervals_executed < plan.num_intervals, FanStakeError::DcaPlanComplete);
+        require!(now >= plan.next_execution_at, FanStakeError::DcaNotDue);
+        let (amount, keeper_tip, max_price) = (plan.amount_per_interval, plan.keeper_tip, plan.max_price);
+        let needed = amount.checked_add(keeper_tip).ok_or(FanStakeError::MathOverflow)?;
+        require!(lamports_above_rent(&plan.to_account_info())? >= needed, FanStakeError::InsufficientDcaBalance);
+        require!(ctx.accounts.bonding_curve.spot_price() <= max_price, FanStakeError::DcaPriceAboveMax);
+
+        // Move this interval's SOL into the curve vault; the vault then pays the fee
+        ctx.accounts.plan.sub_lamports(amount)?;
+        ctx.accounts.curve_vault.add_lamports(amount)?;
+
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let mint = ctx.accounts.bonding_curve.mint;
+        let owner = ctx.accounts.owner.key();
+        let vault_seeds: &[&[u8]] = &[b"curve_vault", mint.as_ref(), &[ctx.bumps.curve_vault]];
+        let fill = execute_buy(
+            BuyAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                destination: ctx.accounts.owner_token_account.to_account_info(),
+                payer: ctx.accounts.curve_vault.to_account_info(),
+                trader: owner,
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            amount,
+            0,
+            fee_bps,
+            &[vault_seeds],
+        )?;
+        // The cap covers the whole fill, not just the starting price
+        require!(ctx.accounts.bonding_curve.spot_price() <= max_price, FanStakeError::DcaPriceAboveMax);
+        credit_tokens(&ctx.accounts.buyer_record, &mut ctx.accounts.bonding_curve, fill.tokens_out)?;
+        update_position(&ctx.accounts.position, |p| p.record_buy(amount, fill.tokens_out, now))?;
+
+        ctx.accounts.plan.sub_lamports(keeper_tip)?;
+        ctx.accounts.keeper.add_lamports(keeper_tip)?;
+
+        let plan = &mut ctx.accounts.plan;
+        plan.intervals_executed += 1;
+        plan.total_sol_spent = plan.total_sol_spent.saturating_add(amount);
+        plan.total_tokens_bought = plan.total_tokens_bought.saturating_add(fill.tokens_out);
+        let behind = (now - plan.next_execution_at) % plan.interval_secs;
+        plan.next_execution_at = now - behind + plan.interval_secs;
+
+        msg!(
+            "DCA executed: {} lamports -> {} tokens ({}/{})",
+            amount,
+            fill.tokens_out,
+            plan.intervals_executed,
+            plan.num_intervals
+        );
+        Ok(())
+    }
+
+    /// Admin opens a lending pool on one curve: lenders supply SOL, holders
+    /// borrow it against the curve's tokens under `params`. Only until the
+    /// admin group takes over.
+    pub fn init_lending_pool(ctx: Context<InitLendingPool>, params: LendingParams) -> Result<()> {
+        params.validate()?;
+        let pool = &mut ctx.accounts.pool;
+        pool.mint = ctx.accounts.mint.key();
+        pool.params = params;
+        pool.liquidity = 0;
+        pool.total_shares = 0;
+        pool.total_debt = 0;
+        pool.total_collateral = 0;
+        pool.borrow_index = fanstake_math::BORROW_INDEX_SCALE;
+        pool.last_accrual = Clock::get()?.unix_timestamp;
+        pool.bump = ctx.bumps.pool;
+        pool.version = LendingPool::VERSION;
+        msg!("Lending pool opened for {}", pool.mint);
+        Ok(())
+    }
+
+    /// Admin updates a pool's risk and rate parameters. Interest up to now
+    /// accrues at the old rates.
+    pub fn set_lending_params(ctx: Context<SetLendingParams>, params: LendingParams) -> Result<()> {
+        params.validate()?;
+        let pool = &mut ctx.accounts.pool;
+        pool.accrue(Clock::get()?.unix_timestamp)?;
+        pool.params = params;
+        msg!("Lending params updated for {}", pool.mint);
+        Ok(())
+    }
+
+    /// Lender supplies SOL and receives pool shares, which grow with the
+    /// interest borrowers pay.
+    pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let pool = &mut ctx.accounts.pool;
+        pool.accrue(Clock::get()?.unix_timestamp)?;
+        let shares = fanstake_math::shares_for_deposit(pool.total_shares, pool.total_assets()?, amount)
+            .ok_or(FanStakeError::MathOverflow)?;
+        require!(shares > 0, FanStakeError::InvalidAmount);
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.lender.to_account_info(),
+                    to: pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        pool.liquidity = pool.liquidity.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        pool.total_shares = pool.total_shares.checked_add(shares).ok_or(FanStakeError::MathOverflow)?;
+
+        let position = &mut ctx.accounts.lender_position;
+        position.owner = ctx.accounts.lender.key();
+        position.mint = pool.mint;
+        position.shares = position.shares.checked_add(shares).ok_or(FanStakeError::MathOverflow)?;
+        position.bump = ctx.bumps.lender_position;
+        position.version = LenderPosition::VERSION;
+
+        msg!("Liquidity deposited: {} lamports -> {} shares", amount, shares);
+        Ok(())
+    }
+
+    /// Lender redeems shares for SOL, limited to what isn't lent out.
+    pub fn withdraw_liquidity(ctx: Context<WithdrawLiquidity>, shares: u64) -> Result<()> {
+        require!(shares > 0, FanStakeError::InvalidAmount);
+        require!(shares <= ctx.accounts.lender_position.shares, FanStakeError::InsufficientShares);
+        let pool = &mut ctx.accounts.pool;
+        pool.accrue(Clock::get()?.unix_timestamp)?;
+        let amount = fanstake_math::pro_rata(shares, pool.total_assets()?, pool.total_shares)
+            .ok_or(FanStakeError::MathOverflow)?;
+        require!(amount <= pool.liquidity, FanStakeError::InsufficientLiquidity);
+
+        pool.liquidity -= amount;
+        pool.total_shares -= shares;
+        ctx.accounts.lender_position.shares -= shares;
+        pool.sub_lamports(amount)?;
+        ctx.accounts.lender.add_lamports(amount)?;
+
+        msg!("Liquidity withdrawn: {} shares -> {} lamports", shares, amount);
+        Ok(())
+    }
+
+    /// Borrower locks artist tokens in the pool.
+    pub fn deposit_collateral(
+        ctx: Context<DepositCollateral>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        // Same lockup as `sell`: the artist can't pledge vesting tokens
+        if ctx.accounts.borrower.key() == ctx.accounts.bonding_curve.artist {
+            require_vested(&ctx.accounts.artist_vesting)?;
+        }
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.borrower_token_account.to_account_info(),
+            ctx.accounts.collateral_vault.to_account_info(),
+            ctx.accounts.borrower.to_account_info(),
+            &[],
+            amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        let loan = &mut ctx.accounts.loan;
+        if loan.borrower == Pubkey::default() {
+            loan.borrower = ctx.accounts.borrower.key();
+            loan.mint = pool.mint;
+            loan.index_snapshot = pool.borrow_index;
+            loan.bump = ctx.bumps.loan;
+            loan.version = Loan::VERSION;
+        }
+        loan.collateral = loan.collateral.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        pool.total_collateral = pool.total_collateral.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+
+        msg!("Collateral deposited: {} tokens", amount);
+        Ok(())
+    }
+
+    /// Borrower takes back collateral, as long as the remaining collateral
+    /// still covers the debt at the pool's LTV.
+    pub fn withdraw_collateral(
+        ctx: Context<WithdrawCollateral>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.pool;
+        let loan = &mut ctx.accounts.loan;
+        require!(amount <= loan.collateral, FanStakeError::InsufficientCollateral);
+        pool.accrue(now)?;
+        let debt = loan.sync(pool)?;
+
+        let remaining = loan.collateral - amount;
+        if debt > 0 {
+            let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+            let stats = &ctx.accounts.curve_stats;
+            let value = collateral_value(&ctx.accounts.bonding_curve, stats, remaining, fee_bps, now)?;
+            require!(debt <= pool.max_borrow(value.borrow)?, FanStakeError::LoanUnhealthy);
+        }
+        loan.collateral = remaining;
+        pool.total_collateral = pool.total_collateral.saturating_sub(amount);
+
+        let mint = pool.mint;
+        let seeds: &[&[u8]] = &[b"lending_pool", mint.as_ref(), &[pool.bump]];
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.collateral_vault.to_account_info(),
+            ctx.accounts.borrower_token_account.to_account_info(),
+            pool.to_account_info(),
+            &[seeds],
+            amount,
+        )?;
+
+        msg!("Collateral withdrawn: {} tokens", amount);
+        Ok(())
+    }
+
+    /// Borrower draws SOL against their collateral, up to the pool's LTV of
+    /// its value (see `collateral_value`). Like trading, it stops while the
+    /// curve is inactive or the platform is paused.
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        require!(ctx.accounts.bonding_curve.is_active, FanStakeError::CurveNotActive);
+        require!(!ctx.accounts.platform_config.paused, FanStakeError::PlatformPaused);
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.pool;
+        let loan = &mut ctx.accounts.loan;
+        pool.accrue(now)?;
+        let debt = loan.sync(pool)?;
+        require!(amount <= pool.liquidity, FanStakeError::InsufficientLiquidity);
+
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let stats = &ctx.accounts.curve_stats;
+        let value = collateral_value(&ctx.accounts.bonding_curve, stats, loan.collateral, fee_bps, now)?;
+        let new_debt = debt.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        require!(new_debt <= pool.max_borrow(value.borrow)?, FanStakeError::LoanUnhealthy);
+
+        loan.debt = new_debt;
+        pool.total_debt = pool.total_debt.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        pool.liquidity -= amount;
+        pool.sub_lamports(amount)?;
+        ctx.accounts.borrower.add_lamports(amount)?;
+
+        msg!("Borrowed {} lamports (debt {})", amount, new_debt);
+        Ok(())
+    }
+
+    /// Borrower pays back up to `amount` lamports of their debt.
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let pool = &mut ctx.accounts.pool;
+        let loan = &mut ctx.accounts.loan;
+        pool.accrue(Clock::get()?.unix_timestamp)?;
+        let debt = loan.sync(pool)?;
+        let paid = amount.min(debt);
+        require!(paid > 0, FanStakeError::InvalidAmount);
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.borrower.to_account_info(),
+                    to: pool.to_account_info(),
+                },
+            ),
+            paid,
+        )?;
+        loan.debt = debt - paid;
+        pool.total_debt = pool.total_debt.saturating_sub(paid);
+        pool.liquidity = pool.liquidity.checked_add(paid).ok_or(FanStakeError::MathOverflow)?;
+
+        msg!("Repaid {} lamports (debt {})", paid, loan.debt);
+        Ok(())
+    }
+
+    /// Permissionless: liquidates a loan whose health has fallen below 1 by
+    /// selling all of its collateral on the curve. The proceeds repay the
+    /// debt, the liquidator earns the bonus out of any surplus, and the rest
+    /// goes back to the borrower. Any shortfall is written off against the
+    /// lenders.
+    pub fn liquidate(ctx: Context<Liquidate>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let (collateral, debt) = {
+            let pool = &mut ctx.accounts.pool;
+            let loan = &mut ctx.accounts.loan;
+            pool.accrue(now)?;
+            let debt = loan.sync(pool)?;
+            let stats = &ctx.accounts.curve_stats;
+            let value = collateral_value(&ctx.accounts.bonding_curve, stats, loan.collateral, fee_bps, now)?;
+            require!(debt > pool.liquidation_limit(value.liquidation)?, FanStakeError::LoanHealthy);
+            (loan.collateral, debt)
+        };
+
+        let mint = ctx.accounts.pool.mint;
+        let borrower = ctx.accounts.borrower.key();
+        let pool_seeds: &[&[u8]] = &[b"lending_pool", mint.as_ref(), &[ctx.accounts.pool.bump]];
+        let fill = execute_sell(
+            SellAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                source: ctx.accounts.collateral_vault.to_account_info(),
+                owner: ctx.accounts.pool.to_account_info(),
+                recipient: ctx.accounts.pool.to_account_info(),
+                trader: borrower,
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                vault_bump: ctx.bumps.curve_vault,
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            collateral,
+            0,
+            fee_bps,
+            &[pool_seeds],
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        let repaid = fill.sol_out.min(debt);
+        let surplus = fill.sol_out - repaid;
+        let bonus = fanstake_math::pro_rata(repaid, pool.params.liquidation_bonus_bps as u64, BPS_DENOMINATOR)
+            .ok_or(FanStakeError::MathOverflow)?
+            .min(surplus);
+        let bad_debt = debt - repaid;
+
+        pool.total_debt = pool.total_debt.saturating_sub(debt);
+        pool.total_collateral = pool.total_collateral.saturating_sub(collateral);
+        pool.liquidity = pool.liquidity.checked_add(repaid).ok_or(FanStakeError::MathOverflow)?;
+        pool.sub_lamports(surplus)?;
+        ctx.accounts.liquidator.add_lamports(bonus)?;
+        ctx.accounts.borrower.add_lamports(surplus - bonus)?;
+
+        emit!(LiquidationEvent {
+            mint,
+            borrower,
+            liquidator: ctx.accounts.liquidator.key(),
+            collateral,
+            debt,
+            proceeds: fill.sol_out,
+            bonus,
+            bad_debt,
+            timestamp: fill.timestamp,
+        });
+
+        msg!(
+            "Liquidated {}: {} tokens -> {} lamports (debt {}, bonus {})",
+            borrower,
+            collateral,
+            fill.sol_out,
+            debt,
+            bonus
+        );
+        Ok(())
+    }
+
+    /// Artist opts a fresh curve into a treasury: `treasury_bps` of every buy
+    /// (after the fee) is set aside for the declared milestones instead of
+    /// the reserves. Only before the first trade, so every buyer knows the
+    /// terms. Deadlines must be in the future and increasing.
+    pub fn create_artist_treasury(
+        ctx: Context<CreateArtistTreasury>,
+        treasury_bps: u16,
+        milestones: Vec<MilestoneInput>,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        require!(curve.tokens_sold() == 0, FanStakeError::CurveAlreadyTrading);
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            treasury_bps > 0
+                && treasury_bps <= MAX_TREASURY_BPS
+                && !milestones.is_empty()
+                && milestones.len() <= MAX_MILESTONES
+                && milestones.iter().all(|m| m.amount > 0 && m.description.len() <= MAX_MILESTONE_DESCRIPTION_LEN)
+                && milestones.first().is_some_and(|m| m.deadline > now)
+                && milestones.windows(2).all(|w| w[0].deadline < w[1].deadline),
+            FanStakeError::InvalidTreasuryConfig
+        );
+        curve.treasury_bps = treasury_bps;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.mint = curve.mint;
+        treasury.artist = curve.artist;
+        treasury.milestones = milestones
+            .into_iter()
+            .map(|m| Milestone {
+                description: m.description,
+                amount: m.amount,
+                deadline: m.deadline,
+                round: 0,
+                vote_ends_at: 0,
+                votes_for: 0,
+                votes_against: 0,
+                released_at: 0,
+            })
+            .collect();
+        treasury.next_milestone = 0;
+        treasury.total_released = 0;
+        treasury.failed_at = 0;
+        treasury.refund_pool = 0;
+        treasury.refund_supply = 0;
+        treasury.total_refunded = 0;
+        treasury.created_at = now;
+        treasury.bump = ctx.bumps.treasury;
+        treasury.version = ArtistTreasury::VERSION;
+
+        msg!("Artist treasury: {} bps of buys across {} milestones", treasury_bps, treasury.milestones.len());
+        Ok(())
+    }
+
+    /// Artist asks holders to release the next milestone's funds. Opens a
+    /// `TREASURY_VOTE_DURATION` vote that must close before the deadline;
+    /// a rejected milestone can be put to a vote again.
+    pub fn request_release(ctx: Context<RequestRelease>, milestone: u8) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let balance = ctx.accounts.bonding_curve.treasury_balance;
+        let treasury = &mut ctx.accounts.treasury;
+        require!(treasury.failed_at == 0, FanStakeError::TreasuryFailed);
+        require!(milestone == treasury.next_milestone, FanStakeError::MilestoneNotNext);
+        let m = &mut treasury.milestones[milestone as usize];
+        require!(m.vote_ends_at == 0, FanStakeError::VoteAlreadyOpen);
+        let vote_ends_at = now + TREASURY_VOTE_DURATION;
+        require!(vote_ends_at <= m.deadline, FanStakeError::MilestoneNotNext);
+        require!(balance >= m.amount, FanStakeError::InsufficientTreasuryBalance);
+
+        m.round += 1;
+        m.vote_ends_at = vote_ends_at;
+        m.votes_for = 0;
+        m.votes_against = 0;
+
+        msg!("Release vote {} opened on milestone {} until {}", m.round, milestone, vote_ends_at);
+        Ok(())
+    }
+
+    /// Holder votes on an open release by locking `amount` tokens until the
+    /// vote ends (see `withdraw_vote`). The weight is capped by the voter's
+    /// `BuyerRecord::curve_tokens`, so all votes together never exceed the
+    /// tokens bought from the funded curve and not sold back. On SPL Token
+    /// mints that eligibility stays with the buying wallet when tokens move
+    /// on; Token-2022 mints drop it in the transfer hook. Locking tokens here
+    /// doesn't run the hook (see `move_tokens`), so they keep it.
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        milestone: u8,
+        approve: bool,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let treasury = &mut ctx.accounts.treasury;
+        require!(ctx.accounts.voter.key() != treasury.artist, FanStakeError::ArtistCannotVote);
+        require!(amount <= ctx.accounts.buyer_record.curve_tokens, FanStakeError::TokensNotEligible);
+        let mint = treasury.mint;
+        let now = Clock::get()?.unix_timestamp;
+        let m = treasury.milestones.get_mut(milestone as usize).ok_or(FanStakeError::MilestoneNotNext)?;
+        require!(m.vote_ends_at != 0 && now < m.vote_ends_at, FanStakeError::VoteNotOpen);
+
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.voter_token_account.to_account_info(),
+            ctx.accounts.vote_escrow.to_account_info(),
+            ctx.accounts.voter.to_account_info(),
+            &[],
+            amount,
+        )?;
+        if approve {
+            m.votes_for = m.votes_for.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        } else {
+            m.votes_against = m.votes_against.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        }
+
+        let vote = &mut ctx.accounts.vote;
+        vote.voter = ctx.accounts.voter.key();
+        vote.mint = mint;
+        vote.milestone = milestone;
+        vote.round = m.round;
+        vote.approve = approve;
+        vote.amount = amount;
+        vote.bump = ctx.bumps.vote;
+        vote.version = TreasuryVote::VERSION;
+
+        msg!("Vote on milestone {}: {} with {} tokens", milestone, if approve { "for" } else { "against" }, amount);
+        Ok(())
+    }
+
+    /// Voter takes back their locked tokens once the vote they cast in has
+    /// ended.
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        let treasury = &ctx.accounts.treasury;
+        let vote = &ctx.accounts.vote;
+        let m = &treasury.milestones[vote.milestone as usize];
+        let now = Clock::get()?.unix_timestamp;
+        let still_open = treasury.failed_at == 0 && m.round == vote.round && m.vote_ends_at != 0 && now < m.vote_ends_at;
+        require!(!still_open, FanStakeError::VoteStillOpen);
+
+        let seeds: &[&[u8]] = &[b"artist_treasury", treasury.mint.as_ref(), &[treasury.bump]];
+        move_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.bonding_curve,
+            ctx.accounts.vote_escrow.to_account_info(),
+            ctx.accounts.voter_token_account.to_account_info(),
+            treasury.to_account_info(),
+            &[seeds],
+            vote.amount,
+        )?;
+
+        msg!("Vote withdrawn: {} tokens", vote.amount);
+        Ok(())
+    }
+
+    /// Permissionless: settles a release vote once it has ended. If more
+    /// tokens voted for than against, the milestone's funds go to the artist
+    /// — the last milestone takes whatever the treasury holds, and buys stop
+    /// feeding it. Otherwise the artist may ask again before the deadline.
+    pub fn finalize_vote(ctx: Context<FinalizeVote>, milestone: u8) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        require!(treasury.failed_at == 0, FanStakeError::TreasuryFailed);
+        require!(milestone == treasury.next_milestone, FanStakeError::MilestoneNotNext);
+        let is_last = milestone as usize + 1 == treasury.milestones.len();
+        let m = &mut treasury.milestones[milestone as usize];
+        require!(m.vote_ends_at != 0, FanStakeError::VoteNotOpen);
+        require!(now >= m.vote_ends_at, FanStakeError::VoteStillOpen);
+        m.vote_ends_at = 0;
+
+        if m.votes_for <= m.votes_against {
+            msg!("Milestone {} rejected: {} for, {} against", milestone, m.votes_for, m.votes_against);
+            return Ok(());
+        }
+
+        let curve = &mut ctx.accounts.bonding_curve;
+        let amount = if is_last { curve.treasury_balance } else { m.amount.min(curve.treasury_balance) };
+        m.released_at = now;
+        let (votes_for, votes_against) = (m.votes_for, m.votes_against);
+        treasury.next_milestone += 1;
+        treasury.total_released = treasury.total_released.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        curve.treasury_balance -= amount;
+        if is_last {
+            curve.treasury_bps = 0;
+        }
+
+        let mint = curve.mint;
+        let vault_seeds: &[&[u8]] = &[b"curve_vault", mint.as_ref(), &[ctx.bumps.curve_vault]];
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.curve_vault.to_account_info(),
+                    to: ctx.accounts.artist.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+        )?;
+
+        emit!(MilestoneReleasedEvent {
+            mint,
+            milestone,
+            amount,
+            votes_for,
+            votes_against,
+            timestamp: now,
+        });
+
+        msg!("Milestone {} released: {} lamports", milestone, amount);
+        Ok(())
+    }
+
+    /// Permissionless: once the next milestone's deadline passes unreleased,
+    /// the treasury fails. Buys stop feeding it and what it holds is split
+    /// over the curve's `eligible_tokens` as they stand (see `claim_refund`).
+    pub fn fail_treasury(ctx: Context<FailTreasury>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let curve = &mut ctx.accounts.bonding_curve;
+        let treasury = &mut ctx.accounts.treasury;
+        require!(treasury.failed_at == 0, FanStakeError::TreasuryFailed);
+        let missed = treasury
+            .milestones
+            .get(treasury.next_milestone as usize)
+            .is_some_and(|m| now > m.deadline);
+        require!(missed, FanStakeError::TreasuryActive);
+
+        treasury.failed_at = now;
+        treasury.refund_pool = curve.treasury_balance;
+        treasury.refund_supply = curve.eligible_tokens;
+        curve.treasury_bps = 0;
+
+        msg!(
+            "Treasury failed at milestone {}: {} lamports refundable over {} tokens",
+            treasury.next_milestone,
+            treasury.refund_pool,
+            treasury.refund_supply
+        );
+        Ok(())
+    }
+
+    /// Holder of a failed treasury's token sells `token_amount` back to the
+    /// curve as in `sell`, and also gets a pro-rata share of the treasury
+    /// refund for up to their `BuyerRecord::curve_tokens`. Those add up to at
+    /// most the `refund_supply` snapshot, so the pool covers every claim in full.
+    /// The artist can't claim.
+    pub fn claim_refund(ctx: Context<ClaimRefund>, token_amount: u64, min_sol_out: u64) -> Result<()> {
+        require!(ctx.accounts.holder.key() != ctx.accounts.treasury.artist, FanStakeError::ArtistCannotVote);
+        require!(ctx.accounts.treasury.failed_at != 0, FanStakeError::TreasuryActive);
+        let record = &mut ctx.accounts.buyer_record;
+        let eligible = token_amount.min(record.curve_tokens);
+        record.record_sale(&mut ctx.accounts.bonding_curve, token_amount);
+        let treasury = &ctx.accounts.treasury;
+        let refund = fanstake_math::pro_rata(eligible, treasury.refund_pool, treasury.refund_supply)
+            .ok_or(FanStakeError::MathOverflow)?
+            .min(treasury.refund_pool - treasury.total_refunded);
+
+        let fee_bps = ctx.accounts.platform_config.current_fee_bps()?;
+        let holder = ctx.accounts.holder.key();
+        let fill = execute_sell(
+            SellAccounts {
+                bonding_curve: &mut ctx.accounts.bonding_curve,
+                platform_config: &mut ctx.accounts.platform_config,
+                curve_stats: &mut ctx.accounts.curve_stats,
+                price_history: &ctx.accounts.price_history,
+                mint: ctx.accounts.mint.to_account_info(),
+                source: ctx.accounts.holder_token_account.to_account_info(),
+                owner: ctx.accounts.holder.to_account_info(),
+                recipient: ctx.accounts.holder.to_account_info(),
+                trader: holder,
+                curve_vault: ctx.accounts.curve_vault.to_account_info(),
+                vault_bump: ctx.bumps.curve_vault,
+                fee_vault: ctx.accounts.fee_vault.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            token_amount,
+            min_sol_out,
+            fee_bps,
+            &[],
+        )?;
+
+        if refund > 0 {
+            let mint = ctx.accounts.bonding_curve.mint;
+            let vault_seeds: &[&[u8]] = &[b"curve_vault", mint.as_ref(), &[ctx.bumps.curve_vault]];
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.curve_vault.to_account_info(),
+                        to: ctx.accounts.holder.to_account_info(),
+                    },
+                    &[vault_seeds],
+                ),
+                refund,
+            )?;
+            ctx.accounts.bonding_curve.treasury_balance -= refund;
+            ctx.accounts.treasury.total_refunded += refund;
+        }
+
+        msg!("Refund: {} tokens -> {} lamports + {} from the treasury", token_amount, fill.sol_out, refund);
+        Ok(())
+    }
+
+    /// Artist sets up buyback-and-burn on their curve: a PDA that holds SOL
+    /// they fund (`fund_buyback`) and the limits every buyback obeys. A
+    /// schedule in `config` lets any keeper run buybacks (`execute_buyback`).
+    pub fn init_buyback(ctx: Context<InitBuyback>, config: BuybackConfig) -> Result<()> {
+        config.validate()?;
+        let now = Clock::get()?.unix_timestamp;
+        let buyback = &mut ctx.accounts.buyback;
+        buyback.mint = ctx.accounts.mint.key();
+        buyback.artist = ctx.accounts.artist.key();
+        buyback.config = config;
+        buyback.period_start = now;
+        buyback.spent_in_period = 0;
+        buyback.next_run_at = now;
+        buyback.total_sol_spent = 0;
+        buyback.total_tokens_burned = 0;
+        buyback.bump = ctx.bumps.buyback;
+        buyback.version = Buyback::VERSION;
+        msg!("Buyback set up for {}", buyback.mint);
+        Ok(())
+    }
+
+    /// Artist changes the buyback limits or schedule. The next scheduled
+    /// run is due immediately.
+    pub fn set_buyback_config(ctx: Context<SetBuybackConfig>, config: BuybackConfig) -> Result<()> {
+        config.validate()?;
+        let buyback = &mut ctx.accounts.buyback;
+        buyback.config = config;
+        buyback.next_run_at = Clock::get()?.unix_timestamp;
+        msg!("Buyback config updated for {}", buyback.mint);
+        Ok(())
+    }
+
+    /// Anyone adds SOL to a curve's buyback treasury.
+    pub fn fund_buyback(ctx: Context<FundBuyback>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.buyback.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        msg!("Buyback funded: {} lamports", amount);
+        Ok(())
+    }
+
+    /// Artist withdraws unspent SOL from the buyback treasury.
+    pub fn withdraw_buyback(ctx: Context<WithdrawBuyback>, amount: u64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let available = lamports_above_rent(&ctx.accounts.buyback.to_account_info())?;
+        require!(amount <= available, FanStakeError::InsufficientBuybackBalance);
+        ctx.accounts.buyback.sub_lamports(amount)?;
+        ctx.accounts.artist.add_lamports(amount)?;
+        msg!("Buyback withdrawal: {} lamports", amount);
+        Ok(())
+    }
+
+    /// Artist buys `sol_amount` of their token at market and burns it, paying
+    /// from their wallet or, with `from_treasury`, the buyback treasury.
+    /// Subject to the price cap and per-period limit.
+    pub fn buyback(ctx: Context<RunBuyback>, sol_amount: u64, min_tokens_out: u64, from_treasury: bool) -> Result<()> {
+        require!(ctx.accounts.caller.key() == ctx.accounts.buyback.artist, FanStakeError::Unauthorized);
+        let vault_bump = ctx.bumps.curve_vault;
+        fill_buyback(ctx.accounts, vault_bump, sol_amount, min_tokens_out, from_treasury, false)?;
+        Ok(())
+    }
+
+    /// Permissionless: runs the next scheduled buyback from the treasury and
+    /// pays the caller the configured tip.
+    pub fn execute_buyback(ctx: Context<RunBuyback>) -> Result<()> {
+        let config = ctx.accounts.buyback.config;
+        let now = Clock::get()?.unix_timestamp;
+        require!(config.amount_per_run > 0, FanStakeError::BuybackNotDue);
+        require!(now >= ctx.accounts.buyback.next_run_at, FanStakeError::BuybackNotDue);
+        let needed = config.amount_per_run.checked_add(config.keeper_tip).ok_or(FanStakeError::MathOverflow)?;
+        require!(
+            lamports_above_rent(&ctx.accounts.buyback.to_account_info())? >= needed,
+            FanStakeError::InsufficientBuybackBalance
+        );
+
+        let vault_bump = ctx.bumps.curve_vault;
+        fill_buyback(ctx.accounts, vault_bump, config.amount_per_run, 0, true, true)?;
+
+        ctx.accounts.buyback.sub_lamports(config.keeper_tip)?;
+        ctx.accounts.caller.add_lamports(config.keeper_tip)?;
+        let buyback = &mut ctx.accounts.buyback;
+        let behind = (now - buyback.next_run_at) % config.run_interval_secs;
+        buyback.next_run_at = now - behind + config.run_interval_secs;
+        Ok(())
+    }
+
+    /// Platform authority hands config changes to an M-of-N group. From then
+    /// on fees, pauses, fee withdrawals, boost tiers, the swap discount and
+    /// lending parameters change only through executed group proposals, and
+    /// no new lending pools open.
+    pub fn init_admin_group(ctx: Context<InitAdminGroup>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        validate_admin_members(&members, threshold)?;
+        let group = &mut ctx.accounts.admin_group;
+        group.members = members;
+        group.threshold = threshold;
+        group.generation = 0;
+        group.proposal_count = 0;
+        group.bump = ctx.bumps.admin_group;
+        group.version = AdminGroup::VERSION;
+        ctx.accounts.platform_config.admin_group = group.key();
+        msg!("Admin group: {} of {}", threshold, group.members.len());
+        Ok(())
+    }
+
+    /// A group member proposes a config change, approving it at the same
+    /// time. It can be executed once `threshold` members approve, within
+    /// `ADMIN_PROPOSAL_TTL`.
+    pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+        let group = &mut ctx.accounts.admin_group;
+        let member = group.member_index(&ctx.accounts.proposer.key())?;
+        if let AdminAction::SetMembers { members, threshold } = &action {
+            validate_admin_members(members, *threshold)?;
+        }
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposal_id = group.proposal_count;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.action = action;
+        proposal.generation = group.generation;
+        proposal.approvals = 1 << member;
+        proposal.created_at = now;
+        proposal.expires_at = now + ADMIN_PROPOSAL_TTL;
+        proposal.executed_at = 0;
+        proposal.bump = ctx.bumps.proposal;
+        proposal.version = AdminProposal::VERSION;
+        group.proposal_count += 1;
+        msg!("Admin proposal {}: {:?}", proposal.proposal_id, proposal.action);
+        Ok(())
+    }
+
+    /// A group member approves an open proposal.
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        let group = &ctx.accounts.admin_group;
+        let member = group.member_index(&ctx.accounts.member.key())?;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_open(group, Clock::get()?.unix_timestamp)?;
+        require!(proposal.approvals & (1 << member) == 0, FanStakeError::AlreadyApproved);
+        proposal.approvals |= 1 << member;
+        msg!("Admin proposal {}: {} of {} approvals", proposal.proposal_id, proposal.approvals.count_ones(), group.threshold);
+        Ok(())
+    }
+
+    /// Permissionless: applies a proposal that has enough approvals.
+    /// `WithdrawFees` passes the recipient and `SetLendingParams` the pool
+    /// as the only remaining account.
+    pub fn execute_admin_action<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteAdminAction<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let group = &mut ctx.accounts.admin_group;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.require_open(group, now)?;
+        require!(proposal.approvals.count_ones() >= group.threshold as u32, FanStakeError::ProposalNotApproved);
+        proposal.executed_at = now;
+
+        let config = &mut ctx.accounts.platform_config;
+        match proposal.action.clone() {
+            AdminAction::SetFee { fee_bps, effective_at } => config.queue_fee_change(fee_bps, effective_at, now)?,
+            AdminAction::SetFeeChangeDelay { delay_secs } => config.set_fee_change_delay(delay_secs, now)?,
+            AdminAction::SetPaused { paused } => config.paused = paused,
+            AdminAction::WithdrawFees { amount, recipient } => {
+                let target = ctx.remaining_accounts.first().ok_or(ErrorCode::AccountNotEnoughKeys)?;
+                require_keys_eq!(target.key(), recipient, FanStakeError::Unauthorized);
+                let available = lamports_above_rent(&ctx.accounts.fee_vault)?;
+                require!(amount > 0 && amount <= available, FanStakeError::InvalidAmount);
+                let seeds: &[&[u8]] = &[b"fee_vault", &[ctx.bumps.fee_vault]];
+                anchor_lang::system_program::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.fee_vault.to_account_info(),
+                            to: target.clone(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )?;
+            }
+            AdminAction::SetSwapFeeDiscount { discount_bps } => config.set_swap_fee_discount(discount_bps)?,
+            AdminAction::SetBoostTier { tier, price_lamports, duration_secs } => {
+                config.set_boost_tier(tier, price_lamports, duration_secs)?
+            }
+            AdminAction::SetLendingParams { mint, params } => {
+                params.validate()?;
+                let info = ctx.remaining_accounts.first().ok_or(ErrorCode::AccountNotEnoughKeys)?;
+                let (expected, _) = Pubkey::find_program_address(&[b"lending_pool", mint.as_ref()], &crate::ID);
+                require_keys_eq!(info.key(), expected, FanStakeError::InvalidLendingParams);
+                let mut pool: Account<LendingPool> = Account::try_from(info)?;
+                pool.accrue(now)?;
+                pool.params = params;
+                pool.exit(&crate::ID)?;
+            }
+            AdminAction::SetMembers { members, threshold } => {
+                validate_admin_members(&members, threshold)?;
+                group.members = members;
+                group.threshold = threshold;
+                // Open proposals were approved by the old members
+                group.generation += 1;
+            }
+        }
+
+        emit!(AdminActionExecutedEvent {
+            proposal_id: proposal.proposal_id,
+            action: proposal.action.clone(),
+            approvals: proposal.approvals.count_ones() as u8,
+            timestamp: now,
+        });
+        msg!("Admin proposal {} executed", proposal.proposal_id);
+        Ok(())
+    }
+
+    /// Permissionless: stores a queued fee change once it is due. Trades do
+    /// this too; either way `FeeChangeActivatedEvent` fires once.
+    pub fn activate_fee_change(ctx: Context<ActivateFeeChange>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(ctx.accounts.platform_config.activate_fee_change(now), FanStakeError::FeeChangeNotDue);
+        msg!("Platform fee now {} bps", ctx.accounts.platform_config.fee_bps);
+        Ok(())
+    }
+
+    /// Permissionless: grows the platform config to the current layout and
+    /// stamps its version. `payer` covers any extra rent.
+    pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        upgrade_account::<PlatformConfig>(&accounts.platform_config, &accounts.payer, &accounts.system_program)?;
+        msg!("Platform config migrated to v{}", PlatformConfig::VERSION);
+        Ok(())
+    }
+
+    /// Permissionless: grows a bonding curve to the current layout, fills in
+    /// fields older curves lack and stamps its version.
+    pub fn migrate_bonding_curve(ctx: Context<MigrateBondingCurve>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        upgrade_account::<BondingCurve>(&accounts.bonding_curve, &accounts.payer, &accounts.system_program)?;
+        msg!("Bonding curve {} migrated to v{}", accounts.mint.key(), BondingCurve::VERSION);
+        Ok(())
+    }
+
+    /// Permissionless: brings any program account to its current layout,
+    /// picking the type from its discriminator. `payer` covers any extra rent.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let (account, payer, system_program) = (&accounts.account, &accounts.payer, &accounts.system_program);
+        let discriminator = account.try_borrow_data()?.get(..8).map(<[u8]>::to_vec).unwrap_or_default();
+        let version = match discriminator.as_slice() {
+            d if d == PlatformConfig::DISCRIMINATOR => upgrade_account::<PlatformConfig>(account, payer, system_program)?,
+            d if d == BondingCurve::DISCRIMINATOR => upgrade_account::<BondingCurve>(account, payer, system_program)?,
+            d if d == Boost::DISCRIMINATOR => upgrade_account::<Boost>(account, payer, system_program)?,
+            d if d == VestingSchedule::DISCRIMINATOR => upgrade_account::<VestingSchedule>(account, payer, system_program)?,
+            d if d == CurveStats::DISCRIMINATOR => upgrade_account::<CurveStats>(account, payer, system_program)?,
+            d if d == BuyerRecord::DISCRIMINATOR => upgrade_account::<BuyerRecord>(account, payer, system_program)?,
+            d if d == Airdrop::DISCRIMINATOR => upgrade_account::<Airdrop>(account, payer, system_program)?,
+            d if d == Position::DISCRIMINATOR => upgrade_account::<Position>(account, payer, system_program)?,
+            d if d == Badge::DISCRIMINATOR => upgrade_account::<Badge>(account, payer, system_program)?,
+            d if d == Index::DISCRIMINATOR => upgrade_account::<Index>(account, payer, system_program)?,
+            d if d == LimitOrder::DISCRIMINATOR => upgrade_account::<LimitOrder>(account, payer, system_program)?,
+            d if d == DcaPlan::DISCRIMINATOR => upgrade_account::<DcaPlan>(account, payer, system_program)?,
+            d if d == LendingPool::DISCRIMINATOR => upgrade_account::<LendingPool>(account, payer, system_program)?,
+            d if d == LenderPosition::DISCRIMINATOR => upgrade_account::<LenderPosition>(account, payer, system_program)?,
+            d if d == Loan::DISCRIMINATOR => upgrade_account::<Loan>(account, payer, system_program)?,
+            d if d == ArtistTreasury::DISCRIMINATOR => upgrade_account::<ArtistTreasury>(account, payer, system_program)?,
+            d if d == TreasuryVote::DISCRIMINATOR => upgrade_account::<TreasuryVote>(account, payer, system_program)?,
+            d if d == Buyback::DISCRIMINATOR => upgrade_account::<Buyback>(account, payer, system_program)?,
+            d if d == AdminGroup::DISCRIMINATOR => upgrade_account::<AdminGroup>(account, payer, system_program)?,
+            d if d == AdminProposal::DISCRIMINATOR => upgrade_account::<AdminProposal>(account, payer, system_program)?,
+            d if d == PriceHistory::DISCRIMINATOR => {
+                // Zero-copy and fixed-size: the version byte came out of the padding
+                let mut data = account.try_borrow_mut_data()?;
+                let version = &mut data[8 + std::mem::offset_of!(PriceHistory, version)];
+                require!(*version < PriceHistory::VERSION, FanStakeError::AlreadyMigrated);
+                *version = PriceHistory::VERSION;
+                PriceHistory::VERSION
+            }
+            _ => return err!(ErrorCode::AccountDiscriminatorNotFound),
+        };
+        msg!("Account {} migrated to v{}", account.key(), version);
+        Ok(())
+    }
+}
+
+/// Decodes a program-owned `T` that may be on an older layout, applies
+/// `Versioned::upgrade`, then reallocs it to fit (new bytes are zeroed,
+/// `payer` tops up rent) and writes it back. Returns the new version.
+fn upgrade_account<'info, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8>
+where
+    T: Versioned + AccountSerialize + AccountDeserialize + Discriminator + Space,
+{
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::ConstraintOwner);
+    let mut state = decode_legacy::<T>(&account.try_borrow_data()?)?;
+    require!(state.version() < T::VERSION, FanStakeError::AlreadyMigrated);
+    state.upgrade();
+    let mut bytes = Vec::new();
+    state.try_serialize(&mut bytes)?;
+
+    let space = bytes.len().max(8 + T::INIT_SPACE);
+    if account.data_len() < space {
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        account.resize(space)?;
+    }
+    account.try_borrow_mut_data()?[..bytes.len()].copy_from_slice(&bytes);
+    Ok(T::VERSION)
+}
+
+/// Decodes `data` as a `T`, reading fields past the end of an older, shorter
+/// layout as zero.
+fn decode_legacy<T: AccountDeserialize + Discriminator + Space>(data: &[u8]) -> Result<T> {
+    require!(data.starts_with(T::DISCRIMINATOR), ErrorCode::AccountDiscriminatorMismatch);
+    let mut padded = data.to_vec();
+    padded.resize(data.len() + T::INIT_SPACE, 0);
+    T::try_deserialize(&mut &padded[..])
+}
+
+/// Accounts the shared buy path touches, borrowed from `Buy` or `GiftBuy`.
+struct BuyAccounts<'a, 'info> {
+    bonding_curve: &'a mut Account<'info, BondingCurve>,
+    platform_config: &'a mut Account<'info, PlatformConfig>,
+    curve_stats: &'a mut Account<'info, CurveStats>,
+    price_history: &'a AccountLoader<'info, PriceHistory>,
+    mint: AccountInfo<'info>,
+    destination: AccountInfo<'info>,     // Token account receiving the minted tokens
+    payer: AccountInfo<'info>,           // Pays SOL + fee (a curve vault when swapping)
+    trader: Pubkey,                      // Wallet recorded on the `TradeEvent`
+    curve_vault: AccountInfo<'info>,
+    fee_vault: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Outcome of `execute_buy`, for the caller's per-wallet bookkeeping.
+struct BuyFill {
+    tokens_out: u64,
+    timestamp: i64,
+}
+
+/// Quotes and settles a buy at `fee_bps`: moves SOL and fee from the payer,
+/// mints to the destination, updates curve-wide stats and emits the
+/// `TradeEvent`. `payer_seeds` signs when the payer is a PDA; a payer that
+/// is the curve vault itself has pre-funded the SOL.
+fn execute_buy(
+    accs: BuyAccounts,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    fee_bps: u64,
+    payer_seeds: &[&[&[u8]]],
+) -> Result<BuyFill> {
+    let curve = accs.bonding_curve;
+
+    require!(curve.is_active, FanStakeError::CurveNotActive);
+    require!(!accs.platform_config.paused, FanStakeError::PlatformPaused);
+    require!(sol_amount > 0, FanStakeError::InvalidAmount);
+
+    // Platform fee + constant product quote (shared with off-chain clients)
+    let reserves = curve.reserves();
+    let quote = fanstake_math::quote_buy_with_treasury(&reserves, sol_amount, fee_bps, curve.treasury_bps as u64)
+        .ok_or(FanStakeError::MathOverflow)?;
+    let fee = quote.fee;
+    let sol_after_fee = sol_amount - fee;
+    let tokens_out = quote.tokens_out;
+
+    require!(tokens_out >= min_tokens_out, FanStakeError::SlippageExceeded);
+    require!(tokens_out <= reserves.real_token, FanStakeError::InsufficientTokens);
+
+    let price_before = curve.spot_price();
+
+    // Update curve state; the treasury cut sits in the vault outside the reserves
+    curve.set_reserves(&reserves.after_buy(&quote).ok_or(FanStakeError::MathOverflow)?);
+    curve.treasury_balance = curve.treasury_balance.checked_add(quote.treasury).ok_or(FanStakeError::MathOverflow)?;
+
+    // Transfer SOL from payer to curve vault
+    if accs.payer.key() != accs.curve_vault.key() {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                accs.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: accs.payer.clone(),
+                    to: accs.curve_vault,
+                },
+                payer_seeds,
+            ),
+            sol_after_fee,
+        )?;
+    }
+
+    // Transfer fee to platform vault
+    if fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                accs.system_program,
+                anchor_lang::system_program::Transfer {
+                    from: accs.payer,
+                    to: accs.fee_vault,
+                },
+                payer_seeds,
+            ),
+            fee,
+        )?;
+    }
+
+    // Mint tokens to the destination (PDA signs)
+    let seeds: &[&[u8]] = &[b"bonding_curve", curve.mint.as_ref(), &[curve.bump]];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            accs.token_program,
+            token_interface::MintTo {
+                mint: accs.mint,
+                to: accs.destination,
+                authority: curve.to_account_info(),
+            },
+            &[seeds],
+        ),
+        tokens_out,
+    )?;
+
+    // Trading stats
+    let now = Clock::get()?.unix_timestamp;
+    let price_after = curve.spot_price();
+    accs.curve_stats.record_buy(sol_amount, tokens_out, fee, price_after, now);
+    accs.platform_config.record_trade(sol_amount, fee);
+    accs.platform_config.activate_fee_change(now);
+    accs.price_history.load_mut()?.record(now, price_before, price_after, sol_amount);
+
+    emit!(TradeEvent::new(
+        curve,
+        accs.trader,
+        true,
+        sol_amount,
+        tokens_out,
+        fee,
+        price_before,
+        now,
+    ));
+
+    msg!("BUY: {} SOL -> {} tokens (fee: {} SOL)", sol_after_fee, tokens_out, fee);
+    Ok(BuyFill { tokens_out, timestamp: now })
+}
+
+/// Buys into the buyback's burn account, checks the price cap on the
+/// post-fill price, burns the tokens and emits a `BuybackEvent`.
+fn fill_buyback(
+    accs: &mut RunBuyback,
+    vault_bump: u8,
+    sol_amount: u64,
+    min_tokens_out: u64,
+    from_treasury: bool,
+    scheduled: bool,
+) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+    let max_price = accs.buyback.config.max_price;
+    accs.buyback.consume_allowance(sol_amount, now)?;
+
+    let mint = accs.bonding_curve.mint;
+    let vault_seeds: &[&[u8]] = &[b"curve_vault", mint.as_ref(), &[vault_bump]];
+    let (payer, payer_seeds): (AccountInfo, &[&[&[u8]]]) = if from_treasury {
+        let available = lamports_above_rent(&accs.buyback.to_account_info())?;
+        require!(available >= sol_amount, FanStakeError::InsufficientBuybackBalance);
+        // The vault is pre-funded and then pays the fee
+        accs.buyback.sub_lamports(sol_amount)?;
+        accs.curve_vault.add_lamports(sol_amount)?;
+        (accs.curve_vault.to_account_info(), &[vault_seeds])
+    } else {
+        (accs.caller.to_account_info(), &[])
+    };
+
+    let fee_bps = accs.platform_config.current_fee_bps()?;
+    let fill = execute_buy(
+        BuyAccounts {
+            bonding_curve: &mut accs.bonding_curve,
+            platform_config: &mut accs.platform_config,
+            curve_stats: &mut accs.curve_stats,
+            price_history: &accs.price_history,
+            mint: accs.mint.to_account_info(),
+            destination: accs.burn_account.to_account_info(),
+            payer,
+            trader: accs.buyback.key(),
+            curve_vault: accs.curve_vault.to_account_info(),
+            fee_vault: accs.fee_vault.to_account_info(),
+            token_program: accs.token_program.to_account_info(),
+            system_program: accs.system_program.to_account_info(),
+        },
+        sol_amount,
+        min_tokens_out,
+        fee_bps,
+        payer_seeds,
+    )?;
+    let price_after = accs.bonding_curve.spot_price();
+    require!(price_after <= max_price, FanStakeError::BuybackPriceAboveMax);
+
+    let seeds: &[&[u8]] = &[b"buyback", mint.as_ref(), &[accs.buyback.bump]];
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            accs.token_program.to_account_info(),
+            token_interface::Burn {
+                mint: accs.mint.to_account_info(),
+                from: accs.burn_account.to_account_info(),
+                authority: accs.buyback.to_account_info(),
+            },
+            &[seeds],
+        ),
+        fill.tokens_out,
+    )?;
+
+    let buyback = &mut accs.buyback;
+    buyback.total_sol_spent = buyback.total_sol_spent.saturating_add(sol_amount);
+    buyback.total_tokens_burned = buyback.total_tokens_burned.saturating_add(fill.tokens_out);
+
+    emit!(BuybackEvent {
+        mint,
+        caller: accs.caller.key(),
+        sol_amount,
+        tokens_burned: fill.tokens_out,
+        price_after,
+        from_treasury,
+        scheduled,
+        timestamp: now,
+    });
+
+    msg!("Buyback: {} lamports -> {} tokens burned", sol_amount, fill.tokens_out);
+    Ok(fill.tokens_out)
+}
+
+/// Accounts the shared sell path touches, borrowed from `Sell` or an index leg.
+struct SellAccounts<'a, 'info> {
+    bonding_curve: &'a mut Account<'info, BondingCurve>,
+    platform_config: &'a mut Account<'info, PlatformConfig>,
+    curve_stats: &'a mut Account<'info, CurveStats>,
+    price_history: &'a AccountLoader<'info, PriceHistory>,
+    mint: AccountInfo<'info>,
+    source: AccountInfo<'info>,          // Token account the tokens are burned from
+    owner: AccountInfo<'info>,           // Authority over `source`
+    recipient: AccountInfo<'info>,       // Receives the SOL (a curve vault when swapping)
+    trader: Pubkey,                      // Wallet recorded on the `TradeEvent`
+    curve_vault: AccountInfo<'info>,
+    vault_bump: u8,
+    fee_vault: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+}
+
+/// Outcome of `execute_sell`, for the caller's per-wallet bookkeeping.
+struct SellFill {
+    sol_out: u64,
+    timestamp: i64,
+}
+
+/// Quotes and settles a sell at `fee_bps`: burns from the source, pays the
+/// recipient and fee out of the curve vault, updates curve-wide stats and
+/// emits the `TradeEvent`. `owner_seeds` signs the burn when the owner is a PDA.
+fn execute_sell(
+    accs: SellAccounts,
+    token_amount: u64,
+    min_sol_out: u64,
+    fee_bps: u64,
+    owner_seeds: &[&[&[u8]]],
+) -> Result<SellFill> {
+    let curve = accs.bonding_curve;
+
+    require!(curve.is_active, FanStakeError::CurveNotActive);
+    require!(!accs.platform_config.paused, FanStakeError::PlatformPaused);
+    require!(token_amount > 0, FanStakeError::InvalidAmount);
+
+    // Constant product quote + platform fee (shared with off-chain clients)
+    let reserves = curve.reserves();
+    let quote = fanstake_math::quote_sell(&reserves, token_amount, fee_bps)
+        .ok_or(FanStakeError::MathOverflow)?;
+    let sol_out_gross = quote.sol_out_gross;
+    let fee = quote.fee;
+    let sol_out = quote.sol_out;
+
+    require!(sol_out >= min_sol_out, FanStakeError::SlippageExceeded);
+    require!(sol_out_gross <= reserves.real_sol, FanStakeError::InsufficientSol);
+
+    let price_before = curve.spot_price();
+
+    // Update curve state
+    curve.set_reserves(&reserves.after_sell(&quote).ok_or(FanStakeError::MathOverflow)?);
+
+    // Burn tokens from seller
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            accs.token_program,
+            token_interface::Burn {
+                mint: accs.mint,
+                from: accs.source,
+                authority: accs.owner,
+            },
+            owner_seeds,
+        ),
+        token_amount,
+    )?;
+
+    // Transfer SOL to the recipient from curve vault (PDA-signed CPI)
+    let vault_seeds: &[&[u8]] = &[b"curve_vault", curve.mint.as_ref(), &[accs.vault_bump]];
+    let signer_seeds = &[vault_seeds];
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            accs.system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: accs.curve_vault.clone(),
+                to: accs.recipient.clone(),
+            },
+            signer_seeds,
+        ),
+        sol_out,
+    )?;
+
+    // Transfer fee to platform vault
+    if fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                accs.system_program,
+                anchor_lang::system_program::Transfer {
+                    from: accs.curve_vault,
+                    to: accs.fee_vault,
+                },
+                signer_seeds,
+            ),
+            fee,
+        )?;
+    }
+
+    // Trading stats
+    let now = Clock::get()?.unix_timestamp;
+    let price_after = curve.spot_price();
+    accs.curve_stats.record_sell(sol_out_gross, token_amount, fee, price_after, now);
+    accs.platform_config.record_trade(sol_out_gross, fee);
+    accs.platform_config.activate_fee_change(now);
+    accs.price_history.load_mut()?.record(now, price_before, price_after, sol_out_gross);
+
+    emit!(TradeEvent::new(
+        curve,
+        accs.trader,
+        false,
+        sol_out_gross,
+        token_amount,
+        fee,
+        price_before,
+        now,
+    ));
+
+    msg!("SELL: {} tokens -> {} SOL (fee: {} SOL)", token_amount, sol_out, fee);
+    Ok(SellFill { sol_out, timestamp: now })
+}
+
+/// Token program account for a curve's mint, picked from the two an index
+/// instruction may carry.
+fn component_token_program<'info>(
+    curve: &BondingCurve,
+    token_program: &Program<'info, Token>,
+    token_2022_program: &Option<Program<'info, Token2022>>,
+) -> Result<AccountInfo<'info>> {
+    if curve.token_program == token_program.key() {
+        return Ok(token_program.to_account_info());
+    }
+    match token_2022_program {
+        Some(program) if curve.token_program == program.key() => Ok(program.to_account_info()),
+        _ => err!(FanStakeError::IndexAccountMismatch),
+    }
+}
+
+/// One index component's accounts, taken from remaining accounts and checked
+/// against the index: `[bonding_curve, mint, index_token_account,
+/// curve_stats, price_history, curve_vault]`.
+struct IndexLeg<'info> {
+    bonding_curve: Account<'info, BondingCurve>,
+    curve_stats: Account<'info, CurveStats>,
+    price_history: AccountLoader<'info, PriceHistory>,
+    mint: AccountInfo<'info>,
+    index_token_account: AccountInfo<'info>,
+    curve_vault: AccountInfo<'info>,
+    vault_bump: u8,
+    holding: u64,                        // Index's component balance before the trade
+}
+
+impl<'info> IndexLeg<'info> {
+    const ACCOUNTS: usize = 6;
+
+    fn load_all(
+        index: &Pubkey,
+        components: &[IndexComponent],
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<Self>> {
+        require!(
+            accounts.len() == components.len() * Self::ACCOUNTS,
+            FanStakeError::IndexAccountMismatch
+        );
+        components
+            .iter()
+            .zip(accounts.chunks(Self::ACCOUNTS))
+            .map(|(component, accounts)| Self::load(index, component, accounts))
+            .collect()
+    }
+
+    fn load(index: &Pubkey, component: &IndexComponent, accounts: &'info [AccountInfo<'info>]) -> Result<Self> {
+        let [curve, mint, index_token_account, stats, history, curve_vault] = accounts else {
+            return err!(FanStakeError::IndexAccountMismatch);
+        };
+        let bonding_curve = Account::<BondingCurve>::try_from(curve)?;
+        let curve_stats = Account::<CurveStats>::try_from(stats)?;
+        let price_history = AccountLoader::<PriceHistory>::try_from(history)?;
+        let (vault_address, vault_bump) =
+            Pubkey::find_program_address(&[b"curve_vault", component.mint.as_ref()], &crate::ID);
+        let token_account =
+            InterfaceAccount::<token_interface::TokenAccount>::try_from(index_token_account)?;
+        let expected_token_account = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            index,
+            &component.mint,
+            &bonding_curve.token_program,
+        );
+        require!(
+            bonding_curve.mint == component.mint
+                && mint.key() == component.mint
+                && curve_stats.mint == component.mint
+                && price_history.load()?.mint == component.mint
+                && curve_vault.key() == vault_address
+                && index_token_account.key() == expected_token_account,
+            FanStakeError::IndexAccountMismatch
+        );
+        Ok(Self {
+            bonding_curve,
+            curve_stats,
+            price_history,
+            mint: mint.clone(),
+            index_token_account: index_token_account.clone(),
+            curve_vault: curve_vault.clone(),
+            vault_bump,
+            holding: token_account.amount,
+        })
+    }
+
+    /// Writes back the curve and stats changed by the trade.
+    fn exit(&self) -> Result<()> {
+        self.bonding_curve.exit(&crate::ID)?;
+        self.curve_stats.exit(&crate::ID)
+    }
+}
+
+/// Collateral value in lamports, two ways (see `collateral_value`).
+struct CollateralValue {
+    borrow: u64,
+    liquidation: u64,
+}
+
+/// Values `amount` tokens of collateral. The reserve-backed value is what
+/// selling them on the curve would pay right now; the TWAP value prices them
+/// at the `LENDING_TWAP_WINDOW` TWAP. Borrowing uses the lower of the two so
+/// a pumped spot price can't inflate it; liquidation uses the TWAP so a
+/// momentary dump can't force it. Both fall back to the reserve-backed value
+/// while the curve lacks enough price history.
+fn collateral_value(
+    curve: &BondingCurve,
+    stats: &CurveStats,
+    amount: u64,
+    fee_bps: u64,
+    now: i64,
+) -> Result<CollateralValue> {
+    if amount == 0 {
+        return Ok(CollateralValue { borrow: 0, liquidation: 0 });
+    }
+    let reserves = curve.reserves();
+    let quote = fanstake_math::quote_sell(&reserves, amount, fee_bps).ok_or(FanStakeError::MathOverflow)?;
+    let sell_side = quote.sol_out.min(reserves.real_sol);
+    match stats.twap(LENDING_TWAP_WINDOW, now) {
+        Some((twap, _)) => {
+            let at_twap = fanstake_math::value_at_price(twap, amount).ok_or(FanStakeError::MathOverflow)?;
+            Ok(CollateralValue { borrow: at_twap.min(sell_side), liquidation: at_twap })
+        }
+        None => Ok(CollateralValue { borrow: sell_side, liquidation: sell_side }),
+    }
+}
+
+/// Loads a program account at a PDA that callers always pass but that may not
+/// exist yet, so an opted-in account can't be dodged by leaving it out.
+fn load_if_exists<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &T::owner() || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Writes back an account read with `load_if_exists`.
+fn store<T: AccountSerialize>(info: &AccountInfo, account: &T) -> Result<()> {
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
+
+/// Fails while the artist's allocation is locked. The vesting PDA is always
+/// passed, so leaving it out can't skip the check.
+fn require_vested(info: &AccountInfo) -> Result<()> {
+    if let Some(vesting) = load_if_exists::<VestingSchedule>(info)? {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= vesting.vesting_end, FanStakeError::TokensStillVesting);
+    }
+    Ok(())
+}
+
+/// The transfer hook's lockup, for moves it doesn't see: until vesting ends
+/// the artist's allocation account keeps at least the allocation.
+fn require_allocation_kept(info: &AccountInfo, curve: &BondingCurve, balance_after: u64) -> Result<()> {
+    if let Some(vesting) = load_if_exists::<VestingSchedule>(info)? {
+        if Clock::get()?.unix_timestamp < vesting.vesting_end {
+            require!(balance_after >= curve.artist_allocation(), FanStakeError::TokensStillVesting);
+        }
+    }
+    Ok(())
+}
+
+/// Moves artist tokens between token accounts from inside the program.
+/// Token-2022 artist mints name this program as their transfer hook, and the
+/// runtime rejects the fanstake → Token-2022 → fanstake reentry a
+/// `transfer_checked` would make. Those moves burn from `from` and mint as
+/// much to `to` instead, with the curve as mint authority: the supply is
+/// unchanged and the hook doesn't run, so callers hold the artist to the
+/// vesting lockup themselves. SPL Token mints transfer as usual.
+#[allow(clippy::too_many_arguments)]
+fn move_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, token_interface::Mint>,
+    curve: &Account<'info, BondingCurve>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    authority_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if token_program.key() != token_interface::spl_token_2022::ID {
+        return token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::TransferChecked { from, mint: mint.to_account_info(), to, authority },
+                authority_seeds,
+            ),
+            amount,
+            mint.decimals,
+        );
+    }
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::Burn { mint: mint.to_account_info(), from, authority },
+            authority_seeds,
+        ),
+        amount,
+    )?;
+    let seeds: &[&[u8]] = &[b"bonding_curve", curve.mint.as_ref(), &[curve.bump]];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::MintTo { mint: mint.to_account_info(), to, authority: curve.to_account_info() },
+            &[seeds],
+        ),
+        amount,
+    )
+}
+
+/// Counts a sale through the curve on the seller's buyer record, if they have one.
+fn record_sale(info: &AccountInfo, curve: &mut BondingCurve, token_amount: u64) -> Result<()> {
+    if let Some(mut record) = load_if_exists::<BuyerRecord>(info)? {
+        record.record_sale(curve, token_amount);
+        store(info, &record)?;
+    }
+    Ok(())
+}
+
+/// Credits tokens bought through the curve to a buyer record, if the wallet has one.
+fn credit_tokens(info: &AccountInfo, curve: &mut BondingCurve, tokens: u64) -> Result<()> {
+    if let Some(mut record) = load_if_exists::<BuyerRecord>(info)? {
+        record.credit_tokens(curve, tokens);
+        store(info, &record)?;
+    }
+    Ok(())
+}
+
+/// Applies a trade to a wallet's cost-basis position, if they've opened one.
+fn update_position(info: &AccountInfo, apply: impl FnOnce(&mut Position)) -> Result<()> {
+    if let Some(mut position) = load_if_exists::<Position>(info)? {
+        apply(&mut position);
+        store(info, &position)?;
+    }
+    Ok(())
+}
+
+/// Lamports a program account holds beyond its rent-exempt minimum.
+fn lamports_above_rent(info: &AccountInfo) -> Result<u64> {
+    let rent = Rent::get()?.minimum_balance(info.data_len());
+    Ok(info.lamports().saturating_sub(rent))
+}
+
+/// 1 to `MAX_ADMIN_MEMBERS` distinct members and a reachable threshold.
+fn validate_admin_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty()
+            && members.len() <= MAX_ADMIN_MEMBERS
+            && threshold > 0
+            && threshold as usize <= members.len()
+            && members.iter().enumerate().all(|(i, m)| !members[..i].contains(m)),
+        FanStakeError::InvalidAdminGroup
+    );
+    Ok(())
+}
+
+/// Accounts appended to every transfer-hook call: the vesting schedule and
+/// the bonding curve, both derived from the mint (account index 1), and the
+/// source owner's buyer record, derived from the owner field of the source
+/// token account (account index 0, bytes 32..64).
+fn transfer_hook_extra_accounts() -> Result<[ExtraAccountMeta; 3]> {
+    let pda = |prefix: &[u8], is_writable: bool| {
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal { bytes: prefix.to_vec() }, Seed::AccountKey { index: 1 }],
+            false,
+            is_writable,
+        )
+    };
+    let buyer_record = ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal { bytes: b"buyer_record".to_vec() },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+        ],
+        false,
+        true,
+    )?;
+    Ok([pda(b"artist_vesting", false)?, pda(b"bonding_curve", true)?, buyer_record])
+}
+
+/// Metaplex metadata content mirrored from the curve account.
+fn token_metadata(curve: &BondingCurve) -> mpl_token_metadata::types::DataV2 {
+    mpl_token_metadata::types::DataV2 {
+        name: curve.name.clone(),
+        symbol: curve.symbol.clone(),
+        uri: curve.uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    }
+}
+
+/// Merkle hash prefixes — leaves and inner nodes are domain-separated
+/// so an inner node can never be passed off as a leaf.
+const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+/// Merkle leaf for an airdrop allocation: sha256(0x00 || index || claimant || amount).
+pub fn airdrop_leaf(index: u32, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[MERKLE_LEAF_PREFIX, &index.to_le_bytes(), claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Verifies a proof built with sorted sibling pairs (no left/right flags needed).
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[MERKLE_NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[MERKLE_NODE_PREFIX, sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
+
+// ============================================================
+// ACCOUNTS
+// ============================================================
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: Fee vault PDA
+    #[account(
+        seeds = [b"fee_vault"],
+        bump,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateArtistToken<'info> {
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        init,
+        payer = artist,
+        mint::decimals = 6,
+        mint::authority = bonding_curve,
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub artist: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = artist,
+        associated_token::mint = mint,
+        associated_token::authority = artist,
+    )]
+    pub artist_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: Account<'info, VestingSchedule>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + CurveStats::INIT_SPACE,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + std::mem::size_of::<PriceHistory>(),
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Metaplex metadata PDA, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateArtistToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+        has_one = artist, // only the original artist can update
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    pub artist: Signer<'info>,
+    /// CHECK: Metaplex metadata PDA — may be empty for curves without metadata
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), bonding_curve.mint.as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    /// Required when the metadata account exists
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    /// CHECK: The curve's mint — Token-2022 mints hold their own metadata
+    #[account(mut, address = bonding_curve.mint)]
+    pub mint: UncheckedAccount<'info>,
+    /// Required for Token-2022 mints
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct Buy<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    /// Per-wallet record — created on first buy, drives the unique-buyer count
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + BuyerRecord::INIT_SPACE,
+        seeds = [b"buyer_record", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub buyer_record: Box<Account<'info, BuyerRecord>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    /// CHECK: Cost-basis position PDA — updated once opened (see `open_position`)
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub position: UncheckedAccount<'info>,
+    /// SPL Token or Token-2022, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    /// CHECK: the seller's `BuyerRecord`, updated when it exists (see `record_sale`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub buyer_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    /// CHECK: Artist vesting PDA — checked when the artist is selling
+    #[account(
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: UncheckedAccount<'info>,
+    /// CHECK: Cost-basis position PDA — updated once opened (see `open_position`)
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub position: UncheckedAccount<'info>,
+    /// SPL Token or Token-2022, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GiftBuy<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Any wallet — only used as the token account authority
+    pub recipient: UncheckedAccount<'info>,
+    /// Created on the payer's dime if the recipient has never held the token
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: Recipient's `BuyerRecord`, updated when it exists (see `credit_tokens`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), recipient.key().as_ref()],
+        bump,
+    )]
+    pub recipient_buyer_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    /// SPL Token or Token-2022, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(airdrop_id: u64, merkle_root: [u8; 32], total_amount: u64, num_recipients: u32)]
+pub struct CreateAirdrop<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Airdrop::INIT_SPACE + Airdrop::bitmap_len(num_recipients),
+        seeds = [b"airdrop", mint.key().as_ref(), creator.key().as_ref(), &airdrop_id.to_le_bytes()],
+        bump,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+    /// Only FanStake artist tokens can be airdropped
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"airdrop_escrow", airdrop.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = airdrop,
+        token::token_program = token_program,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: Artist vesting PDA — checked when the artist funds a Token-2022 airdrop
+    #[account(
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    #[account(
+        mut,
+        seeds = [b"airdrop", airdrop.mint.as_ref(), airdrop.creator.as_ref(), &airdrop.airdrop_id.to_le_bytes()],
+        bump = airdrop.bump,
+        has_one = mint,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+    /// Mint authority for Token-2022 escrow moves (see `move_tokens`)
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        seeds = [b"airdrop_escrow", airdrop.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program,
+    )]
+    pub claimant_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimUnclaimed<'info> {
+    #[account(
+        mut,
+        seeds = [b"airdrop", airdrop.mint.as_ref(), airdrop.creator.as_ref(), &airdrop.airdrop_id.to_le_bytes()],
+        bump = airdrop.bump,
+        has_one = creator, // only the funder can reclaim
+        has_one = mint,
+        close = creator,
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+    /// Mint authority for Token-2022 escrow moves (see `move_tokens`)
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        seeds = [b"airdrop_escrow", airdrop.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+
+#[derive(Accounts)]
+pub struct SetBoostTier<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+        constraint = platform_config.admin_group == Pubkey::default() @ FanStakeError::AdminGroupRequired,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseBoost<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Boost::INIT_SPACE,
+        seeds = [b"boost", bonding_curve.mint.as_ref()],
+        bump,
+    )]
+    pub boost: Account<'info, Boost>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitCurveStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CurveStats::INIT_SPACE,
+        seeds = [b"curve_stats", bonding_curve.mint.as_ref()],
+        bump,
+    )]
+    pub curve_stats: Account<'info, CurveStats>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    #[account(
+        seeds = [b"curve_stats", curve_stats.mint.as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Account<'info, CurveStats>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitPriceHistory<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<PriceHistory>(),
+        seeds = [b"price_history", bonding_curve.mint.as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPriceHistoryInterval<'info> {
+    #[account(
+        mut,
+        seeds = [b"price_history", bonding_curve.mint.as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+        has_one = artist, // only the original artist can reconfigure
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    pub artist: Signer<'info>,
+}
+
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Position::INIT_SPACE,
+        seeds = [b"position", bonding_curve.mint.as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(kind: BadgeKind)]
+pub struct MintBadge<'info> {
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Badge::INIT_SPACE,
+        seeds = [b"badge", buyer_record.mint.as_ref(), wallet.key().as_ref(), &[kind as u8]],
+        bump,
+    )]
+    pub badge: Account<'info, Badge>,
+    #[account(
+        seeds = [b"buyer_record", buyer_record.mint.as_ref(), wallet.key().as_ref()],
+        bump = buyer_record.bump,
+    )]
+    pub buyer_record: Account<'info, BuyerRecord>,
+    /// The wallet's balance of the curve's token
+    #[account(
+        token::mint = buyer_record.mint,
+        token::authority = wallet,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitTokenMetadata<'info> {
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+
+#[derive(Accounts)]
+pub struct CreateArtistToken2022<'info> {
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        init,
+        payer = artist,
+        mint::decimals = 6,
+        mint::authority = bonding_curve,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = bonding_curve,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::transfer_hook::authority = bonding_curve,
+        extensions::transfer_hook::program_id = crate::ID,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub artist: Signer<'info>,
+    #[account(
+        init,
+        payer = artist,
+        associated_token::mint = mint,
+        associated_token::authority = artist,
+        associated_token::token_program = token_program,
+    )]
+    pub artist_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: Box<Account<'info, VestingSchedule>>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + CurveStats::INIT_SPACE,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + std::mem::size_of::<PriceHistory>(),
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Transfer-hook extra account list, written in the handler
+    #[account(
+        init,
+        payer = artist,
+        space = ExtraAccountMetaList::size_of(transfer_hook_extra_accounts()?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order is fixed by the SPL transfer-hook interface.
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, token_interface::TokenAccount>,
+    /// CHECK: Source owner or delegate — not needed for the check
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: Extra account list for this mint
+    #[account(seeds = [b"extra-account-metas", mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump = artist_vesting.bump,
+    )]
+    pub artist_vesting: Account<'info, VestingSchedule>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    /// CHECK: the source owner's `BuyerRecord`, debited when it exists (see `BuyerRecord::forfeit_tokens`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), source_token.owner.as_ref()],
+        bump,
+    )]
+    pub buyer_record: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(index_id: u64)]
+pub struct CreateIndex<'info> {
+    #[account(
+        init,
+        payer = curator,
+        space = 8 + Index::INIT_SPACE,
+        seeds = [b"index", curator.key().as_ref(), &index_id.to_le_bytes()],
+        bump,
+    )]
+    pub index: Box<Account<'info, Index>>,
+    #[account(
+        init,
+        payer = curator,
+        seeds = [b"index_mint", index.key().as_ref()],
+        bump,
+        mint::decimals = INDEX_DECIMALS,
+        mint::authority = index,
+    )]
+    pub index_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    /// Required when any component is a Token-2022 curve
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyIndex<'info> {
+    #[account(
+        seeds = [b"index", index.curator.as_ref(), &index.index_id.to_le_bytes()],
+        bump = index.bump,
+        has_one = index_mint,
+    )]
+    pub index: Box<Account<'info, Index>>,
+    #[account(mut)]
+    pub index_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = index_mint,
+        associated_token::authority = user,
+    )]
+    pub user_index_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    /// Required when any component is a Token-2022 curve
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemIndex<'info> {
+    #[account(
+        seeds = [b"index", index.curator.as_ref(), &index.index_id.to_le_bytes()],
+        bump = index.bump,
+        has_one = index_mint,
+    )]
+    pub index: Box<Account<'info, Index>>,
+    #[account(mut)]
+    pub index_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = index_mint,
+        associated_token::authority = user,
+    )]
+    pub user_index_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    /// Required when any component is a Token-2022 curve
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSwapFeeDiscount<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+        constraint = platform_config.admin_group == Pubkey::default() @ FanStakeError::AdminGroupRequired,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeChangeDelay<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+        constraint = platform_config.admin_group == Pubkey::default() @ FanStakeError::AdminGroupRequired,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SwapArtistTokens<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    // Curve being sold
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", from_mint.key().as_ref()],
+        bump = from_curve.bump,
+    )]
+    pub from_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = from_token_program)]
+    pub from_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = from_mint,
+        associated_token::authority = user,
+        associated_token::token_program = from_token_program,
+    )]
+    pub from_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", from_mint.key().as_ref()],
+        bump = from_curve_stats.bump,
+    )]
+    pub from_curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", from_mint.key().as_ref()],
+        bump,
+    )]
+    pub from_price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", from_mint.key().as_ref()],
+        bump,
+    )]
+    pub from_curve_vault: AccountInfo<'info>,
+    /// CHECK: the seller's `BuyerRecord`, updated when it exists (see `record_sale`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", from_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub from_buyer_record: UncheckedAccount<'info>,
+    /// CHECK: `from` artist's vesting PDA — checked when that artist is selling
+    #[account(
+        seeds = [b"artist_vesting", from_mint.key().as_ref()],
+        bump,
+    )]
+    pub from_artist_vesting: UncheckedAccount<'info>,
+    /// CHECK: Cost-basis position PDA for the sold token — updated once opened
+    #[account(
+        mut,
+        seeds = [b"position", from_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub from_position: UncheckedAccount<'info>,
+
+    // Curve being bought
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", to_mint.key().as_ref()],
+        bump = to_curve.bump,
+    )]
+    pub to_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = to_token_program)]
+    pub to_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = to_mint,
+        associated_token::authority = user,
+        associated_token::token_program = to_token_program,
+    )]
+    pub to_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", to_mint.key().as_ref()],
+        bump = to_curve_stats.bump,
+    )]
+    pub to_curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", to_mint.key().as_ref()],
+        bump,
+    )]
+    pub to_price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", to_mint.key().as_ref()],
+        bump,
+    )]
+    pub to_curve_vault: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + BuyerRecord::INIT_SPACE,
+        seeds = [b"buyer_record", to_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub to_buyer_record: Box<Account<'info, BuyerRecord>>,
+    /// CHECK: Cost-basis position PDA for the bought token — updated once opened
+    #[account(
+        mut,
+        seeds = [b"position", to_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub to_position: UncheckedAccount<'info>,
+
+    /// Token programs of the two curves (may be the same program twice)
+    pub from_token_program: Interface<'info, TokenInterface>,
+    pub to_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(order_id: u64, side: OrderSide)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + LimitOrder::INIT_SPACE,
+        seeds = [b"limit_order", mint.key().as_ref(), owner.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Box<Account<'info, LimitOrder>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// Receives buy fills; source of sell escrow
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// Sell orders only: holds the escrowed tokens
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    /// CHECK: Artist vesting PDA — checked when the artist places a sell order
+    #[account(
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"limit_order", mint.key().as_ref(), owner.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        has_one = owner,
+        has_one = mint,
+    )]
+    pub order: Box<Account<'info, LimitOrder>>,
+    /// Mint authority for Token-2022 escrow moves (see `move_tokens`)
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOrder<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"limit_order", mint.key().as_ref(), owner.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        has_one = owner,
+        has_one = mint,
+    )]
+    pub order: Box<Account<'info, LimitOrder>>,
+    /// CHECK: Order owner — receives the fill, escrow rent and order rent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    /// Anyone; earns the order's tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: Owner's `BuyerRecord`, updated when it exists (see `credit_tokens`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub buyer_record: UncheckedAccount<'info>,
+    /// CHECK: Owner's cost-basis position PDA — updated once opened
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: UncheckedAccount<'info>,
+    /// Sell orders only
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+#[instruction(plan_id: u64)]
+pub struct CreateDcaPlan<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DcaPlan::INIT_SPACE,
+        seeds = [b"dca_plan", mint.key().as_ref(), owner.key().as_ref(), &plan_id.to_le_bytes()],
+        bump,
+    )]
+    pub plan: Box<Account<'info, DcaPlan>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// Receives every scheduled buy
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDca<'info> {
+    #[account(
+        mut,
+        seeds = [b"dca_plan", plan.mint.as_ref(), owner.key().as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = owner,
+    )]
+    pub plan: Box<Account<'info, DcaPlan>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDcaPlan<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"dca_plan", plan.mint.as_ref(), owner.key().as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = owner,
+    )]
+    pub plan: Box<Account<'info, DcaPlan>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDca<'info> {
+    #[account(
+        mut,
+        seeds = [b"dca_plan", mint.key().as_ref(), owner.key().as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = owner,
+        has_one = mint,
+    )]
+    pub plan: Box<Account<'info, DcaPlan>>,
+    /// CHECK: Plan owner, matched by `has_one`
+    pub owner: UncheckedAccount<'info>,
+    /// Anyone; earns the plan's tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: Owner's `BuyerRecord`, updated when it exists (see `credit_tokens`)
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub buyer_record: UncheckedAccount<'info>,
+    /// CHECK: Owner's cost-basis position PDA — updated once opened
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitLendingPool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LendingPool::INIT_SPACE,
+        seeds = [b"lending_pool", mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+        constraint = platform_config.admin_group == Pubkey::default() @ FanStakeError::AdminGroupRequired,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    /// Holds every borrower's collateral
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetLendingParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LendingPool>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+        constraint = platform_config.admin_group == Pubkey::default() @ FanStakeError::AdminGroupRequired,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        init_if_needed,
+        payer = lender,
+        space = 8 + LenderPosition::INIT_SPACE,
+        seeds = [b"lender_position", pool.mint.as_ref(), lender.key().as_ref()],
+        bump,
+    )]
+    pub lender_position: Box<Account<'info, LenderPosition>>,
+    #[account(mut)]
+    pub lender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        mut,
+        seeds = [b"lender_position", pool.mint.as_ref(), lender.key().as_ref()],
+        bump = lender_position.bump,
+        constraint = lender_position.owner == lender.key() @ FanStakeError::Unauthorized,
+    )]
+    pub lender_position: Box<Account<'info, LenderPosition>>,
+    #[account(mut)]
+    pub lender: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", mint.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        space = 8 + Loan::INIT_SPACE,
+        seeds = [b"loan", mint.key().as_ref(), borrower.key().as_ref()],
+        bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = borrower,
+        associated_token::token_program = token_program,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: Artist vesting PDA — checked when the artist pledges tokens
+    #[account(
+        seeds = [b"artist_vesting", mint.key().as_ref()],
+        bump,
+    )]
+    pub artist_vesting: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCollateral<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", mint.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        mut,
+        seeds = [b"loan", mint.key().as_ref(), borrower.key().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    pub borrower: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = borrower,
+        associated_token::token_program = token_program,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        mut,
+        seeds = [b"loan", pool.mint.as_ref(), borrower.key().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+    #[account(
+        seeds = [b"bonding_curve", pool.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        seeds = [b"curve_stats", pool.mint.as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", pool.mint.as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        mut,
+        seeds = [b"loan", pool.mint.as_ref(), borrower.key().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    #[account(
+        mut,
+        seeds = [b"lending_pool", mint.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LendingPool>>,
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"loan", mint.key().as_ref(), borrower.key().as_ref()],
+        bump = loan.bump,
+        has_one = borrower,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+    /// CHECK: Loan owner — receives any surplus and the loan's rent
+    #[account(mut)]
+    pub borrower: UncheckedAccount<'info>,
+    /// Anyone; earns the liquidation bonus
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct CreateArtistTreasury<'info> {
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + ArtistTreasury::INIT_SPACE,
+        seeds = [b"artist_treasury", mint.key().as_ref()],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, ArtistTreasury>>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = artist @ FanStakeError::Unauthorized,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    /// Holds tokens locked in release votes
+    #[account(
+        init,
+        payer = artist,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vote_escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub artist: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRelease<'info> {
+    #[account(
+        mut,
+        seeds = [b"artist_treasury", treasury.mint.as_ref()],
+        bump = treasury.bump,
+        has_one = artist @ FanStakeError::Unauthorized,
+    )]
+    pub treasury: Account<'info, ArtistTreasury>,
+    #[account(
+        seeds = [b"bonding_curve", treasury.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    pub artist: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(milestone: u8)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"artist_treasury", mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, ArtistTreasury>>,
+    /// One open vote per wallet per treasury; withdraw it before voting again
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + TreasuryVote::INIT_SPACE,
+        seeds = [b"treasury_vote", mint.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote: Box<Account<'info, TreasuryVote>>,
+    /// Mint authority for Token-2022 escrow moves (see `move_tokens`)
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"buyer_record", mint.key().as_ref(), voter.key().as_ref()],
+        bump = buyer_record.bump,
+    )]
+    pub buyer_record: Box<Account<'info, BuyerRecord>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub voter_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vote_escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(
+        seeds = [b"artist_treasury", mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, ArtistTreasury>>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"treasury_vote", mint.key().as_ref(), voter.key().as_ref()],
+        bump = vote.bump,
+    )]
+    pub vote: Box<Account<'info, TreasuryVote>>,
+    /// Mint authority for Token-2022 escrow moves (see `move_tokens`)
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub voter_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vote_escrow: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"artist_treasury", treasury.mint.as_ref()],
+        bump = treasury.bump,
+        has_one = artist,
+    )]
+    pub treasury: Box<Account<'info, ArtistTreasury>>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", treasury.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    /// CHECK: Treasury's artist — receives released funds
+    #[account(mut)]
+    pub artist: UncheckedAccount<'info>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", treasury.mint.as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FailTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"artist_treasury", treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, ArtistTreasury>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", treasury.mint.as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        seeds = [b"artist_treasury", mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, ArtistTreasury>>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"buyer_record", mint.key().as_ref(), holder.key().as_ref()],
+        bump = buyer_record.bump,
+    )]
+    pub buyer_record: Box<Account<'info, BuyerRecord>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitBuyback<'info> {
+    #[account(
+        init,
+        payer = artist,
+        space = 8 + Buyback::INIT_SPACE,
+        seeds = [b"buyback", mint.key().as_ref()],
+        bump,
+    )]
+    pub buyback: Box<Account<'info, Buyback>>,
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = artist @ FanStakeError::Unauthorized,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    /// Receives bought tokens for the instant before they are burned
+    #[account(
+        init,
+        payer = artist,
+        associated_token::mint = mint,
+        associated_token::authority = buyback,
+        associated_token::token_program = token_program,
+    )]
+    pub burn_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(mut)]
+    pub artist: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetBuybackConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"buyback", buyback.mint.as_ref()],
+        bump = buyback.bump,
+        has_one = artist @ FanStakeError::Unauthorized,
+    )]
+    pub buyback: Account<'info, Buyback>,
+    pub artist: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundBuyback<'info> {
+    #[account(
+        mut,
+        seeds = [b"buyback", buyback.mint.as_ref()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Account<'info, Buyback>,
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBuyback<'info> {
+    #[account(
+        mut,
+        seeds = [b"buyback", buyback.mint.as_ref()],
+        bump = buyback.bump,
+        has_one = artist @ FanStakeError::Unauthorized,
+    )]
+    pub buyback: Account<'info, Buyback>,
+    #[account(mut)]
+    pub artist: Signer<'info>,
+}
+
+/// Shared by `buyback` (artist only) and `execute_buyback` (anyone).
+#[derive(Accounts)]
+pub struct RunBuyback<'info> {
+    #[account(
+        mut,
+        seeds = [b"buyback", mint.key().as_ref()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Box<Account<'info, Buyback>>,
+    /// Artist paying from their wallet, or a keeper earning the tip
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = buyback,
+        associated_token::token_program = token_program,
+    )]
+    pub burn_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"curve_stats", mint.key().as_ref()],
+        bump = curve_stats.bump,
+    )]
+    pub curve_stats: Box<Account<'info, CurveStats>>,
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump,
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+    /// CHECK: Curve vault PDA holds SOL
+    #[account(
+        mut,
+        seeds = [b"curve_vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_vault: AccountInfo<'info>,
+    /// CHECK: Platform fee vault
+    #[account(
+        mut,
+        address = platform_config.fee_vault,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct InitAdminGroup<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AdminGroup::INIT_SPACE,
+        seeds = [b"admin_group"],
+        bump,
+    )]
+    pub admin_group: Account<'info, AdminGroup>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        has_one = authority,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateArtistToken<'info> {
+pub struct ProposeAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_group"],
+        bump = admin_group.bump,
+    )]
+    pub admin_group: Account<'info, AdminGroup>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::INIT_SPACE,
+        seeds = [b"admin_proposal".as_ref(), &admin_group.proposal_count.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    #[account(
+        seeds = [b"admin_group"],
+        bump = admin_group.bump,
+    )]
+    pub admin_group: Account<'info, AdminGroup>,
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_group"],
+        bump = admin_group.bump,
+    )]
+    pub admin_group: Account<'info, AdminGroup>,
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: Fee vault PDA, only debited by `WithdrawFees`
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump,
+    )]
+    pub fee_vault: AccountInfo<'info>,
+    pub executor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+#[derive(Accounts)]
+pub struct ActivateFeeChange<'info> {
     #[account(
-        init,
-        payer = artist,
-        space = 8 + BondingCurve::INIT_SPACE,
-        seeds = [b"bonding_curve", mint.key().as_ref()],
+        mut,
+        seeds = [b"platform_config"],
         bump,
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
-    #[account(mut)]
     pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+pub struct MigratePlatformConfig<'info> {
+    /// CHECK: may be on an older, shorter layout; owner and discriminator are checked in `migrate_account`
     #[account(
-        init,
-        payer = artist,
-        mint::decimals = 6,
-        mint::authority = bonding_curve,
-    )]
-    pub mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub artist: Signer<'info>,
-    #[account(
-        init_if_needed,
-        payer = artist,
-        associated_token::mint = mint,
-        associated_token::authority = artist,
-    )]
-    pub artist_token_account: Account<'info, TokenAccount>,
-    #[account(
-        init,
-        payer = artist,
-        space = 8 + VestingSchedule::INIT_SPACE,
-        seeds = [b"artist_vesting", mint.key().as_ref()],
+        mut,
+        seeds = [b"platform_config"],
         bump,
     )]
-    pub artist_vesting: Account<'info, VestingSchedule>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub platform_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateArtistToken<'info> {
+pub struct MigrateBondingCurve<'info> {
+    /// CHECK: may be on an older, shorter layout; owner and discriminator are checked in `migrate_account`
     #[account(
         mut,
-        seeds = [b"bonding_curve", bonding_curve.mint.as_ref()],
-        bump = bonding_curve.bump,
-        has_one = artist, // only the original artist can update
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
-    pub artist: Signer<'info>,
+    pub bonding_curve: UncheckedAccount<'info>,
+    /// CHECK: only used to derive the curve address
+    pub mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: any program account, possibly on an older, shorter layout; the type comes from its discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================
+// STATE
+// ============================================================
+
+/// Accounts that carry a layout version, so `migrate_account` can bring
+/// older ones up to date. New fields are only ever appended; on an older
+/// account they read as zero until migrated.
+pub trait Versioned {
+    const VERSION: u8;
+
+    fn version(&self) -> u8;
+
+    /// Fills in defaults for fields the stored version lacked and stamps
+    /// `VERSION`.
+    fn upgrade(&mut self);
+}
+
+/// `Versioned` for accounts with nothing to fill in beyond the version.
+macro_rules! versioned {
+    ($($account:ty => $version:literal),* $(,)?) => {
+        $(impl Versioned for $account {
+            const VERSION: u8 = $version;
+
+            fn version(&self) -> u8 {
+                self.version
+            }
+
+            fn upgrade(&mut self) {
+                self.version = Self::VERSION;
+            }
+        })*
+    };
+}
+
+versioned! {
+    Boost => 1,
+    VestingSchedule => 1,
+    CurveStats => 1,
+    PriceHistory => 1,
+    BuyerRecord => 1,
+    Airdrop => 1,
+    Position => 1,
+    Badge => 1,
+    Index => 1,
+    LimitOrder => 1,
+    DcaPlan => 1,
+    LendingPool => 1,
+    LenderPosition => 1,
+    Loan => 1,
+    ArtistTreasury => 1,
+    TreasuryVote => 1,
+    Buyback => 1,
+    AdminGroup => 1,
+    AdminProposal => 1,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub authority: Pubkey,      // Platform admin
+    pub fee_bps: u16,           // Platform fee in basis points (100 = 1%)
+    pub fee_vault: Pubkey,      // Where fees go
+    pub total_artists: u64,     // Counter
+    pub boost_tiers: [BoostTier; MAX_BOOST_TIERS], // Boost pricing (price 0 = disabled)
+    pub total_volume_sol: u64,  // Lifetime buy + sell volume (lamports, gross)
+    pub total_fees: u64,        // Lifetime platform fees (lamports)
+    pub total_trades: u64,      // Lifetime buy + sell count
+    pub swap_fee_discount_bps: u16, // Share of the fee waived on each leg of a swap
+    pub admin_group: Pubkey,    // M-of-N group governing the config (default = none yet)
+    pub paused: bool,           // Trading halted by the admin group
+    pub fee_change_delay: i64,  // Minimum notice before a new fee applies
+    pub pending_fee_bps: u16,   // Queued fee, applied from `pending_fee_effective_at`
+    pub pending_fee_effective_at: i64, // 0 = no change queued
+    pub version: u8,            // Layout version; see `migrate_platform_config`
+    pub pending_fee_change_delay: i64, // Queued shorter delay, applied from `pending_delay_effective_at`
+    pub pending_delay_effective_at: i64, // 0 = no change queued
+}
+
+impl Versioned for PlatformConfig {
+    const VERSION: u8 = 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        // Configs from before versioning may predate the fee notice period
+        // and boost tiers; give them what `initialize` sets today
+        if self.version == 0 {
+            if self.fee_change_delay == 0 {
+                self.fee_change_delay = DEFAULT_FEE_CHANGE_DELAY;
+            }
+            if self.boost_tiers.iter().all(|t| t.price_lamports == 0) {
+                self.boost_tiers = DEFAULT_BOOST_TIERS;
+            }
+        }
+        self.version = Self::VERSION;
+    }
+}
+
+impl PlatformConfig {
+    pub fn record_trade(&mut self, sol_volume: u64, fee: u64) {
+        self.total_volume_sol = self.total_volume_sol.saturating_add(sol_volume);
+        self.total_fees = self.total_fees.saturating_add(fee);
+        self.total_trades = self.total_trades.saturating_add(1);
+    }
+
+    /// Platform fee in force now: the queued fee once its time has come,
+    /// even before a trade or `activate_fee_change` has stored it.
+    pub fn current_fee_bps(&self) -> Result<u64> {
+        let now = Clock::get()?.unix_timestamp;
+        let due = self.pending_fee_effective_at != 0 && now >= self.pending_fee_effective_at;
+        Ok(if due { self.pending_fee_bps } else { self.fee_bps } as u64)
+    }
+
+    /// Notice a fee change needs now: a queued shorter delay once its time
+    /// has come, else the stored one.
+    pub fn current_fee_change_delay(&self, now: i64) -> i64 {
+        let due = self.pending_delay_effective_at != 0 && now >= self.pending_delay_effective_at;
+        if due { self.pending_fee_change_delay } else { self.fee_change_delay }
+    }
+
+    /// A longer delay applies at once. A shorter one waits out the current
+    /// delay, so cutting the notice can't rush a fee change through.
+    /// Replaces any shorter delay already queued.
+    pub fn set_fee_change_delay(&mut self, delay_secs: i64, now: i64) -> Result<()> {
+        require!(delay_secs >= 0, FanStakeError::InvalidAmount);
+        let current = self.current_fee_change_delay(now);
+        self.fee_change_delay = current;
+        if delay_secs >= current {
+            self.fee_change_delay = delay_secs;
+            self.pending_fee_change_delay = 0;
+            self.pending_delay_effective_at = 0;
+            msg!("Fee change delay set to {}s", delay_secs);
+        } else {
+            self.pending_fee_change_delay = delay_secs;
+            self.pending_delay_effective_at = now + current;
+            msg!("Fee change delay {}s -> {}s at {}", current, delay_secs, self.pending_delay_effective_at);
+        }
+        Ok(())
+    }
+
+    /// Queues `fee_bps` to apply at `effective_at`, or as soon as the delay
+    /// allows when that is 0. Replaces any change still pending; one that
+    /// has already come due is stored first, so the live fee never jumps back.
+    pub fn queue_fee_change(&mut self, fee_bps: u16, effective_at: i64, now: i64) -> Result<()> {
+        require!(fee_bps <= MAX_PLATFORM_FEE_BPS, FanStakeError::InvalidPlatformFee);
+        self.activate_fee_change(now);
+        let earliest = now + self.current_fee_change_delay(now);
+        let effective_at = if effective_at == 0 { earliest } else { effective_at };
+        require!(effective_at >= earliest, FanStakeError::FeeChangeTooSoon);
+        self.pending_fee_bps = fee_bps;
+        self.pending_fee_effective_at = effective_at;
+        emit!(FeeChangeQueuedEvent {
+            old_fee_bps: self.fee_bps,
+            new_fee_bps: fee_bps,
+            effective_at,
+            timestamp: now,
+        });
+        msg!("Fee change queued: {} -> {} bps at {}", self.fee_bps, fee_bps, effective_at);
+        Ok(())
+    }
+
+    /// Stores a queued fee whose time has come. Returns whether it did.
+    pub fn activate_fee_change(&mut self, now: i64) -> bool {
+        if self.pending_fee_effective_at == 0 || now < self.pending_fee_effective_at {
+            return false;
+        }
+        emit!(FeeChangeActivatedEvent {
+            old_fee_bps: self.fee_bps,
+            new_fee_bps: self.pending_fee_bps,
+            timestamp: now,
+        });
+        self.fee_bps = self.pending_fee_bps;
+        self.pending_fee_bps = 0;
+        self.pending_fee_effective_at = 0;
+        true
+    }
+
+    pub fn set_boost_tier(&mut self, tier: u8, price_lamports: u64, duration_secs: i64) -> Result<()> {
+        require!((tier as usize) < MAX_BOOST_TIERS, FanStakeError::InvalidBoostTier);
+        require!(price_lamports == 0 || duration_secs > 0, FanStakeError::InvalidAmount);
+        self.boost_tiers[tier as usize] = BoostTier { price_lamports, duration_secs };
+        msg!("Boost tier {} set: {} lamports for {}s", tier, price_lamports, duration_secs);
+        Ok(())
+    }
+
+    pub fn set_swap_fee_discount(&mut self, discount_bps: u16) -> Result<()> {
+        require!(discount_bps as u64 <= BPS_DENOMINATOR, FanStakeError::InvalidFeeDiscount);
+        self.swap_fee_discount_bps = discount_bps;
+        msg!("Swap fee discount set: {} bps", discount_bps);
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct BoostTier {
+    pub price_lamports: u64,    // Price per purchase
+    pub duration_secs: i64,     // Time added per purchase
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Boost {
+    pub mint: Pubkey,           // Boosted artist token
+    pub tier: u8,               // Highest tier bought during the current boost
+    pub last_payer: Pubkey,     // Most recent purchaser
+    pub started_at: i64,        // Start of the current boost
+    pub expires_at: i64,        // Boost is active while now < expires_at
+    pub total_paid: u64,        // Lifetime lamports paid for this curve
+    pub bump: u8,
+    pub version: u8,            // Layout version; see `migrate_account`
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub mint: Pubkey,       // Token mint
+    pub artist: Pubkey,     // Artist wallet
+    pub vesting_end: i64,   // Unix timestamp when tokens unlock
+    pub bump: u8,
+    pub version: u8,        // Layout version; see `migrate_account`
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    pub artist: Pubkey,                   // Artist's wallet
+    pub mint: Pubkey,                     // Token mint address
+    #[max_len(32)]
+    pub name: String,                     // Artist/token name
+    #[max_len(10)]
+    pub symbol: String,                   // Token symbol
+    #[max_len(200)]
+    pub uri: String,                      // Metadata URI
+    pub virtual_sol_reserves: u64,        // Virtual SOL in the curve
+    pub virtual_token_reserves: u64,      // Virtual tokens in the curve
+    pub real_sol_reserves: u64,           // Actual SOL locked
+    pub real_token_reserves: u64,         // Actual tokens available
+    pub total_supply: u64,                // Total token supply
+    pub artist_share_bps: u16,           // Artist's allocation (basis points)
+    pub is_active: bool,                  // Is the curve active?
+    pub created_at: i64,                  // Unix timestamp
+    pub bump: u8,                         // PDA bump
+    pub token_program: Pubkey,            // SPL Token or Token-2022
+    pub treasury_bps: u16,                // Share of each buy (after fee) routed to the artist treasury
+    pub treasury_balance: u64,            // Treasury lamports held in the vault, outside the reserves
+    pub version: u8,                      // Layout version; see `migrate_bonding_curve`
+    pub eligible_tokens: u64,             // Buyer records' `curve_tokens`, summed — the refund denominator
+}
+
+impl Versioned for BondingCurve {
+    const VERSION: u8 = 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        // Curves from before Token-2022 support all use SPL Token
+        if self.token_program == Pubkey::default() {
+            self.token_program = token::ID;
+        }
+        // v1 didn't sum eligible tokens; a live treasury starts from the
+        // tokens sold, which can only dilute refunds, never overdraw them
+        if self.version < 2 && self.treasury_bps > 0 {
+            self.eligible_tokens = self.tokens_sold();
+        }
+        self.version = Self::VERSION;
+    }
+}
+
+impl BondingCurve {
+    /// Initial curve parameters shared by both launch paths.
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch(
+        &mut self,
+        artist: Pubkey,
+        mint: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+        artist_share_bps: u16,
+        token_program: Pubkey,
+        now: i64,
+        bump: u8,
+    ) {
+        self.artist = artist;
+        self.mint = mint;
+        self.name = name;
+        self.symbol = symbol;
+        self.uri = uri;
+        self.virtual_sol_reserves = 30_000_000_000;
+        self.virtual_token_reserves = 1_073_000_000_000_000;
+        self.real_sol_reserves = 0;
+        self.real_token_reserves = INITIAL_REAL_TOKEN_RESERVES;
+        self.total_supply = TOTAL_SUPPLY;
+        self.artist_share_bps = artist_share_bps;
+        self.is_active = true;
+        self.created_at = now;
+        self.bump = bump;
+        self.token_program = token_program;
+        self.version = Self::VERSION;
+    }
+
+    /// Tokens bought off the curve and not sold back.
+    pub fn tokens_sold(&self) -> u64 {
+        INITIAL_REAL_TOKEN_RESERVES.saturating_sub(self.real_token_reserves)
+    }
+
+    /// Tokens minted to the artist at launch (locked until vesting ends).
+    pub fn artist_allocation(&self) -> u64 {
+        (self.total_supply as u128 * self.artist_share_bps as u128 / 10_000) as u64
+    }
+
+    pub fn reserves(&self) -> CurveReserves {
+        CurveReserves {
+            virtual_sol: self.virtual_sol_reserves,
+            virtual_token: self.virtual_token_reserves,
+            real_sol: self.real_sol_reserves,
+            real_token: self.real_token_reserves,
+        }
+    }
+
+    pub fn set_reserves(&mut self, reserves: &CurveReserves) {
+        self.virtual_sol_reserves = reserves.virtual_sol;
+        self.virtual_token_reserves = reserves.virtual_token;
+        self.real_sol_reserves = reserves.real_sol;
+        self.real_token_reserves = reserves.real_token;
+    }
+
+    /// Current marginal price (see `PRICE_SCALE`).
+    pub fn spot_price(&self) -> u64 {
+        fanstake_math::spot_price(self.virtual_sol_reserves, self.virtual_token_reserves).unwrap()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CurveStats {
+    pub mint: Pubkey,                     // Token mint address
+    pub buy_volume_sol: u64,              // Lifetime SOL spent on buys (gross, lamports)
+    pub sell_volume_sol: u64,             // Lifetime SOL paid out by sells (gross, lamports)
+    pub buy_volume_tokens: u64,           // Lifetime tokens minted by buys
+    pub sell_volume_tokens: u64,          // Lifetime tokens burned by sells
+    pub buy_count: u64,                   // Number of buys
+    pub sell_count: u64,                  // Number of sells
+    pub fees_paid: u64,                   // Lifetime platform fees (lamports)
+    pub ath_price: u64,                   // All-time-high spot price (see PRICE_SCALE)
+    pub last_trade_at: i64,               // Unix timestamp of the last trade
+    pub unique_buyers: u64,               // Distinct wallets that have bought
+    pub bump: u8,                         // PDA bump
+    pub price_cumulative: u128,           // Σ price × seconds (wrapping), for TWAP
+    pub last_price: u64,                  // Spot price in effect since `price_updated_at`
+    pub price_updated_at: i64,            // Last accumulator update
+    pub observation_index: u16,           // Slot of the newest observation
+    pub observation_count: u16,           // Filled observation slots
+    pub observations: [Observation; TWAP_OBSERVATIONS], // Ring buffer of accumulator snapshots
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl CurveStats {
+    pub fn trade_count(&self) -> u64 {
+        self.buy_count + self.sell_count
+    }
+
+    pub fn record_buy(&mut self, sol_amount: u64, tokens_out: u64, fee: u64, price_after: u64, now: i64) {
+        self.buy_volume_sol = self.buy_volume_sol.saturating_add(sol_amount);
+        self.buy_volume_tokens = self.buy_volume_tokens.saturating_add(tokens_out);
+        self.buy_count += 1;
+        self.record_common(fee, price_after, now);
+    }
+
+    pub fn record_sell(&mut self, sol_out_gross: u64, token_amount: u64, fee: u64, price_after: u64, now: i64) {
+        self.sell_volume_sol = self.sell_volume_sol.saturating_add(sol_out_gross);
+        self.sell_volume_tokens = self.sell_volume_tokens.saturating_add(token_amount);
+        self.sell_count += 1;
+        self.record_common(fee, price_after, now);
+    }
+
+    fn record_common(&mut self, fee: u64, price_after: u64, now: i64) {
+        self.fees_paid = self.fees_paid.saturating_add(fee);
+        self.ath_price = self.ath_price.max(price_after);
+        self.last_trade_at = now;
+        self.update_oracle(price_after, now);
+    }
+
+    pub fn init_oracle(&mut self, price: u64, now: i64) {
+        self.last_price = price;
+        self.price_updated_at = now;
+        self.push_observation(now);
+    }
+
+    /// Uniswap v2-style accumulator: the price that held since the last update is
+    /// weighted by elapsed time *before* the new price takes effect, so a price moved
+    /// within a single transaction contributes nothing.
+    pub fn update_oracle(&mut self, price_after: u64, now: i64) {
+        self.price_cumulative = self.cumulative_at(now);
+        self.price_updated_at = self.price_updated_at.max(now);
+        self.last_price = price_after;
+
+        let latest = self.observations[self.observation_index as usize];
+        if self.observation_count == 0 || now - latest.timestamp >= TWAP_OBSERVATION_INTERVAL {
+            self.push_observation(now);
+        }
+    }
+
+    /// Counterfactual accumulator value at `now` (last price carried forward).
+    pub fn cumulative_at(&self, now: i64) -> u128 {
+        let elapsed = now.saturating_sub(self.price_updated_at).max(0) as u128;
+        self.price_cumulative
+            .wrapping_add((self.last_price as u128).wrapping_mul(elapsed))
+    }
+
+    fn push_observation(&mut self, now: i64) {
+        if self.observation_count > 0 {
+            self.observation_index = ((self.observation_index as usize + 1) % TWAP_OBSERVATIONS) as u16;
+        }
+        self.observations[self.observation_index as usize] = Observation {
+            timestamp: now,
+            price_cumulative: self.price_cumulative,
+        };
+        self.observation_count = (self.observation_count + 1).min(TWAP_OBSERVATIONS as u16);
+    }
+
+    /// Time-weighted average price over at least `window` seconds, measured from the
+    /// newest observation at or before `now - window`. Returns `(price, actual_window)`.
+    pub fn twap(&self, window: i64, now: i64) -> Option<(u64, i64)> {
+        let target = now.checked_sub(window)?;
+        let count = self.observation_count as usize;
+        let newest = self.observation_index as usize;
+        let start = (0..count)
+            .map(|i| self.observations[(newest + TWAP_OBSERVATIONS - i) % TWAP_OBSERVATIONS])
+            .find(|obs| obs.timestamp <= target)?;
+        let elapsed = now - start.timestamp;
+        if elapsed <= 0 {
+            return None;
+        }
+        let delta = self.cumulative_at(now).wrapping_sub(start.price_cumulative);
+        Some(((delta / elapsed as u128) as u64, elapsed))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct Observation {
+    pub timestamp: i64,                   // When the observation was written
+    pub price_cumulative: u128,           // Accumulator value at that time
+}
+
+/// Return data of `quote_buy`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct BuyQuoteResult {
+    pub tokens_out: u64,                  // Tokens the buyer would receive
+    pub fee: u64,                         // Platform fee (lamports)
+    pub price_after: u64,                 // Spot price after the trade (see PRICE_SCALE)
+}
+
+/// Return data of `quote_sell`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SellQuoteResult {
+    pub sol_out: u64,                     // Lamports the seller would receive (after fee)
+    pub fee: u64,                         // Platform fee (lamports)
+    pub price_after: u64,                 // Spot price after the trade (see PRICE_SCALE)
+}
+
+/// Return data of `get_twap`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TwapResult {
+    pub price: u64,                       // Time-weighted average price (see PRICE_SCALE)
+    pub window: i64,                      // Seconds actually covered (>= requested window)
+    pub spot_price: u64,                  // Current spot price, for comparison
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BuyerRecord {
+    pub mint: Pubkey,                     // Token mint address
+    pub wallet: Pubkey,                   // Fan wallet
+    pub sequence: u64,                    // Nth unique buyer of this token (0 = never bought)
+    pub first_buy_at: i64,                // Unix timestamp of first buy
+    pub bump: u8,                         // PDA bump
+    pub has_sold: bool,                   // Ever sold through the curve
+    pub sol_spent: u64,                   // Lifetime lamports spent on buys (fee included)
+    pub curve_tokens: u64,                // Bought while the treasury was funded, net of sales and Token-2022 transfers out — backs refunds and votes
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl BuyerRecord {
+    /// Seconds held since the first buy, if the wallet has bought and never sold.
+    pub fn held_without_selling(&self, now: i64) -> Option<i64> {
+        (self.sequence > 0 && !self.has_sold).then(|| now - self.first_buy_at)
+    }
+
+    /// `balance` is the wallet's current token balance; Diamond Hands also
+    /// needs tokens still held, since transfers out don't mark a sale.
+    pub fn qualifies_for(&self, kind: BadgeKind, balance: u64, now: i64) -> bool {
+        match kind {
+            BadgeKind::FoundingFan => (1..=FOUNDING_FAN_MAX_SEQUENCE).contains(&self.sequence),
+            BadgeKind::DayOne => (1..=DAY_ONE_MAX_SEQUENCE).contains(&self.sequence),
+            BadgeKind::DiamondHands => {
+                balance > 0
+                    && self
+                        .held_without_selling(now)
+                        .is_some_and(|held| held >= DIAMOND_HANDS_MIN_HOLD)
+            }
+            BadgeKind::Backstage => self.sol_spent >= BACKSTAGE_MIN_SOL_SPENT,
+        }
+    }
+
+    pub fn init_if_new(&mut self, mint: Pubkey, wallet: Pubkey, bump: u8) {
+        if self.wallet == Pubkey::default() {
+            self.mint = mint;
+            self.wallet = wallet;
+            self.bump = bump;
+            self.version = Self::VERSION;
+        }
+    }
+
+    /// Counts a buy; the wallet's first one assigns its buyer sequence.
+    pub fn record_buy(&mut self, stats: &mut CurveStats, sol_amount: u64, now: i64) {
+        if self.sequence == 0 {
+            stats.unique_buyers += 1;
+            self.sequence = stats.unique_buyers;
+            self.first_buy_at = now;
+        }
+        self.sol_spent = self.sol_spent.saturating_add(sol_amount);
+    }
+
+    /// Tokens bought from the curve back refunds and votes only while its
+    /// treasury is funded, so the artist allocation (never bought) and
+    /// buys after a failure carry no claim on the treasury. The curve keeps
+    /// the sum in `eligible_tokens`.
+    pub fn credit_tokens(&mut self, curve: &mut BondingCurve, tokens: u64) {
+        if curve.treasury_bps > 0 {
+            self.curve_tokens = self.curve_tokens.saturating_add(tokens);
+            curve.eligible_tokens = curve.eligible_tokens.saturating_add(tokens);
+        }
+    }
+
+    pub fn record_sale(&mut self, curve: &mut BondingCurve, token_amount: u64) {
+        self.has_sold = true;
+        self.forfeit_tokens(curve, token_amount);
+    }
+
+    /// Drops up to `token_amount` of the wallet's eligible tokens, and the
+    /// curve's sum with them. Returns how many it dropped.
+    pub fn forfeit_tokens(&mut self, curve: &mut BondingCurve, token_amount: u64) -> u64 {
+        let forfeited = token_amount.min(self.curve_tokens);
+        self.curve_tokens -= forfeited;
+        curve.eligible_tokens = curve.eligible_tokens.saturating_sub(forfeited);
+        forfeited
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Airdrop {
+    pub creator: Pubkey,                  // Funder — receives leftovers after expiry
+    pub mint: Pubkey,                     // Artist token being distributed
+    pub airdrop_id: u64,                  // Creator-chosen id (PDA seed)
+    pub merkle_root: [u8; 32],            // Root over (index, claimant, amount) leaves
+    pub total_amount: u64,                // Tokens deposited into escrow
+    pub claimed_amount: u64,              // Tokens claimed so far
+    pub num_recipients: u32,              // Number of leaves
+    pub num_claimed: u32,                 // Leaves claimed so far
+    pub expires_at: i64,                  // Claims close; creator may reclaim
+    pub bump: u8,                         // PDA bump
+    #[max_len(0)]
+    pub claim_bitmap: Vec<u8>,            // 1 bit per leaf; sized at creation
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl Airdrop {
+    pub fn bitmap_len(num_recipients: u32) -> usize {
+        (num_recipients as usize).div_ceil(8)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claim_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claim_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
+
+/// Fixed-size OHLC ring buffer, updated on every trade. Zero-copy so a chart can
+/// read the whole history with one `getAccountInfo`.
+#[account(zero_copy)]
+pub struct PriceHistory {
+    pub mint: Pubkey,                     // Token mint address
+    pub interval_secs: i64,               // Candle size in seconds
+    pub head: u32,                        // Slot of the newest bucket
+    pub count: u32,                       // Filled bucket slots
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account` (0 in older padding)
+    pub _padding: [u8; 6],
+    pub buckets: [PriceBucket; PRICE_HISTORY_BUCKETS],
 }
 
-#[derive(Accounts)]
-pub struct BuySell<'info> {
-    #[account(
-        mut,
-        seeds = [b"bonding_curve", mint.key().as_ref()],
-        bump = bonding_curve.bump,
-    )]
-    pub bonding_curve: Account<'info, BondingCurve>,
-    #[account(
-        seeds = [b"platform_config"],
-        bump,
-    )]
-    pub platform_config: Account<'info, PlatformConfig>,
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = user,
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    /// CHECK: Curve vault PDA holds SOL
-    #[account(
-        mut,
-        seeds = [b"curve_vault", mint.key().as_ref()],
-        bump,
-    )]
-    pub curve_vault: AccountInfo<'info>,
-    /// CHECK: Platform fee vault
-    #[account(
-        mut,
-        address = platform_config.fee_vault,
-    )]
-    pub fee_vault: AccountInfo<'info>,
-    /// Optional vesting schedule — only checked when artist is selling
-    #[account(
-        seeds = [b"artist_vesting", mint.key().as_ref()],
-        bump,
-    )]
-    pub artist_vesting: Option<Account<'info, VestingSchedule>>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+#[zero_copy]
+#[derive(Default)]
+pub struct PriceBucket {
+    pub start: i64,                       // Bucket open time (aligned to interval)
+    pub open: u64,                        // Prices in PRICE_SCALE units
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume_sol: u64,                  // Gross SOL traded in the bucket (lamports)
+}
+
+impl PriceHistory {
+    pub fn record(&mut self, now: i64, price_before: u64, price_after: u64, volume_sol: u64) {
+        let start = now - now.rem_euclid(self.interval_secs);
+        let hi = price_before.max(price_after);
+        let lo = price_before.min(price_after);
+
+        if self.count > 0 && self.buckets[self.head as usize].start == start {
+            let bucket = &mut self.buckets[self.head as usize];
+            bucket.high = bucket.high.max(hi);
+            bucket.low = bucket.low.min(lo);
+            bucket.close = price_after;
+            bucket.volume_sol = bucket.volume_sol.saturating_add(volume_sol);
+            return;
+        }
+
+        if self.count > 0 {
+            self.head = (self.head + 1) % PRICE_HISTORY_BUCKETS as u32;
+        }
+        self.count = (self.count + 1).min(PRICE_HISTORY_BUCKETS as u32);
+        self.buckets[self.head as usize] = PriceBucket {
+            start,
+            open: price_before,
+            high: hi,
+            low: lo,
+            close: price_after,
+            volume_sol,
+        };
+    }
 }
 
 // ============================================================
-// STATE
+// EVENTS
 // ============================================================
 
+/// Emitted on every buy and sell. Carries the post-trade reserves so
+/// indexers can track curves without re-reading accounts.
+#[event]
+pub struct TradeEvent {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    pub sol_amount: u64,                  // Gross lamports in (buy) or out (sell), fee included
+    pub token_amount: u64,                // Tokens minted (buy) or burned (sell)
+    pub fee: u64,                         // Platform fee (lamports)
+    pub price_before: u64,                // Spot prices (see PRICE_SCALE)
+    pub price_after: u64,
+    pub virtual_sol_reserves: u64,        // Reserves after the trade
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+impl TradeEvent {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        curve: &BondingCurve,
+        trader: Pubkey,
+        is_buy: bool,
+        sol_amount: u64,
+        token_amount: u64,
+        fee: u64,
+        price_before: u64,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            mint: curve.mint,
+            trader,
+            is_buy,
+            sol_amount,
+            token_amount,
+            fee,
+            price_before,
+            price_after: curve.spot_price(),
+            virtual_sol_reserves: curve.virtual_sol_reserves,
+            virtual_token_reserves: curve.virtual_token_reserves,
+            real_sol_reserves: curve.real_sol_reserves,
+            real_token_reserves: curve.real_token_reserves,
+            timestamp,
+        }
+    }
+}
+
+/// Emitted alongside the `TradeEvent` when tokens are bought for someone else.
+#[event]
+pub struct GiftEvent {
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub sol_amount: u64,                  // Gross lamports paid, fee included
+    pub token_amount: u64,                // Tokens minted to the recipient
+    pub memo_hash: Option<[u8; 32]>,      // Hash of an off-chain gift message
+    pub timestamp: i64,
+}
+
+/// Emitted by `liquidate`; the collateral sale also emits a `TradeEvent`.
+#[event]
+pub struct LiquidationEvent {
+    pub mint: Pubkey,
+    pub borrower: Pubkey,
+    pub liquidator: Pubkey,
+    pub collateral: u64,        // Tokens sold on the curve
+    pub debt: u64,              // Lamports owed at liquidation
+    pub proceeds: u64,          // Lamports the sale returned (after fee)
+    pub bonus: u64,             // Lamports paid to the liquidator
+    pub bad_debt: u64,          // Debt the proceeds didn't cover, written off
+    pub timestamp: i64,
+}
+
+/// Emitted by `buyback` and `execute_buyback`; the buy itself also emits a
+/// `TradeEvent` with the buyback PDA as trader.
+#[event]
+pub struct BuybackEvent {
+    pub mint: Pubkey,
+    pub caller: Pubkey,         // Artist, or the keeper of a scheduled run
+    pub sol_amount: u64,        // Lamports spent, fee included
+    pub tokens_burned: u64,
+    pub price_after: u64,       // Spot price after the buy (see PRICE_SCALE)
+    pub from_treasury: bool,    // Paid by the buyback treasury rather than the artist's wallet
+    pub scheduled: bool,        // Run by `execute_buyback`
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeQueuedEvent {
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeActivatedEvent {
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionExecutedEvent {
+    pub proposal_id: u64,
+    pub action: AdminAction,
+    pub approvals: u8,          // Member approvals at execution
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneReleasedEvent {
+    pub mint: Pubkey,
+    pub milestone: u8,
+    pub amount: u64,            // Lamports paid to the artist
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `buy_index` and `redeem_index`; the component trades emit
+/// their own `TradeEvent`s.
+#[event]
+pub struct IndexTradeEvent {
+    pub index: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    pub sol_amount: u64,                  // Lamports paid in (buy) or out (redeem)
+    pub index_amount: u64,                // Index tokens minted or burned
+    pub index_supply: u64,                // Index token supply after the trade
+    pub timestamp: i64,
+}
+
+
+/// Per-(curve, wallet) cost basis, using the average-cost method. Tokens
+/// sold beyond what the position tracks (e.g. airdropped) have zero cost.
 #[account]
 #[derive(InitSpace)]
-pub struct PlatformConfig {
-    pub authority: Pubkey,      // Platform admin
-    pub fee_bps: u16,           // Platform fee in basis points (100 = 1%)
-    pub fee_vault: Pubkey,      // Where fees go
-    pub total_artists: u64,     // Counter
+pub struct Position {
+    pub mint: Pubkey,                     // Token mint address
+    pub wallet: Pubkey,                   // Fan wallet
+    pub tokens_bought: u64,               // Lifetime tokens bought through the curve
+    pub tokens_sold: u64,                 // Lifetime tokens sold through the curve
+    pub sol_in: u64,                      // Lifetime lamports spent on buys (fee included)
+    pub sol_out: u64,                     // Lifetime lamports received from sells (after fee)
+    pub tokens_held: u64,                 // Tokens the cost basis covers
+    pub cost_basis: u64,                  // Lamports paid for `tokens_held`
+    pub realized_pnl: i64,                // Lifetime sell proceeds minus their cost (lamports)
+    pub first_buy_at: i64,                // Unix timestamp of the first tracked buy (0 = none)
+    pub last_trade_at: i64,               // Unix timestamp of the last tracked trade
+    pub opened_at: i64,                   // Tracking starts here
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl Position {
+    pub fn record_buy(&mut self, sol_amount: u64, tokens_out: u64, now: i64) {
+        self.tokens_bought = self.tokens_bought.saturating_add(tokens_out);
+        self.sol_in = self.sol_in.saturating_add(sol_amount);
+        self.tokens_held = self.tokens_held.saturating_add(tokens_out);
+        self.cost_basis = self.cost_basis.saturating_add(sol_amount);
+        if self.first_buy_at == 0 {
+            self.first_buy_at = now;
+        }
+        self.last_trade_at = now;
+    }
+
+    pub fn record_sell(&mut self, token_amount: u64, sol_out: u64, now: i64) {
+        let covered = token_amount.min(self.tokens_held);
+        let cost = if self.tokens_held == 0 {
+            0
+        } else {
+            (self.cost_basis as u128 * covered as u128 / self.tokens_held as u128) as u64
+        };
+        self.tokens_sold = self.tokens_sold.saturating_add(token_amount);
+        self.sol_out = self.sol_out.saturating_add(sol_out);
+        self.tokens_held -= covered;
+        self.cost_basis -= cost;
+        let pnl = sol_out as i128 - cost as i128;
+        self.realized_pnl = (self.realized_pnl as i128 + pnl).clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        self.last_trade_at = now;
+    }
+
+    /// Average entry price of the tracked holding (see PRICE_SCALE).
+    pub fn average_price(&self) -> u64 {
+        fanstake_math::spot_price(self.cost_basis, self.tokens_held).unwrap_or(0)
+    }
+}
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum BadgeKind {
+    FoundingFan,                          // One of the first FOUNDING_FAN_MAX_SEQUENCE buyers
+    DayOne,                               // One of the first DAY_ONE_MAX_SEQUENCE buyers
+    DiamondHands,                         // Held DIAMOND_HANDS_MIN_HOLD without ever selling
+    Backstage,                            // Spent at least BACKSTAGE_MIN_SOL_SPENT
 }
 
+/// Non-transferable badge attestation, one per (curve, wallet, kind).
+/// Snapshot of the qualifying facts at issue time.
 #[account]
 #[derive(InitSpace)]
-pub struct VestingSchedule {
-    pub mint: Pubkey,       // Token mint
-    pub artist: Pubkey,     // Artist wallet
-    pub vesting_end: i64,   // Unix timestamp when tokens unlock
-    pub bump: u8,
+pub struct Badge {
+    pub mint: Pubkey,                     // Token mint address
+    pub wallet: Pubkey,                   // Badge holder
+    pub kind: BadgeKind,
+    pub sequence: u64,                    // Buyer sequence at issue time
+    pub first_buy_at: i64,                // First buy at issue time
+    pub sol_spent: u64,                   // Lifetime spend at issue time (lamports)
+    pub issued_at: i64,                   // Unix timestamp
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
 }
 
+
+/// A weighted basket of artist curves with its own SPL index token. The
+/// index PDA holds the component tokens; index tokens are a pro-rata claim.
 #[account]
 #[derive(InitSpace)]
-pub struct BondingCurve {
-    pub artist: Pubkey,                   // Artist's wallet
-    pub mint: Pubkey,                     // Token mint address
+pub struct Index {
+    pub curator: Pubkey,                  // Creator (PDA seed)
+    pub index_id: u64,                    // Curator-chosen id (PDA seed)
+    pub index_mint: Pubkey,               // Index token mint (PDA, index is authority)
     #[max_len(32)]
-    pub name: String,                     // Artist/token name
+    pub name: String,
     #[max_len(10)]
-    pub symbol: String,                   // Token symbol
-    #[max_len(200)]
-    pub uri: String,                      // Metadata URI
-    pub virtual_sol_reserves: u64,        // Virtual SOL in the curve
-    pub virtual_token_reserves: u64,      // Virtual tokens in the curve
-    pub real_sol_reserves: u64,           // Actual SOL locked
-    pub real_token_reserves: u64,         // Actual tokens available
-    pub total_supply: u64,                // Total token supply
-    pub artist_share_bps: u16,           // Artist's allocation (basis points)
-    pub is_active: bool,                  // Is the curve active?
-    pub created_at: i64,                  // Unix timestamp
+    pub symbol: String,
+    #[max_len(MAX_INDEX_COMPONENTS)]
+    pub components: Vec<IndexComponent>, // Weights sum to 10,000 bps
+    pub created_at: i64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct IndexComponent {
+    pub mint: Pubkey,           // Artist token mint
+    pub weight_bps: u16,        // Share of each buy
+}
+
+
+/// Resting buy-below / sell-above order on one curve, filled by any keeper.
+/// Buy orders escrow SOL in this account; sell orders escrow tokens in the
+/// order's token account. The keeper tip sits here too.
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    pub owner: Pubkey,                    // Placed the order; receives the fill
+    pub mint: Pubkey,                     // Artist token
+    pub order_id: u64,                    // Owner-chosen id (PDA seed)
+    pub side: OrderSide,
+    pub amount: u64,                      // Lamports to spend (buy) or tokens to sell (sell)
+    pub trigger_price: u64,               // Buy at or below / sell at or above (see PRICE_SCALE)
+    pub min_out: u64,                     // Minimum tokens (buy) or lamports (sell) from the fill
+    pub keeper_tip: u64,                  // Lamports paid to the executing keeper
+    pub created_at: i64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+
+/// Recurring buy plan on one curve, cranked by any keeper. Holds the fan's
+/// unspent SOL on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct DcaPlan {
+    pub owner: Pubkey,                    // Fan; receives every buy
+    pub mint: Pubkey,                     // Artist token
+    pub plan_id: u64,                     // Owner-chosen id (PDA seed)
+    pub amount_per_interval: u64,         // Lamports spent per execution (fee included)
+    pub interval_secs: i64,
+    pub num_intervals: u32,               // Executions before the plan is done
+    pub intervals_executed: u32,
+    pub max_price: u64,                   // No buys above this spot price (see PRICE_SCALE)
+    pub keeper_tip: u64,                  // Lamports paid per execution
+    pub next_execution_at: i64,
+    pub total_sol_spent: u64,
+    pub total_tokens_bought: u64,
+    pub created_at: i64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+
+/// Per-curve lending pool. Holds the lenders' idle SOL on top of its rent,
+/// and every borrower's collateral in its token account.
+#[account]
+#[derive(InitSpace)]
+pub struct LendingPool {
+    pub mint: Pubkey,                     // Artist token accepted as collateral
+    pub params: LendingParams,
+    pub liquidity: u64,                   // Idle lamports available to borrow or withdraw
+    pub total_shares: u64,                // Lender shares outstanding
+    pub total_debt: u64,                  // Lamports owed by borrowers, as of `last_accrual`
+    pub total_collateral: u64,            // Tokens locked across all loans
+    pub borrow_index: u128,               // Interest growth since launch (see BORROW_INDEX_SCALE)
+    pub last_accrual: i64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl LendingPool {
+    /// Liquidity plus outstanding debt — what the lenders' shares are worth.
+    pub fn total_assets(&self) -> Result<u64> {
+        Ok(self.liquidity.checked_add(self.total_debt).ok_or(FanStakeError::MathOverflow)?)
+    }
+
+    /// Most a loan may owe against collateral worth `value` lamports.
+    pub fn max_borrow(&self, value: u64) -> Result<u64> {
+        let limit = fanstake_math::pro_rata(value, self.params.ltv_bps as u64, BPS_DENOMINATOR);
+        Ok(limit.ok_or(FanStakeError::MathOverflow)?)
+    }
+
+    /// Debt above which a loan against collateral worth `value` lamports can
+    /// be liquidated (health below 1).
+    pub fn liquidation_limit(&self, value: u64) -> Result<u64> {
+        let limit = fanstake_math::pro_rata(value, self.params.liquidation_threshold_bps as u64, BPS_DENOMINATOR);
+        Ok(limit.ok_or(FanStakeError::MathOverflow)?)
+    }
+
+    /// Grows the borrow index and total debt at the current utilization rate.
+    pub fn accrue(&mut self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_accrual);
+        if elapsed <= 0 {
+            return Ok(());
+        }
+        let (base, slope) = (self.params.base_rate_bps as u64, self.params.slope_bps as u64);
+        let rate = fanstake_math::utilization_bps(self.total_debt, self.liquidity)
+            .and_then(|util| fanstake_math::borrow_rate_bps(util, base, slope))
+            .ok_or(FanStakeError::MathOverflow)?;
+        let index = fanstake_math::accrue_borrow_index(self.borrow_index, rate, elapsed as u64)
+            .ok_or(FanStakeError::MathOverflow)?;
+        self.total_debt = fanstake_math::grow_debt(self.total_debt, self.borrow_index, index)
+            .ok_or(FanStakeError::MathOverflow)?;
+        self.borrow_index = index;
+        self.last_accrual = now;
+        Ok(())
+    }
+}
+
+/// Risk and rate settings of a lending pool, all in basis points.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct LendingParams {
+    pub ltv_bps: u16,                     // Max debt / collateral value when borrowing
+    pub liquidation_threshold_bps: u16,   // Debt / collateral value at which a loan is liquidatable
+    pub base_rate_bps: u16,               // Annual borrow rate at 0% utilization
+    pub slope_bps: u16,                   // Added to the annual rate at 100% utilization
+    pub liquidation_bonus_bps: u16,       // Liquidator's cut of the repaid debt
+}
+
+impl LendingParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.ltv_bps > 0
+                && self.ltv_bps < self.liquidation_threshold_bps
+                && self.liquidation_threshold_bps as u64 <= BPS_DENOMINATOR
+                && self.liquidation_bonus_bps <= MAX_LIQUIDATION_BONUS_BPS,
+            FanStakeError::InvalidLendingParams
+        );
+        Ok(())
+    }
+}
+
+/// A lender's shares in one lending pool.
+#[account]
+#[derive(InitSpace)]
+pub struct LenderPosition {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub shares: u64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+/// One borrower's collateral and debt in a lending pool.
+#[account]
+#[derive(InitSpace)]
+pub struct Loan {
+    pub borrower: Pubkey,
+    pub mint: Pubkey,
+    pub collateral: u64,                  // Tokens locked in the pool
+    pub debt: u64,                        // Lamports owed as of `index_snapshot`
+    pub index_snapshot: u128,             // Pool borrow index when `debt` was last synced
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl Loan {
+    /// Brings `debt` up to the pool's current borrow index and returns it.
+    pub fn sync(&mut self, pool: &LendingPool) -> Result<u64> {
+        self.debt = fanstake_math::grow_debt(self.debt, self.index_snapshot, pool.borrow_index)
+            .ok_or(FanStakeError::MathOverflow)?;
+        self.index_snapshot = pool.borrow_index;
+        Ok(self.debt)
+    }
+}
+
+
+/// Milestone-gated artist treasury. Its SOL sits in the curve vault (see
+/// `BondingCurve::treasury_balance`); this account tracks the milestones,
+/// votes and refunds.
+#[account]
+#[derive(InitSpace)]
+pub struct ArtistTreasury {
+    pub mint: Pubkey,
+    pub artist: Pubkey,                   // Receives released funds
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,       // Released strictly in order
+    pub next_milestone: u8,               // Index of the first unreleased milestone
+    pub total_released: u64,
+    pub failed_at: i64,                   // Set once a deadline is missed (0 = active)
+    pub refund_pool: u64,                 // Treasury balance when it failed
+    pub refund_supply: u64,               // Curve's `eligible_tokens` when it failed — the refund denominator
+    pub total_refunded: u64,
+    pub created_at: i64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct Milestone {
+    #[max_len(MAX_MILESTONE_DESCRIPTION_LEN)]
+    pub description: String,              // e.g. "Record EP"
+    pub amount: u64,                      // Lamports released when approved
+    pub deadline: i64,                    // Unreleased past this → treasury fails
+    pub round: u32,                       // Release votes held so far
+    pub vote_ends_at: i64,                // Current vote's end (0 = no vote open)
+    pub votes_for: u64,                   // Tokens locked for, this round
+    pub votes_against: u64,               // Tokens locked against, this round
+    pub released_at: i64,                 // 0 until released
+}
+
+/// A milestone as the artist declares it in `create_artist_treasury`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MilestoneInput {
+    pub description: String,
+    pub amount: u64,
+    pub deadline: i64,
+}
+
+/// Tokens a holder locked in one release vote.
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryVote {
+    pub voter: Pubkey,
+    pub mint: Pubkey,
+    pub milestone: u8,
+    pub round: u32,                       // Milestone vote round this was cast in
+    pub approve: bool,
+    pub amount: u64,                      // Tokens locked in the vote escrow
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+
+/// An artist's buyback-and-burn treasury on one curve. Holds the SOL funded
+/// for buybacks on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct Buyback {
+    pub mint: Pubkey,
+    pub artist: Pubkey,                   // Sets the config, may buy back from their wallet
+    pub config: BuybackConfig,
+    pub period_start: i64,                // Start of the current rate-limit period
+    pub spent_in_period: u64,             // Lamports spent on buybacks this period
+    pub next_run_at: i64,                 // Next scheduled run (see `execute_buyback`)
+    pub total_sol_spent: u64,
+    pub total_tokens_burned: u64,
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl Buyback {
+    /// Counts `amount` against the current period's limit, starting a new
+    /// period if the last one has ended.
+    pub fn consume_allowance(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(amount > 0, FanStakeError::InvalidAmount);
+        let period = self.config.period_secs;
+        if now >= self.period_start + period {
+            self.period_start = now - (now - self.period_start) % period;
+            self.spent_in_period = 0;
+        }
+        let spent = self.spent_in_period.checked_add(amount).ok_or(FanStakeError::MathOverflow)?;
+        require!(spent <= self.config.max_sol_per_period, FanStakeError::BuybackRateLimited);
+        self.spent_in_period = spent;
+        Ok(())
+    }
+}
+
+/// Limits and schedule of a buyback.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct BuybackConfig {
+    pub max_price: u64,                   // No buybacks that end above this spot price (see PRICE_SCALE)
+    pub period_secs: i64,                 // Rate-limit period
+    pub max_sol_per_period: u64,          // Lamports spent on buybacks per period, fees included
+    pub amount_per_run: u64,              // Lamports per scheduled run (0 = no schedule)
+    pub run_interval_secs: i64,           // Seconds between scheduled runs
+    pub keeper_tip: u64,                  // Lamports paid to whoever runs a scheduled buyback
+}
+
+impl BuybackConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_price > 0
+                && self.period_secs > 0
+                && self.max_sol_per_period > 0
+                && (self.amount_per_run == 0
+                    || (self.amount_per_run <= self.max_sol_per_period
+                        && self.run_interval_secs >= MIN_BUYBACK_INTERVAL)),
+            FanStakeError::InvalidBuybackConfig
+        );
+        Ok(())
+    }
+}
+
+
+/// M-of-N group that governs the platform config once set up.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminGroup {
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,                    // Approvals needed to execute
+    pub generation: u32,                  // Bumped on member changes; older proposals lapse
+    pub proposal_count: u64,              // Next proposal id (PDA seed)
+    pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl AdminGroup {
+    /// Position of `key` in `members` — its bit in a proposal's approvals.
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        self.members.iter().position(|m| m == key).ok_or(error!(FanStakeError::NotAdminMember))
+    }
+}
+
+/// A config change proposed to the admin group, kept after execution as a
+/// record.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub generation: u32,                  // Group generation it was proposed under
+    pub approvals: u16,                   // Bit i = members[i] approved
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub executed_at: i64,                 // 0 until executed
     pub bump: u8,                         // PDA bump
+    pub version: u8,                      // Layout version; see `migrate_account`
+}
+
+impl AdminProposal {
+    /// Not executed, not expired, and proposed to the current members.
+    pub fn require_open(&self, group: &AdminGroup, now: i64) -> Result<()> {
+        require!(
+            self.executed_at == 0 && now < self.expires_at && self.generation == group.generation,
+            FanStakeError::ProposalClosed
+        );
+        Ok(())
+    }
+}
+
+/// Config changes the admin group can make.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub enum AdminAction {
+    SetFee { fee_bps: u16, effective_at: i64 }, // Queued; 0 = as soon as the delay allows
+    SetFeeChangeDelay { delay_secs: i64 },
+    SetPaused { paused: bool },
+    WithdrawFees { amount: u64, recipient: Pubkey },
+    SetSwapFeeDiscount { discount_bps: u16 },
+    SetBoostTier { tier: u8, price_lamports: u64, duration_secs: i64 },
+    SetLendingParams { mint: Pubkey, params: LendingParams },
+    SetMembers {
+        #[max_len(MAX_ADMIN_MEMBERS)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
 }
 
 // ============================================================
@@ -570,4 +6770,249 @@ pub enum FanStakeError {
     Unauthorized,
     #[msg("Artist tokens are still vesting. Please wait until the lockup period ends.")]
     TokensStillVesting,
+    #[msg("Airdrop must have between 1 and 65,536 recipients.")]
+    InvalidRecipientCount,
+    #[msg("Expiry must be in the future.")]
+    InvalidExpiry,
+    #[msg("Airdrop has expired.")]
+    AirdropExpired,
+    #[msg("Airdrop has not expired yet.")]
+    AirdropNotExpired,
+    #[msg("Recipient index is out of range.")]
+    InvalidRecipientIndex,
+    #[msg("This allocation has already been claimed.")]
+    AlreadyClaimed,
+    #[msg("Invalid Merkle proof.")]
+    InvalidMerkleProof,
+    #[msg("Invalid or disabled boost tier.")]
+    InvalidBoostTier,
+    #[msg("Not enough price history for the requested TWAP window.")]
+    TwapWindowUnavailable,
+    #[msg("Price history interval must be between 1 minute and 1 week.")]
+    InvalidInterval,
+    #[msg("Arithmetic overflow in curve math.")]
+    MathOverflow,
+    #[msg("Badge criteria not met.")]
+    BadgeNotEarned,
+    #[msg("Index needs 1-10 distinct components with weights summing to 10,000 bps.")]
+    InvalidIndexComponents,
+    #[msg("Remaining accounts don't match the index components.")]
+    IndexAccountMismatch,
+    #[msg("Cannot swap a token for itself.")]
+    SameCurve,
+    #[msg("Fee discount cannot exceed 100%.")]
+    InvalidFeeDiscount,
+    #[msg("Sell orders need the order's escrow token account.")]
+    MissingOrderEscrow,
+    #[msg("The curve's spot price has not crossed the order's trigger price.")]
+    OrderNotTriggered,
+    #[msg("DCA plans need at least one interval of at least one minute.")]
+    InvalidDcaSchedule,
+    #[msg("Not enough unspent SOL in the DCA plan.")]
+    InsufficientDcaBalance,
+    #[msg("The next DCA interval is not due yet.")]
+    DcaNotDue,
+    #[msg("All intervals of this DCA plan have run.")]
+    DcaPlanComplete,
+    #[msg("Spot price is above the DCA plan's maximum.")]
+    DcaPriceAboveMax,
+    #[msg("LTV must be positive and below the liquidation threshold (max 100%), bonus at most 20%.")]
+    InvalidLendingParams,
+    #[msg("Not enough idle SOL in the lending pool.")]
+    InsufficientLiquidity,
+    #[msg("Not enough pool shares.")]
+    InsufficientShares,
+    #[msg("Not enough collateral in the loan.")]
+    InsufficientCollateral,
+    #[msg("Loan would exceed the pool's loan-to-value limit.")]
+    LoanUnhealthy,
+    #[msg("Loan is healthy and cannot be liquidated.")]
+    LoanHealthy,
+    #[msg("Treasury needs a 1-50% share and 1-5 milestones with positive amounts, short descriptions and increasing future deadlines.")]
+    InvalidTreasuryConfig,
+    #[msg("A treasury can only be set up before the curve's first trade.")]
+    CurveAlreadyTrading,
+    #[msg("Only the next milestone can be voted on, and its vote must end before its deadline.")]
+    MilestoneNotNext,
+    #[msg("A release vote is already open on this milestone.")]
+    VoteAlreadyOpen,
+    #[msg("No release vote is open on this milestone.")]
+    VoteNotOpen,
+    #[msg("The release vote has not ended yet.")]
+    VoteStillOpen,
+    #[msg("The artist can't vote on or claim refunds from their own treasury.")]
+    ArtistCannotVote,
+    #[msg("Not enough SOL in the treasury for this milestone yet.")]
+    InsufficientTreasuryBalance,
+    #[msg("The treasury has failed; only refunds remain.")]
+    TreasuryFailed,
+    #[msg("No milestone deadline has been missed; the treasury is still active.")]
+    TreasuryActive,
+    #[msg("Buyback needs a price cap, a rate limit, and a schedule within it at least a minute apart.")]
+    InvalidBuybackConfig,
+    #[msg("Buyback would exceed this period's spending limit.")]
+    BuybackRateLimited,
+    #[msg("Buyback would push the price above its cap.")]
+    BuybackPriceAboveMax,
+    #[msg("No scheduled buyback is due.")]
+    BuybackNotDue,
+    #[msg("Not enough SOL in the buyback treasury.")]
+    InsufficientBuybackBalance,
+    #[msg("Admin group needs 1-10 distinct members and a threshold between 1 and the member count.")]
+    InvalidAdminGroup,
+    #[msg("Signer is not a member of the admin group.")]
+    NotAdminMember,
+    #[msg("This member has already approved the proposal.")]
+    AlreadyApproved,
+    #[msg("The proposal doesn't have enough approvals yet.")]
+    ProposalNotApproved,
+    #[msg("The proposal was executed, expired, or predates a member change.")]
+    ProposalClosed,
+    #[msg("The platform is governed by its admin group; propose this change there.")]
+    AdminGroupRequired,
+    #[msg("Trading is paused.")]
+    PlatformPaused,
+    #[msg("Platform fee can't exceed 10%.")]
+    InvalidPlatformFee,
+    #[msg("A fee change must take effect no earlier than the configured delay.")]
+    FeeChangeTooSoon,
+    #[msg("No queued fee change is due.")]
+    FeeChangeNotDue,
+    #[msg("Account is already on the current layout version.")]
+    AlreadyMigrated,
+    #[msg("The mint has metadata, but the accounts to update it weren't passed.")]
+    MetadataAccountsMissing,
+    #[msg("Only tokens bought from the curve while its treasury was funded can vote.")]
+    TokensNotEligible,
+    #[msg("The transfer hook only runs inside a Token-2022 transfer.")]
+    NotTransferring,
+    #[msg("Buy orders don't take an escrow token account.")]
+    UnexpectedOrderEscrow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A config as the baseline `initialize` left it: 74 bytes of
+    /// `{authority, fee_bps, fee_vault, total_artists}`.
+    fn v0_platform_config() -> Vec<u8> {
+        let mut data = PlatformConfig::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[12; 32]);
+        data.extend_from_slice(&250u16.to_le_bytes());
+        data.extend_from_slice(&[13; 32]);
+        data.extend_from_slice(&7u64.to_le_bytes());
+        assert_eq!(data.len(), 8 + 74);
+        data
+    }
+
+    /// A vesting schedule as the baseline launch left it, before `version`.
+    fn v0_vesting_schedule() -> Vec<u8> {
+        let mut data = VestingSchedule::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&[11; 32]);
+        data.extend_from_slice(&1_700_086_400i64.to_le_bytes());
+        data.push(254);
+        data
+    }
+
+    #[test]
+    fn migrating_a_v0_platform_config_fills_in_defaults() {
+        assert!(PlatformConfig::try_deserialize(&mut &v0_platform_config()[..]).is_err());
+        let mut config = decode_legacy::<PlatformConfig>(&v0_platform_config()).unwrap();
+        assert_eq!(config.version(), 0);
+        config.upgrade();
+        assert_eq!(config.version, PlatformConfig::VERSION);
+        assert_eq!(config.authority, Pubkey::new_from_array([12; 32]));
+        assert_eq!((config.fee_bps, config.total_artists), (250, 7));
+        assert_eq!(config.fee_vault, Pubkey::new_from_array([13; 32]));
+        assert_eq!(config.fee_change_delay, DEFAULT_FEE_CHANGE_DELAY);
+        assert_eq!(config.boost_tiers[1].price_lamports, DEFAULT_BOOST_TIERS[1].price_lamports);
+        assert_eq!((config.pending_fee_change_delay, config.pending_delay_effective_at), (0, 0));
+        assert_eq!(config.current_fee_change_delay(0), DEFAULT_FEE_CHANGE_DELAY);
+    }
+
+    #[test]
+    fn migrating_a_v1_platform_config_keeps_its_settings() {
+        let mut config = decode_legacy::<PlatformConfig>(&v0_platform_config()).unwrap();
+        config.version = 1;
+        config.upgrade();
+        assert_eq!(config.version, PlatformConfig::VERSION);
+        assert_eq!(config.fee_change_delay, 0);
+        assert_eq!(config.boost_tiers[0].price_lamports, 0);
+    }
+
+    #[test]
+    fn migrating_a_v0_vesting_schedule_keeps_the_lockup() {
+        // Too short for the current layout, so `require_vested` can't read it yet
+        assert!(VestingSchedule::try_deserialize(&mut &v0_vesting_schedule()[..]).is_err());
+        let mut vesting = decode_legacy::<VestingSchedule>(&v0_vesting_schedule()).unwrap();
+        assert_eq!(vesting.version(), 0);
+        vesting.upgrade();
+        assert_eq!(vesting.version, VestingSchedule::VERSION);
+        assert_eq!(vesting.artist, Pubkey::new_from_array([11; 32]));
+        assert_eq!((vesting.vesting_end, vesting.bump), (1_700_086_400, 254));
+
+        let mut bytes = Vec::new();
+        vesting.try_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 + VestingSchedule::INIT_SPACE);
+        assert!(VestingSchedule::try_deserialize(&mut &bytes[..]).is_ok());
+    }
+
+    #[test]
+    fn queueing_a_fee_change_keeps_a_due_one() {
+        let mut config = decode_legacy::<PlatformConfig>(&v0_platform_config()).unwrap();
+        config.upgrade();
+        config.fee_bps = 1000;
+        config.queue_fee_change(100, 0, 0).unwrap();
+        let due = config.pending_fee_effective_at;
+
+        // Nothing has traded since the change came due, so it is still pending
+        config.queue_fee_change(500, 0, due).unwrap();
+        assert_eq!(config.fee_bps, 100);
+        assert_eq!(config.pending_fee_bps, 500);
+        assert_eq!(config.pending_fee_effective_at, due + DEFAULT_FEE_CHANGE_DELAY);
+    }
+
+    #[test]
+    fn cutting_the_fee_change_delay_waits_out_the_current_one() {
+        let mut config = decode_legacy::<PlatformConfig>(&v0_platform_config()).unwrap();
+        config.upgrade();
+        config.set_fee_change_delay(0, 100).unwrap();
+        assert_eq!(config.current_fee_change_delay(100), DEFAULT_FEE_CHANGE_DELAY);
+        assert_eq!(config.pending_delay_effective_at, 100 + DEFAULT_FEE_CHANGE_DELAY);
+        assert_eq!(config.current_fee_change_delay(100 + DEFAULT_FEE_CHANGE_DELAY), 0);
+
+        // Raising it back applies at once and drops the queued cut
+        config.set_fee_change_delay(DEFAULT_FEE_CHANGE_DELAY, 200).unwrap();
+        assert_eq!(config.pending_delay_effective_at, 0);
+        assert_eq!(config.current_fee_change_delay(100 + DEFAULT_FEE_CHANGE_DELAY), DEFAULT_FEE_CHANGE_DELAY);
+    }
+
+    #[test]
+    fn eligible_tokens_sum_buyer_records_net_of_sales() {
+        let mut curve = decode_legacy::<BondingCurve>(BondingCurve::DISCRIMINATOR).unwrap();
+        let mut fan = decode_legacy::<BuyerRecord>(BuyerRecord::DISCRIMINATOR).unwrap();
+        let mut other = decode_legacy::<BuyerRecord>(BuyerRecord::DISCRIMINATOR).unwrap();
+
+        // Nothing is eligible before the treasury is funded
+        fan.credit_tokens(&mut curve, 100);
+        assert_eq!((fan.curve_tokens, curve.eligible_tokens), (0, 0));
+
+        curve.treasury_bps = 2000;
+        fan.credit_tokens(&mut curve, 100);
+        other.credit_tokens(&mut curve, 50);
+        assert_eq!(curve.eligible_tokens, 150);
+
+        // Selling tokens received from elsewhere only drops the seller's own share
+        fan.record_sale(&mut curve, 150);
+        assert_eq!((fan.curve_tokens, curve.eligible_tokens), (0, 50));
+        assert_eq!(other.forfeit_tokens(&mut curve, 20), 20);
+        assert_eq!((other.curve_tokens, curve.eligible_tokens), (30, 30));
+    }
+
+    #[test]
+    fn decode_legacy_rejects_other_accounts() {
+        assert!(decode_legacy::<PlatformConfig>(BondingCurve::DISCRIMINATOR).is_err());
+    }
 }